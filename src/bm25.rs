@@ -1,8 +1,13 @@
 //! BM25 search indexing for skill discovery.
 //!
 //! Vendored from jpx-engine with modifications for skillet:
-//! removed serde derives (index is ephemeral), removed source document storage
-//! (skill data lives in SkillIndex), removed portability fields.
+//! removed source document storage (skill data lives in SkillIndex), removed
+//! portability fields, added incremental add/remove/update of documents.
+//! The index derives serde so the CLI can persist it between runs (see
+//! [`crate::cache::write_search`]). Text analysis goes through the
+//! [`Tokenizer`] trait: the built-in [`Analyzer`] pipeline lowercases,
+//! splits words, emits character bigrams for CJK runs, drops stop words,
+//! and stems with a Snowball stemmer chosen by [`Language`].
 //!
 //! # BM25 Formula
 //!
@@ -17,10 +22,64 @@
 //! - k1 = term frequency saturation parameter (default 1.2)
 //! - b = length normalization parameter (default 0.75)
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+use rust_stemmers::{Algorithm, Stemmer};
+use serde::{Deserialize, Serialize};
+
+/// Common English stop words excluded from indexing.
+pub const ENGLISH_STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
+    "they", "this", "to", "was", "will", "with",
+    // Question words and pronouns -- improve natural language query handling
+    "how", "do", "does", "did", "can", "should", "would", "could", "what", "which", "where", "when",
+    "why", "who", "me", "my", "i", "you", "your", "we", "us", // Common filler words
+    "about", "from", "have", "has", "had", "been", "being", "some", "any", "all", "just", "want",
+    "need",
+];
+
+/// Common German stop words excluded from indexing.
+pub const GERMAN_STOP_WORDS: &[&str] = &[
+    "aber", "als", "am", "an", "auch", "auf", "aus", "bei", "bin", "bis", "das", "dass", "dem",
+    "den", "der", "des", "die", "du", "ein", "eine", "einem", "einen", "einer", "eines", "er",
+    "es", "für", "hat", "ich", "ihr", "im", "in", "ist", "mit", "nach", "nicht", "noch", "oder",
+    "sich", "sie", "sind", "so", "und", "von", "vor", "war", "was", "wie", "wir", "zu", "zum",
+    "zur",
+];
+
+/// Common French stop words excluded from indexing.
+pub const FRENCH_STOP_WORDS: &[&str] = &[
+    "au", "aux", "avec", "ce", "ces", "dans", "de", "des", "du", "elle", "en", "est", "et", "il",
+    "ils", "je", "la", "le", "les", "leur", "lui", "ma", "mais", "me", "mes", "ne", "nous", "on",
+    "ou", "par", "pas", "pour", "qu", "que", "qui", "sa", "se", "ses", "son", "sur", "ta", "te",
+    "tes", "ton", "tu", "un", "une", "vous",
+];
+
+/// Common Spanish stop words excluded from indexing.
+pub const SPANISH_STOP_WORDS: &[&str] = &[
+    "a", "al", "como", "con", "de", "del", "el", "ella", "en", "es", "esta", "este", "la", "las",
+    "lo", "los", "me", "mi", "no", "o", "para", "pero", "por", "que", "se", "si", "sin", "su",
+    "sus", "te", "tu", "un", "una", "uno", "y", "ya",
+];
+
+/// Owned copies of each language's built-in stop words, for analyzers of
+/// non-default languages.
+static DEFAULT_STOP_WORDS: LazyLock<HashMap<Language, Vec<String>>> = LazyLock::new(|| {
+    Language::NAMES
+        .iter()
+        .map(|&(language, _, _)| {
+            let words = language.default_stopwords();
+            (language, words.iter().map(|s| s.to_string()).collect())
+        })
+        .collect()
+});
 
 /// BM25 index structure
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Bm25Index {
     /// Index configuration
     pub options: IndexOptions,
@@ -28,18 +87,32 @@ pub struct Bm25Index {
     /// Total number of documents
     pub doc_count: usize,
 
+    /// Positional ID for the next document without an ID field. Only ever
+    /// increases, so IDs freed by removal are never handed out again.
+    #[serde(default)]
+    pub next_id: usize,
+
     /// Average document length (in tokens)
     pub avg_doc_length: f64,
 
+    /// Sum of all document lengths (in tokens), kept for incremental updates
+    pub total_length: usize,
+
     /// Document metadata: id -> DocInfo
     pub docs: HashMap<String, DocInfo>,
 
     /// Inverted index: term -> TermInfo
     pub terms: HashMap<String, TermInfo>,
+
+    /// Document counts for languages other than `options.language`.
+    /// Queries are analyzed once per language present so those documents
+    /// stay reachable.
+    #[serde(default)]
+    pub languages: HashMap<Language, usize>,
 }
 
 /// Index configuration options
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct IndexOptions {
     /// Fields to index (empty = treat input as text)
     pub fields: Vec<String>,
@@ -50,6 +123,10 @@ pub struct IndexOptions {
     /// Normalize case (default: true)
     pub lowercase: bool,
 
+    /// Default analysis language for documents and queries (default: simple)
+    #[serde(default)]
+    pub language: Language,
+
     /// Terms to exclude from indexing
     pub stopwords: Vec<String>,
 
@@ -69,6 +146,7 @@ impl Default for IndexOptions {
             fields: Vec::new(),
             id_field: None,
             lowercase: true,
+            language: Language::default(),
             stopwords: Vec::new(),
             k1: 1.2,
             b: 0.75,
@@ -78,7 +156,7 @@ impl Default for IndexOptions {
 }
 
 /// Document metadata
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DocInfo {
     /// Document length in tokens
     pub length: usize,
@@ -87,10 +165,17 @@ pub struct DocInfo {
     /// Used during index construction; retained for potential field-level scoring.
     #[allow(dead_code)]
     pub field_lengths: HashMap<String, usize>,
+
+    /// Distinct terms in this document, used to drop postings on removal
+    pub terms: Vec<String>,
+
+    /// Language the document was analyzed with
+    #[serde(default)]
+    pub language: Language,
 }
 
 /// Per-document posting for a term, with optional field-level breakdown.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TermPostings {
     /// Total term frequency across all fields.
     pub total_freq: usize,
@@ -100,7 +185,7 @@ pub struct TermPostings {
 }
 
 /// Term information in the inverted index
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TermInfo {
     /// Document frequency (number of documents containing this term)
     pub df: usize,
@@ -116,6 +201,271 @@ struct TokenizedDoc {
     field_tokens: HashMap<String, Vec<String>>,
 }
 
+/// Analysis language: selects the stemmer and default stop words.
+///
+/// Parses from names (`"english"`) or ISO 639-1 codes (`"en"`, `"ja-JP"`).
+/// CJK languages have no stemmer; their text is indexed as character
+/// bigrams regardless of the selected language.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Language {
+    /// Built-in English suffix stripper, see `stem_simple`.
+    #[default]
+    Simple,
+    /// No stemming.
+    None,
+    Arabic,
+    Danish,
+    Dutch,
+    English,
+    Finnish,
+    French,
+    German,
+    Greek,
+    Hungarian,
+    Italian,
+    Norwegian,
+    Portuguese,
+    Romanian,
+    Russian,
+    Spanish,
+    Swedish,
+    Tamil,
+    Turkish,
+    Chinese,
+    Japanese,
+    Korean,
+}
+
+impl Language {
+    /// All languages with their canonical name and ISO 639-1 code.
+    const NAMES: &[(Language, &str, &str)] = &[
+        (Language::Simple, "simple", ""),
+        (Language::None, "none", ""),
+        (Language::Arabic, "arabic", "ar"),
+        (Language::Danish, "danish", "da"),
+        (Language::Dutch, "dutch", "nl"),
+        (Language::English, "english", "en"),
+        (Language::Finnish, "finnish", "fi"),
+        (Language::French, "french", "fr"),
+        (Language::German, "german", "de"),
+        (Language::Greek, "greek", "el"),
+        (Language::Hungarian, "hungarian", "hu"),
+        (Language::Italian, "italian", "it"),
+        (Language::Norwegian, "norwegian", "no"),
+        (Language::Portuguese, "portuguese", "pt"),
+        (Language::Romanian, "romanian", "ro"),
+        (Language::Russian, "russian", "ru"),
+        (Language::Spanish, "spanish", "es"),
+        (Language::Swedish, "swedish", "sv"),
+        (Language::Tamil, "tamil", "ta"),
+        (Language::Turkish, "turkish", "tr"),
+        (Language::Chinese, "chinese", "zh"),
+        (Language::Japanese, "japanese", "ja"),
+        (Language::Korean, "korean", "ko"),
+    ];
+
+    /// Canonical lowercase name.
+    pub fn name(self) -> &'static str {
+        Self::NAMES
+            .iter()
+            .find(|(l, _, _)| *l == self)
+            .map(|(_, name, _)| *name)
+            .unwrap_or("simple")
+    }
+
+    /// Snowball algorithm for this language, if it has one.
+    fn algorithm(self) -> Option<Algorithm> {
+        Some(match self {
+            Language::Arabic => Algorithm::Arabic,
+            Language::Danish => Algorithm::Danish,
+            Language::Dutch => Algorithm::Dutch,
+            Language::English => Algorithm::English,
+            Language::Finnish => Algorithm::Finnish,
+            Language::French => Algorithm::French,
+            Language::German => Algorithm::German,
+            Language::Greek => Algorithm::Greek,
+            Language::Hungarian => Algorithm::Hungarian,
+            Language::Italian => Algorithm::Italian,
+            Language::Norwegian => Algorithm::Norwegian,
+            Language::Portuguese => Algorithm::Portuguese,
+            Language::Romanian => Algorithm::Romanian,
+            Language::Russian => Algorithm::Russian,
+            Language::Spanish => Algorithm::Spanish,
+            Language::Swedish => Algorithm::Swedish,
+            Language::Tamil => Algorithm::Tamil,
+            Language::Turkish => Algorithm::Turkish,
+            Language::Simple
+            | Language::None
+            | Language::Chinese
+            | Language::Japanese
+            | Language::Korean => return None,
+        })
+    }
+
+    /// Built-in stop words for this language, empty where there is no list.
+    pub fn default_stopwords(self) -> &'static [&'static str] {
+        match self {
+            Language::Simple | Language::English => ENGLISH_STOP_WORDS,
+            Language::German => GERMAN_STOP_WORDS,
+            Language::French => FRENCH_STOP_WORDS,
+            Language::Spanish => SPANISH_STOP_WORDS,
+            _ => &[],
+        }
+    }
+}
+
+impl FromStr for Language {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.trim().to_lowercase();
+        // Region subtags don't affect stemming: "pt-BR" -> "pt"
+        let base = lower.split(['-', '_']).next().unwrap_or_default();
+        let alias = match base {
+            "nb" | "nn" => "no",
+            other => other,
+        };
+        Self::NAMES
+            .iter()
+            .find(|(_, name, code)| *name == alias || (!code.is_empty() && *code == alias))
+            .map(|(l, _, _)| *l)
+            .ok_or_else(|| format!("unknown language '{s}'"))
+    }
+}
+
+impl TryFrom<String> for Language {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<Language> for String {
+    fn from(l: Language) -> Self {
+        l.name().to_string()
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Turns text into index terms. Documents and queries must go through the
+/// same tokenizer for their terms to line up.
+pub trait Tokenizer {
+    /// Split `text` into normalized terms.
+    fn tokenize(&self, text: &str) -> Vec<String>;
+}
+
+/// The standard analysis pipeline: lowercase, split on non-word characters,
+/// emit overlapping bigrams for CJK runs, drop stop words, then stem.
+pub struct Analyzer<'a> {
+    lowercase: bool,
+    stopwords: &'a [String],
+    stemmer: StemMode,
+}
+
+/// How an [`Analyzer`] reduces words to stems.
+enum StemMode {
+    None,
+    Simple,
+    Snowball(Stemmer),
+}
+
+impl<'a> Analyzer<'a> {
+    /// Create an analyzer for `language` with the given stop words.
+    pub fn new(language: Language, lowercase: bool, stopwords: &'a [String]) -> Self {
+        let stemmer = match language {
+            Language::Simple => StemMode::Simple,
+            other => match other.algorithm() {
+                Some(alg) => StemMode::Snowball(Stemmer::create(alg)),
+                None => StemMode::None,
+            },
+        };
+        Self {
+            lowercase,
+            stopwords,
+            stemmer,
+        }
+    }
+
+    /// Stem a word and append it unless it is a stop word.
+    fn push_word(&self, word: &mut String, terms: &mut Vec<String>) {
+        if word.is_empty() {
+            return;
+        }
+        if !self.stopwords.iter().any(|s| s == word) {
+            terms.push(match &self.stemmer {
+                StemMode::None => word.clone(),
+                StemMode::Simple => stem_simple(word),
+                StemMode::Snowball(stemmer) => stemmer.stem(word).into_owned(),
+            });
+        }
+        word.clear();
+    }
+}
+
+impl Tokenizer for Analyzer<'_> {
+    fn tokenize(&self, text: &str) -> Vec<String> {
+        let text = if self.lowercase {
+            text.to_lowercase()
+        } else {
+            text.to_string()
+        };
+
+        let mut terms = Vec::new();
+        let mut word = String::new();
+        let mut cjk = Vec::new();
+
+        for c in text.chars() {
+            if is_cjk(c) {
+                self.push_word(&mut word, &mut terms);
+                cjk.push(c);
+            } else if c.is_alphanumeric() || c == '_' {
+                push_bigrams(&mut cjk, &mut terms);
+                word.push(c);
+            } else {
+                self.push_word(&mut word, &mut terms);
+                push_bigrams(&mut cjk, &mut terms);
+            }
+        }
+        self.push_word(&mut word, &mut terms);
+        push_bigrams(&mut cjk, &mut terms);
+
+        terms
+    }
+}
+
+/// Whether a character belongs to a CJK script that doesn't separate
+/// words with spaces (Han, kana, Hangul).
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}'    // Hiragana, Katakana
+        | '\u{31F0}'..='\u{31FF}'  // Katakana phonetic extensions
+        | '\u{3400}'..='\u{4DBF}'  // CJK extension A
+        | '\u{4E00}'..='\u{9FFF}'  // CJK unified ideographs
+        | '\u{AC00}'..='\u{D7AF}'  // Hangul syllables
+        | '\u{F900}'..='\u{FAFF}'  // CJK compatibility ideographs
+        | '\u{FF66}'..='\u{FF9F}'  // Half-width Katakana
+        | '\u{20000}'..='\u{2A6DF}' // CJK extension B
+    )
+}
+
+/// Emit overlapping character bigrams for a CJK run (a lone character is
+/// emitted as-is), then clear the run.
+fn push_bigrams(run: &mut Vec<char>, terms: &mut Vec<String>) {
+    match run.len() {
+        0 => {}
+        1 => terms.push(run[0].to_string()),
+        _ => terms.extend(run.windows(2).map(|w| w.iter().collect::<String>())),
+    }
+    run.clear();
+}
+
 /// Search result
 #[derive(Debug, Clone)]
 pub struct SearchResult {
@@ -130,86 +480,207 @@ pub struct SearchResult {
     pub matches: HashMap<String, Vec<String>>,
 }
 
+/// A document similar to a reference document, see [`Bm25Index::similar`].
+#[derive(Debug, Clone)]
+pub struct SimilarResult {
+    /// Document ID
+    pub id: String,
+
+    /// Cosine similarity of BM25 term weights, `0.0..=1.0`
+    pub score: f64,
+
+    /// Terms both documents contain, by contribution (strongest first)
+    pub shared_terms: Vec<String>,
+}
+
 impl Bm25Index {
     /// Create a new empty index with the given options
     pub fn new(options: IndexOptions) -> Self {
         Self {
             options,
             doc_count: 0,
+            next_id: 0,
             avg_doc_length: 0.0,
+            total_length: 0,
             docs: HashMap::new(),
             terms: HashMap::new(),
+            languages: HashMap::new(),
         }
     }
 
     /// Build an index from an array of documents
     pub fn build(docs: &[serde_json::Value], options: IndexOptions) -> Self {
         let mut index = Self::new(options);
-        let mut total_length = 0usize;
 
         for (i, doc) in docs.iter().enumerate() {
             let doc_id = index.get_doc_id(doc, i);
             let tdoc = index.tokenize_doc(doc);
-            let doc_length = tdoc.tokens.len();
-            total_length += doc_length;
+            let language = index.options.language;
+            index.insert_tokenized(doc_id, tdoc, language);
+        }
+        index.next_id = docs.len();
 
-            // Store document info
-            index.docs.insert(
-                doc_id.clone(),
-                DocInfo {
-                    length: doc_length,
-                    field_lengths: tdoc.field_lengths,
-                },
-            );
+        index
+    }
+
+    /// Add a document to the index, returning its ID.
+    ///
+    /// Documents without an ID field are assigned the next positional ID.
+    /// If a document with the same ID is already indexed it is replaced.
+    pub fn add_document(&mut self, doc: &serde_json::Value) -> String {
+        let doc_id = self.get_doc_id(doc, self.next_id);
+        self.next_id += 1;
+        let tdoc = self.tokenize_doc(doc);
+        self.insert_tokenized(doc_id.clone(), tdoc, self.options.language);
+        doc_id
+    }
+
+    /// Add a document from `(field, text)` pairs without going through JSON.
+    ///
+    /// Fields not listed in `options.fields` are ignored; when no fields are
+    /// configured, all text is indexed as a single unweighted body.
+    /// If a document with the same ID is already indexed it is replaced.
+    pub fn add_fields(&mut self, doc_id: &str, fields: &[(&str, &str)]) {
+        self.add_fields_in(doc_id, fields, self.options.language);
+    }
+
+    /// Like [`add_fields`](Self::add_fields), analyzing the text as `language`
+    /// instead of the index default.
+    pub fn add_fields_in(&mut self, doc_id: &str, fields: &[(&str, &str)], language: Language) {
+        let analyzer = self.analyzer(language);
+        let mut tdoc = TokenizedDoc {
+            tokens: Vec::new(),
+            field_lengths: HashMap::new(),
+            field_tokens: HashMap::new(),
+        };
+
+        for (field, text) in fields {
+            if self.options.fields.is_empty() {
+                tdoc.tokens.extend(analyzer.tokenize(text));
+            } else if self.options.fields.iter().any(|f| f == field) {
+                let ft = analyzer.tokenize(text);
+                tdoc.field_lengths.insert(field.to_string(), ft.len());
+                tdoc.tokens.extend(ft.iter().cloned());
+                tdoc.field_tokens.insert(field.to_string(), ft);
+            }
+        }
 
-            // Build per-field term frequency maps
-            let mut field_term_freqs: HashMap<String, HashMap<String, usize>> = HashMap::new();
-            for (field, ftokens) in &tdoc.field_tokens {
-                let ftf = field_term_freqs.entry(field.clone()).or_default();
-                for token in ftokens {
-                    *ftf.entry(token.clone()).or_insert(0) += 1;
+        self.insert_tokenized(doc_id.to_string(), tdoc, language);
+    }
+
+    /// Replace an indexed document. Equivalent to remove + add.
+    pub fn update_document(&mut self, doc: &serde_json::Value) -> String {
+        self.add_document(doc)
+    }
+
+    /// Remove a document and its postings. Returns false if the ID is unknown.
+    ///
+    /// Document frequencies, the document count, and the average document
+    /// length are adjusted so IDF and length normalization stay exact.
+    pub fn remove_document(&mut self, doc_id: &str) -> bool {
+        let Some(info) = self.docs.remove(doc_id) else {
+            return false;
+        };
+
+        for term in &info.terms {
+            if let Some(term_info) = self.terms.get_mut(term)
+                && term_info.postings.remove(doc_id).is_some()
+            {
+                term_info.df -= 1;
+                if term_info.df == 0 {
+                    self.terms.remove(term);
                 }
             }
+        }
 
-            // Update inverted index with total + per-field frequencies
-            let mut term_freqs: HashMap<String, usize> = HashMap::new();
-            for token in tdoc.tokens {
-                *term_freqs.entry(token).or_insert(0) += 1;
+        if let Some(count) = self.languages.get_mut(&info.language) {
+            *count -= 1;
+            if *count == 0 {
+                self.languages.remove(&info.language);
             }
+        }
 
-            for (term, freq) in term_freqs {
-                let term_info = index.terms.entry(term.clone()).or_insert(TermInfo {
-                    df: 0,
-                    postings: HashMap::new(),
-                });
-                term_info.df += 1;
-
-                // Collect per-field frequencies for this term
-                let mut field_freqs = HashMap::new();
-                for (field, ftf) in &field_term_freqs {
-                    if let Some(&ff) = ftf.get(&term) {
-                        field_freqs.insert(field.clone(), ff);
-                    }
-                }
+        self.doc_count -= 1;
+        self.total_length -= info.length;
+        self.update_avg_doc_length();
+        true
+    }
 
-                term_info.postings.insert(
-                    doc_id.clone(),
-                    TermPostings {
-                        total_freq: freq,
-                        field_freqs,
-                    },
-                );
+    /// Store a tokenized document and update the inverted index.
+    fn insert_tokenized(&mut self, doc_id: String, tdoc: TokenizedDoc, language: Language) {
+        // Replacing an existing document: drop its old postings first
+        self.remove_document(&doc_id);
+
+        let doc_length = tdoc.tokens.len();
+
+        // Build per-field term frequency maps
+        let mut field_term_freqs: HashMap<String, HashMap<String, usize>> = HashMap::new();
+        for (field, ftokens) in &tdoc.field_tokens {
+            let ftf = field_term_freqs.entry(field.clone()).or_default();
+            for token in ftokens {
+                *ftf.entry(token.clone()).or_insert(0) += 1;
             }
+        }
 
-            index.doc_count += 1;
+        // Update inverted index with total + per-field frequencies
+        let mut term_freqs: HashMap<String, usize> = HashMap::new();
+        for token in tdoc.tokens {
+            *term_freqs.entry(token).or_insert(0) += 1;
         }
 
-        // Calculate average document length
-        if index.doc_count > 0 {
-            index.avg_doc_length = total_length as f64 / index.doc_count as f64;
+        let mut doc_terms = Vec::with_capacity(term_freqs.len());
+        for (term, freq) in term_freqs {
+            let term_info = self.terms.entry(term.clone()).or_insert(TermInfo {
+                df: 0,
+                postings: HashMap::new(),
+            });
+            term_info.df += 1;
+
+            // Collect per-field frequencies for this term
+            let mut field_freqs = HashMap::new();
+            for (field, ftf) in &field_term_freqs {
+                if let Some(&ff) = ftf.get(&term) {
+                    field_freqs.insert(field.clone(), ff);
+                }
+            }
+
+            term_info.postings.insert(
+                doc_id.clone(),
+                TermPostings {
+                    total_freq: freq,
+                    field_freqs,
+                },
+            );
+            doc_terms.push(term);
         }
 
-        index
+        // Store document info
+        self.docs.insert(
+            doc_id,
+            DocInfo {
+                length: doc_length,
+                field_lengths: tdoc.field_lengths,
+                terms: doc_terms,
+                language,
+            },
+        );
+
+        if language != self.options.language {
+            *self.languages.entry(language).or_insert(0) += 1;
+        }
+
+        self.doc_count += 1;
+        self.total_length += doc_length;
+        self.update_avg_doc_length();
+    }
+
+    /// Recalculate the average document length from the running total.
+    fn update_avg_doc_length(&mut self) {
+        self.avg_doc_length = if self.doc_count > 0 {
+            self.total_length as f64 / self.doc_count as f64
+        } else {
+            0.0
+        };
     }
 
     /// Get document ID from a document
@@ -283,19 +754,43 @@ impl Bm25Index {
         }
     }
 
-    /// Tokenize text into terms
-    pub fn tokenize_text(&self, text: &str) -> Vec<String> {
-        let text = if self.options.lowercase {
-            text.to_lowercase()
+    /// The analysis pipeline for `language`.
+    ///
+    /// The index default language uses the configured stop words; other
+    /// languages use their own built-in lists.
+    pub fn analyzer(&self, language: Language) -> Analyzer<'_> {
+        let stopwords: &[String] = if language == self.options.language {
+            &self.options.stopwords
         } else {
-            text.to_string()
+            DEFAULT_STOP_WORDS
+                .get(&language)
+                .map(Vec::as_slice)
+                .unwrap_or(&[])
         };
+        Analyzer::new(language, self.options.lowercase, stopwords)
+    }
 
-        text.split(|c: char| !c.is_alphanumeric() && c != '_')
-            .filter(|s| !s.is_empty())
-            .filter(|s| !self.options.stopwords.contains(&s.to_string()))
-            .map(stem_simple)
-            .collect()
+    /// Tokenize text into terms using the index default language.
+    pub fn tokenize_text(&self, text: &str) -> Vec<String> {
+        self.analyzer(self.options.language).tokenize(text)
+    }
+
+    /// Tokenize a query for every language present in the index.
+    ///
+    /// A document analyzed as German only has German stems, so the query
+    /// is run through each language's pipeline and the terms are merged.
+    pub fn tokenize_query(&self, text: &str) -> Vec<String> {
+        let mut terms = self.tokenize_text(text);
+        let mut languages: Vec<Language> = self.languages.keys().copied().collect();
+        languages.sort_by_key(|l| l.name());
+        for language in languages {
+            for term in self.analyzer(language).tokenize(text) {
+                if !terms.contains(&term) {
+                    terms.push(term);
+                }
+            }
+        }
+        terms
     }
 }
 
@@ -453,7 +948,8 @@ impl Bm25Index {
     ///
     /// When `field_weights` are configured, uses BM25F-style scoring where
     /// weighted TF = sum(weight_f * tf_f) replaces the flat term frequency.
-    fn score_doc(&self, doc_id: &str, query_terms: &[String]) -> f64 {
+    /// Each query term's contribution is scaled by its query weight.
+    fn score_doc(&self, doc_id: &str, query_terms: &[(String, f64)]) -> f64 {
         let doc_info = match self.docs.get(doc_id) {
             Some(info) => info,
             None => return 0.0,
@@ -463,48 +959,140 @@ impl Bm25Index {
         let k1 = self.options.k1;
         let b = self.options.b;
         let avgdl = self.avg_doc_length;
-        let use_field_weights = !self.options.field_weights.is_empty();
 
         let mut score = 0.0;
 
-        for term in query_terms {
+        for (term, query_weight) in query_terms {
             let idf = self.idf(term);
             let postings = self.terms.get(term).and_then(|t| t.postings.get(doc_id));
 
-            let tf = match postings {
-                Some(p) if use_field_weights => {
-                    // BM25F: weighted TF = sum(weight * field_tf)
-                    let mut weighted = 0.0;
-                    for (field, &freq) in &p.field_freqs {
-                        let w = self
-                            .options
-                            .field_weights
-                            .get(field)
-                            .copied()
-                            .unwrap_or(1.0);
-                        weighted += w * freq as f64;
-                    }
-                    weighted
-                }
-                Some(p) => p.total_freq as f64,
-                None => 0.0,
-            };
+            let tf = postings.map(|p| self.weighted_tf(p)).unwrap_or(0.0);
 
             if tf > 0.0 {
                 // BM25 formula
                 let numerator = tf * (k1 + 1.0);
                 let denominator = tf + k1 * (1.0 - b + b * doc_length / avgdl);
-                score += idf * numerator / denominator;
+                score += query_weight * idf * numerator / denominator;
             }
         }
 
         score
     }
 
+    /// Term frequency for a posting, field-weighted when `field_weights`
+    /// are configured (BM25F: weighted TF = sum(weight * field_tf)).
+    fn weighted_tf(&self, postings: &TermPostings) -> f64 {
+        if self.options.field_weights.is_empty() {
+            return postings.total_freq as f64;
+        }
+        postings
+            .field_freqs
+            .iter()
+            .map(|(field, &freq)| {
+                let w = self
+                    .options
+                    .field_weights
+                    .get(field)
+                    .copied()
+                    .unwrap_or(1.0);
+                w * freq as f64
+            })
+            .sum()
+    }
+
+    /// BM25 weight of every term in a document: what each term would
+    /// contribute if it were a query term of weight 1.0.
+    pub fn term_weights(&self, doc_id: &str) -> HashMap<String, f64> {
+        let Some(doc_info) = self.docs.get(doc_id) else {
+            return HashMap::new();
+        };
+        let k1 = self.options.k1;
+        let norm =
+            1.0 - self.options.b + self.options.b * doc_info.length as f64 / self.avg_doc_length;
+
+        doc_info
+            .terms
+            .iter()
+            .filter_map(|term| {
+                let postings = self.terms.get(term)?.postings.get(doc_id)?;
+                let tf = self.weighted_tf(postings);
+                let weight = self.idf(term) * tf * (k1 + 1.0) / (tf + k1 * norm);
+                (weight > 0.0).then(|| (term.clone(), weight))
+            })
+            .collect()
+    }
+
+    /// Documents most similar to `doc_id`, by cosine similarity of their
+    /// [`term_weights`](Self::term_weights) vectors.
+    ///
+    /// Each result lists the shared terms that contribute most to the
+    /// similarity, strongest first.
+    pub fn similar(&self, doc_id: &str, top_k: usize) -> Vec<SimilarResult> {
+        let source = self.term_weights(doc_id);
+        let source_norm = source.values().map(|w| w * w).sum::<f64>().sqrt();
+        if source_norm == 0.0 {
+            return Vec::new();
+        }
+
+        let mut candidates: HashSet<&str> = HashSet::new();
+        for term in source.keys() {
+            if let Some(info) = self.terms.get(term) {
+                candidates.extend(info.postings.keys().map(String::as_str));
+            }
+        }
+        candidates.remove(doc_id);
+
+        let mut results: Vec<SimilarResult> = candidates
+            .into_iter()
+            .filter_map(|other| {
+                let weights = self.term_weights(other);
+                let norm = weights.values().map(|w| w * w).sum::<f64>().sqrt();
+                let mut shared: Vec<(&String, f64)> = source
+                    .iter()
+                    .filter_map(|(term, w)| Some((term, w * weights.get(term)?)))
+                    .collect();
+                let dot: f64 = shared.iter().map(|(_, c)| c).sum();
+                if dot <= 0.0 || norm == 0.0 {
+                    return None;
+                }
+                shared.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+                Some(SimilarResult {
+                    id: other.to_string(),
+                    score: dot / (source_norm * norm),
+                    shared_terms: shared.into_iter().map(|(t, _)| t.clone()).collect(),
+                })
+            })
+            .collect();
+
+        results.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        results.truncate(top_k);
+        results
+    }
+
     /// Search the index
     pub fn search(&self, query: &str, top_k: usize) -> Vec<SearchResult> {
-        let query_terms = self.tokenize_text(query);
+        let query_terms: Vec<(String, f64)> = self
+            .tokenize_query(query)
+            .into_iter()
+            .map(|t| (t, 1.0))
+            .collect();
+        self.search_weighted(&query_terms, top_k)
+    }
 
+    /// Search the index with pre-tokenized, individually weighted query terms.
+    ///
+    /// Used for query expansion: expanded terms carry a weight below 1.0 so
+    /// they contribute less than the terms the user actually typed.
+    pub fn search_weighted(
+        &self,
+        query_terms: &[(String, f64)],
+        top_k: usize,
+    ) -> Vec<SearchResult> {
         if query_terms.is_empty() {
             return Vec::new();
         }
@@ -512,7 +1100,7 @@ impl Bm25Index {
         // Find candidate documents (those containing at least one query term)
         let mut candidates: HashMap<String, f64> = HashMap::new();
 
-        for term in &query_terms {
+        for (term, _) in query_terms {
             if let Some(term_info) = self.terms.get(term) {
                 for doc_id in term_info.postings.keys() {
                     candidates.entry(doc_id.clone()).or_insert(0.0);
@@ -524,8 +1112,8 @@ impl Bm25Index {
         let mut results: Vec<SearchResult> = candidates
             .keys()
             .map(|doc_id| {
-                let score = self.score_doc(doc_id, &query_terms);
-                let matches = self.get_matches(doc_id, &query_terms);
+                let score = self.score_doc(doc_id, query_terms);
+                let matches = self.get_matches(doc_id, query_terms);
 
                 SearchResult {
                     id: doc_id.clone(),
@@ -549,10 +1137,14 @@ impl Bm25Index {
     }
 
     /// Get matched terms for a document
-    fn get_matches(&self, doc_id: &str, query_terms: &[String]) -> HashMap<String, Vec<String>> {
+    fn get_matches(
+        &self,
+        doc_id: &str,
+        query_terms: &[(String, f64)],
+    ) -> HashMap<String, Vec<String>> {
         let mut matches: HashMap<String, Vec<String>> = HashMap::new();
 
-        for term in query_terms {
+        for (term, _) in query_terms {
             if self
                 .terms
                 .get(term)
@@ -823,6 +1415,194 @@ mod tests {
         assert!(results[0].score > results[1].score);
     }
 
+    #[test]
+    fn test_search_weighted_scales_scores() {
+        let docs = vec![json!("kubernetes deploy"), json!("helm charts")];
+        let index = Bm25Index::build(&docs, IndexOptions::default());
+
+        let term = index.tokenize_text("kubernetes").remove(0);
+        let full = index.search_weighted(&[(term.clone(), 1.0)], 10);
+        let half = index.search_weighted(&[(term, 0.5)], 10);
+
+        assert_eq!(full.len(), 1);
+        assert!((half[0].score - full[0].score * 0.5).abs() < 1e-10);
+    }
+
+    fn field_options() -> IndexOptions {
+        IndexOptions {
+            fields: vec!["name".to_string(), "description".to_string()],
+            id_field: Some("name".to_string()),
+            field_weights: HashMap::from([
+                ("name".to_string(), 3.0),
+                ("description".to_string(), 1.0),
+            ]),
+            ..Default::default()
+        }
+    }
+
+    /// Compare results by id; tied scores have no stable order.
+    fn assert_same_results(a: &Bm25Index, b: &Bm25Index, query: &str) {
+        let ra: HashMap<_, _> = a
+            .search(query, 10)
+            .into_iter()
+            .map(|r| (r.id, r.score))
+            .collect();
+        let rb: HashMap<_, _> = b
+            .search(query, 10)
+            .into_iter()
+            .map(|r| (r.id, r.score))
+            .collect();
+        assert_eq!(ra.len(), rb.len(), "result count for {query:?}");
+        for (id, score) in &ra {
+            assert!(
+                (score - rb[id]).abs() < 1e-10,
+                "score for {id} on {query:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_document_matches_build() {
+        let docs = vec![
+            json!({"name": "create_cluster", "description": "Create a new Redis cluster"}),
+            json!({"name": "delete_cluster", "description": "Delete an existing cluster"}),
+            json!({"name": "create_backup", "description": "Create a backup of data"}),
+        ];
+        let built = Bm25Index::build(&docs, field_options());
+
+        let mut incremental = Bm25Index::new(field_options());
+        for doc in &docs {
+            incremental.add_document(doc);
+        }
+
+        assert_eq!(incremental.doc_count, built.doc_count);
+        assert_eq!(incremental.total_length, built.total_length);
+        assert!((incremental.avg_doc_length - built.avg_doc_length).abs() < 1e-10);
+        assert_same_results(&built, &incremental, "cluster");
+        assert_same_results(&built, &incremental, "create backup");
+    }
+
+    #[test]
+    fn test_remove_document_matches_rebuild() {
+        let docs = vec![
+            json!({"name": "create_cluster", "description": "Create a new Redis cluster"}),
+            json!({"name": "delete_cluster", "description": "Delete an existing cluster"}),
+            json!({"name": "create_backup", "description": "Create a backup of data"}),
+        ];
+        let mut index = Bm25Index::build(&docs, field_options());
+        assert!(index.remove_document("delete_cluster"));
+        assert!(!index.remove_document("delete_cluster"));
+
+        let rebuilt = Bm25Index::build(&[docs[0].clone(), docs[2].clone()], field_options());
+        assert_eq!(index.doc_count, 2);
+        assert_eq!(index.terms.len(), rebuilt.terms.len());
+        assert!(!index.terms.contains_key("delete"));
+        assert_same_results(&rebuilt, &index, "cluster");
+        assert_same_results(&rebuilt, &index, "create");
+    }
+
+    #[test]
+    fn test_update_document_replaces_postings() {
+        let docs = vec![
+            json!({"name": "tool", "description": "cluster management"}),
+            json!({"name": "other", "description": "backup data"}),
+        ];
+        let mut index = Bm25Index::build(&docs, field_options());
+        index.update_document(&json!({"name": "tool", "description": "backup restore"}));
+
+        let rebuilt = Bm25Index::build(
+            &[
+                json!({"name": "tool", "description": "backup restore"}),
+                docs[1].clone(),
+            ],
+            field_options(),
+        );
+        assert_eq!(index.doc_count, 2);
+        assert!(index.search("cluster", 10).is_empty());
+        assert_same_results(&rebuilt, &index, "backup");
+    }
+
+    #[test]
+    fn test_similar_ranks_by_shared_terms() {
+        let docs = vec![
+            json!({"name": "redis_cluster", "description": "Manage a Redis cluster and its nodes"}),
+            json!({"name": "redis_backup", "description": "Back up a Redis cluster to disk"}),
+            json!({"name": "pdf_export", "description": "Export documents as PDF"}),
+        ];
+        let index = Bm25Index::build(&docs, field_options());
+
+        let similar = index.similar("redis_cluster", 10);
+        assert_eq!(similar.len(), 1, "pdf_export shares no terms");
+        assert_eq!(similar[0].id, "redis_backup");
+        assert!(similar[0].score > 0.0 && similar[0].score <= 1.0);
+        let redis = index.tokenize_text("redis").remove(0);
+        assert!(similar[0].shared_terms.contains(&redis));
+        assert!(index.similar("missing", 10).is_empty());
+    }
+
+    #[test]
+    fn test_similar_identical_documents() {
+        let docs = vec![
+            json!({"id": "a", "description": "lint shell scripts"}),
+            json!({"id": "b", "description": "lint shell scripts"}),
+            json!({"id": "c", "description": "format python code"}),
+        ];
+        let options = IndexOptions {
+            fields: vec!["description".to_string()],
+            id_field: Some("id".to_string()),
+            ..Default::default()
+        };
+        let index = Bm25Index::build(&docs, options);
+        let similar = index.similar("a", 10);
+        assert_eq!(similar.len(), 1);
+        assert_eq!(similar[0].id, "b");
+        assert!((similar[0].score - 1.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_add_fields_matches_json_document() {
+        let mut from_json = Bm25Index::new(field_options());
+        from_json.add_document(&json!({"name": "rust", "description": "systems language"}));
+
+        let mut from_fields = Bm25Index::new(field_options());
+        from_fields.add_fields(
+            "rust",
+            &[
+                ("name", "rust"),
+                ("description", "systems language"),
+                ("ignored", "not indexed"),
+            ],
+        );
+
+        assert_eq!(from_fields.total_length, from_json.total_length);
+        assert!(!from_fields.terms.contains_key("ignored"));
+        assert_same_results(&from_json, &from_fields, "systems rust");
+    }
+
+    #[test]
+    fn test_positional_ids_not_reused_after_remove() {
+        let mut index = Bm25Index::build(
+            &[json!("alpha docs"), json!("beta docs")],
+            IndexOptions::default(),
+        );
+        assert!(index.remove_document("0"));
+
+        let id = index.add_document(&json!("gamma docs"));
+        assert_ne!(id, "1");
+        assert_eq!(index.doc_count, 2);
+        assert_eq!(index.search("beta", 10)[0].id, "1");
+        assert_eq!(index.search("gamma", 10)[0].id, id);
+    }
+
+    #[test]
+    fn test_remove_last_document_resets_stats() {
+        let mut index = Bm25Index::build(&[json!("hello world")], IndexOptions::default());
+        assert!(index.remove_document("0"));
+        assert_eq!(index.doc_count, 0);
+        assert_eq!(index.avg_doc_length, 0.0);
+        assert!(index.terms.is_empty());
+    }
+
     #[test]
     fn test_field_weights_backward_compatible() {
         // Empty field_weights should produce the same scores as no weighting
@@ -856,4 +1636,94 @@ mod tests {
             assert!((a.score - b.score).abs() < 1e-10);
         }
     }
+
+    #[test]
+    fn test_language_parse() {
+        assert_eq!("english".parse::<Language>().unwrap(), Language::English);
+        assert_eq!("EN".parse::<Language>().unwrap(), Language::English);
+        assert_eq!("ja-JP".parse::<Language>().unwrap(), Language::Japanese);
+        assert_eq!("pt_BR".parse::<Language>().unwrap(), Language::Portuguese);
+        assert_eq!("nb".parse::<Language>().unwrap(), Language::Norwegian);
+        assert!("klingon".parse::<Language>().is_err());
+        assert_eq!(Language::German.to_string(), "german");
+    }
+
+    #[test]
+    fn test_analyzer_porter2() {
+        let analyzer = Analyzer::new(Language::English, true, &[]);
+        assert_eq!(
+            analyzer.tokenize("Running generalizations"),
+            vec!["run", "general"]
+        );
+    }
+
+    #[test]
+    fn test_analyzer_stopwords() {
+        let stop = vec!["der".to_string()];
+        let analyzer = Analyzer::new(Language::German, true, &stop);
+        assert_eq!(analyzer.tokenize("der Häuser"), vec!["haus"]);
+    }
+
+    #[test]
+    fn test_non_default_languages_use_their_own_stop_words() {
+        let index = Bm25Index::new(IndexOptions {
+            stopwords: ENGLISH_STOP_WORDS.iter().map(|s| s.to_string()).collect(),
+            ..Default::default()
+        });
+        assert_eq!(
+            index.analyzer(Language::German).tokenize("der Hund is"),
+            vec!["hund", "is"]
+        );
+        assert_eq!(
+            index.analyzer(Language::Spanish).tokenize("el perro the"),
+            vec!["perr", "the"]
+        );
+        // No built-in list: nothing is dropped
+        assert_eq!(
+            index.analyzer(Language::Italian).tokenize("il cane the"),
+            vec!["il", "can", "the"]
+        );
+    }
+
+    #[test]
+    fn test_analyzer_cjk_bigrams() {
+        let analyzer = Analyzer::new(Language::English, true, &[]);
+        assert_eq!(
+            analyzer.tokenize("日本語のテスト"),
+            vec!["日本", "本語", "語の", "のテ", "テス", "スト"]
+        );
+        // Mixed scripts split at the boundary; lone CJK chars are unigrams
+        assert_eq!(analyzer.tokenize("rust入門 字"), vec!["rust", "入門", "字"]);
+    }
+
+    #[test]
+    fn test_search_cjk_document() {
+        let mut index = Bm25Index::new(IndexOptions::default());
+        index.add_fields("ja", &[("body", "データベースの移行ツール")]);
+        index.add_fields("en", &[("body", "database migration tool")]);
+
+        let results = index.search("移行", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "ja");
+    }
+
+    #[test]
+    fn test_per_document_language() {
+        let options = IndexOptions {
+            language: Language::English,
+            ..Default::default()
+        };
+        let mut index = Bm25Index::new(options);
+        index.add_fields_in("de", &[("body", "Datenbanken verwalten")], Language::German);
+        index.add_fields("en", &[("body", "managing databases")]);
+        assert_eq!(index.languages.get(&Language::German), Some(&1));
+
+        // The German stem is only produced by the German pipeline
+        let results = index.search("Datenbank", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "de");
+
+        index.remove_document("de");
+        assert!(index.languages.is_empty());
+    }
 }
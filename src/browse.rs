@@ -0,0 +1,179 @@
+//! Hierarchical browsing of the skill catalog.
+//!
+//! Categories can be paths (`development/rust/testing`), and nested skills
+//! get an implicit group from their `repo_path`: `acme/lang/java/maven-build`
+//! sits under `lang/java`. [`browse`] walks that tree one level at a time,
+//! returning the child groups with skill counts and the skills filed
+//! directly at the requested level.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::state::{SkillEntry, SkillIndex, SkillSummary};
+use crate::taxonomy;
+
+/// A child group one level below the browsed path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Group {
+    /// Last path segment (e.g. "rust").
+    pub name: String,
+    /// Full path, usable as the next `browse` path (e.g. "development/rust").
+    pub path: String,
+    /// Distinct skills at or below this group.
+    pub count: usize,
+}
+
+/// One level of the category tree.
+#[derive(Debug, Clone)]
+pub struct Listing {
+    /// The browsed path ("" for the root).
+    pub path: String,
+    pub groups: Vec<Group>,
+    /// Skills filed exactly at this path, sorted by owner and name.
+    pub skills: Vec<SkillSummary>,
+}
+
+impl Listing {
+    /// Whether nothing lives at or below this path.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty() && self.skills.is_empty()
+    }
+}
+
+/// Every tree path a skill is filed under: its categories plus the group
+/// derived from a nested `repo_path` (the directories between owner and
+/// skill name).
+pub fn skill_paths(entry: &SkillEntry) -> Vec<String> {
+    let mut paths: Vec<String> = entry
+        .latest()
+        .and_then(|v| v.metadata.skill.classification.as_ref())
+        .map(|c| c.categories.clone())
+        .unwrap_or_default();
+
+    if let Some(ref rpath) = entry.repo_path {
+        let segments: Vec<&str> = rpath.split('/').collect();
+        if segments.len() > 2 {
+            let group = taxonomy::normalize(&segments[1..segments.len() - 1].join("/"));
+            if !group.is_empty() && !paths.contains(&group) {
+                paths.push(group);
+            }
+        }
+    }
+    paths
+}
+
+/// List the groups and skills at `path` ("" for the root). The path should
+/// be canonical (see [`taxonomy::canonical`]).
+pub fn browse(index: &SkillIndex, path: &str) -> Listing {
+    let path = path.trim_matches('/');
+    let prefix = if path.is_empty() {
+        String::new()
+    } else {
+        format!("{path}/")
+    };
+
+    let mut children: BTreeMap<String, BTreeSet<(&str, &str)>> = BTreeMap::new();
+    let mut skills = Vec::new();
+
+    for entry in index.skills.values() {
+        let paths = skill_paths(entry);
+        let mut here = false;
+        for p in &paths {
+            if p == path {
+                here = true;
+            } else if let Some(rest) = p.strip_prefix(&prefix) {
+                let child = rest.split('/').next().unwrap_or(rest);
+                children
+                    .entry(child.to_string())
+                    .or_default()
+                    .insert((entry.owner.as_str(), entry.name.as_str()));
+            }
+        }
+        if here && let Some(summary) = SkillSummary::from_entry(entry) {
+            skills.push(summary);
+        }
+    }
+
+    skills.sort_by(|a, b| (&a.owner, &a.name).cmp(&(&b.owner, &b.name)));
+
+    Listing {
+        path: path.to_string(),
+        groups: children
+            .into_iter()
+            .map(|(name, members)| Group {
+                path: format!("{prefix}{name}"),
+                name,
+                count: members.len(),
+            })
+            .collect(),
+        skills,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testutil::TestRepo;
+
+    fn index() -> SkillIndex {
+        let repo = TestRepo::standard();
+        crate::index::load_index(repo.path(), &crate::index::FileLimits::default())
+            .expect("load test repo")
+    }
+
+    #[test]
+    fn test_root_lists_top_level_groups() {
+        let listing = browse(&index(), "");
+        let dev = listing
+            .groups
+            .iter()
+            .find(|g| g.name == "development")
+            .expect("development group");
+        assert_eq!(dev.path, "development");
+        assert!(dev.count >= 3);
+        assert!(listing.skills.is_empty());
+    }
+
+    #[test]
+    fn test_repo_path_groups_are_browsable() {
+        let index = index();
+        let root = browse(&index, "");
+        assert!(root.groups.iter().any(|g| g.name == "lang"));
+
+        let lang = browse(&index, "lang");
+        assert_eq!(lang.groups.len(), 1);
+        assert_eq!(lang.groups[0].path, "lang/java");
+        assert_eq!(lang.groups[0].count, 2);
+
+        let java = browse(&index, "lang/java/");
+        assert!(java.groups.is_empty());
+        let names: Vec<&str> = java.skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["gradle-build", "maven-build"]);
+    }
+
+    #[test]
+    fn test_hierarchical_categories() {
+        let mut index = index();
+        let entry = index
+            .skills
+            .get_mut(&("joshrotenberg".to_string(), "rust-dev".to_string()))
+            .expect("rust-dev");
+        for v in &mut entry.versions {
+            if let Some(ref mut c) = v.metadata.skill.classification {
+                c.categories = vec!["development/rust/testing".to_string()];
+            }
+        }
+
+        let dev = browse(&index, "development");
+        assert!(dev.groups.iter().any(|g| g.path == "development/rust"));
+        assert!(!dev.skills.iter().any(|s| s.name == "rust-dev"));
+
+        let testing = browse(&index, "development/rust/testing");
+        assert_eq!(testing.skills.len(), 1);
+        assert_eq!(testing.skills[0].name, "rust-dev");
+    }
+
+    #[test]
+    fn test_unknown_path_is_empty() {
+        assert!(browse(&index(), "no/such/path").is_empty());
+    }
+}
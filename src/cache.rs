@@ -4,6 +4,12 @@
 //! doesn't invalidate others. The cache stores skill entries as a flat
 //! `Vec` (since `HashMap<(String, String), _>` doesn't serialize cleanly
 //! to JSON) and reconstructs the full `SkillIndex` on load.
+//!
+//! The built BM25 search index is persisted alongside, keyed by a
+//! fingerprint of the per-repo cache files and repo HEADs it was built
+//! from (plus a hash of the merged documents, see
+//! `SkillSearch::load_or_build`), so CLI searches can skip re-tokenizing
+//! every skill.
 
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
@@ -11,15 +17,31 @@ use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use serde::{Deserialize, Serialize};
 
+use crate::bm25::Bm25Index;
 use crate::git;
-use crate::state::{SkillEntry, SkillIndex};
+use crate::state::{LoadDiagnostic, SkillEntry, SkillIndex};
 
 /// Bump this to invalidate all caches when the format changes.
 /// v2: added trust_tier, discovered_via to SkillEntry
-const CACHE_VERSION: u32 = 2;
+/// v3: added language to SkillInfo
+/// v4: added origin to SkillEntry
+/// v5: nested and binary skillpack files
+/// v6: added files patterns to SkillInfo
+/// v7: added load diagnostics
+/// v8: full frontmatter, allowed-tools and model
+const CACHE_VERSION: u32 = 8;
+
+/// Bump this to invalidate persisted search indexes when the format changes.
+/// v2: per-document analysis language
+/// v3: monotonic positional document IDs
+/// v4: per-language built-in stop words
+const SEARCH_CACHE_VERSION: u32 = 4;
+
+/// File name of the persisted search index within the cache directory.
+const SEARCH_CACHE_FILE: &str = "search.json";
 
 /// Identifies the source of a repo for cache path derivation.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum RepoSource {
     /// A local filesystem repo.
     Local(PathBuf),
@@ -35,6 +57,24 @@ struct CachedIndex {
     cached_at: u64,
     skills: Vec<SkillEntry>,
     categories: BTreeMap<String, usize>,
+    #[serde(default)]
+    diagnostics: Vec<LoadDiagnostic>,
+}
+
+/// Serialized search index file.
+#[derive(Deserialize)]
+struct CachedSearch {
+    version: u32,
+    key: String,
+    index: Bm25Index,
+}
+
+/// Borrowed form of [`CachedSearch`] for writing without cloning the index.
+#[derive(Serialize)]
+struct CachedSearchRef<'a> {
+    version: u32,
+    key: &'a str,
+    index: &'a Bm25Index,
 }
 
 /// Compute the cache file path relative to a given base directory.
@@ -122,6 +162,8 @@ fn load_in(source: &RepoSource, ttl: Duration, base: &Path) -> Option<SkillIndex
     let index = SkillIndex {
         skills,
         categories: cached.categories,
+        diagnostics: cached.diagnostics,
+        ..Default::default()
     };
 
     tracing::debug!(path = %path.display(), "Loaded index from cache");
@@ -169,6 +211,7 @@ fn write_in(source: &RepoSource, index: &SkillIndex, base: &Path) {
         cached_at: now,
         skills: index.skills.values().cloned().collect(),
         categories: index.categories.clone(),
+        diagnostics: index.diagnostics.clone(),
     };
 
     match serde_json::to_string(&cached) {
@@ -185,6 +228,132 @@ fn write_in(source: &RepoSource, index: &SkillIndex, base: &Path) {
     }
 }
 
+/// Fingerprint the per-repo caches backing a set of loaded repo paths.
+///
+/// `clone_base` is the directory remote repos are checked out under;
+/// paths beneath it map to that remote's cache file, everything else is
+/// treated as a local repo. The fingerprint covers each cache file's
+/// path, size, and modification time plus the repo's current git HEAD,
+/// in load order (merge is first-wins, so order matters).
+///
+/// Returns `None` if any repo has no cache file, in which case there is
+/// nothing stable to key a persisted search index on.
+pub fn fingerprint(repo_paths: &[PathBuf], clone_base: &Path) -> Option<String> {
+    fingerprint_in(repo_paths, clone_base, &cache_dir())
+}
+
+/// Compute a fingerprint using a specific cache base directory.
+fn fingerprint_in(repo_paths: &[PathBuf], clone_base: &Path, base: &Path) -> Option<String> {
+    use sha2::{Digest, Sha256};
+
+    let mut hasher = Sha256::new();
+    hasher.update(SEARCH_CACHE_VERSION.to_le_bytes());
+
+    for path in repo_paths {
+        // Remote checkouts live at <clone_base>/<slug>, cached as <slug>.json
+        let (cache_file, repo_root) = match path
+            .strip_prefix(clone_base)
+            .ok()
+            .and_then(|rel| rel.components().next())
+        {
+            Some(slug) => {
+                let slug = slug.as_os_str().to_string_lossy();
+                (
+                    base.join(format!("{slug}.json")),
+                    clone_base.join(slug.as_ref()),
+                )
+            }
+            None => (
+                cache_path_in(&RepoSource::Local(path.clone()), base),
+                path.clone(),
+            ),
+        };
+
+        let meta = std::fs::metadata(&cache_file).ok()?;
+        let mtime = meta
+            .modified()
+            .ok()?
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+        let head = if repo_root.join(".git").exists() {
+            git::head(&repo_root).ok()
+        } else {
+            None
+        };
+
+        hasher.update(cache_file.to_string_lossy().as_bytes());
+        hasher.update(meta.len().to_le_bytes());
+        hasher.update(mtime.to_le_bytes());
+        hasher.update(head.unwrap_or_default().as_bytes());
+        hasher.update([0]);
+    }
+
+    Some(hex::encode(hasher.finalize()))
+}
+
+/// Load the persisted search index if it was built for `key`.
+///
+/// Returns `None` on any failure (missing, corrupt, version or key
+/// mismatch). Like the per-repo caches, reads are best-effort.
+pub fn load_search(key: &str) -> Option<Bm25Index> {
+    load_search_in(key, &cache_dir())
+}
+
+/// Load a persisted search index using a specific cache base directory.
+fn load_search_in(key: &str, base: &Path) -> Option<Bm25Index> {
+    let path = base.join(SEARCH_CACHE_FILE);
+    let data = std::fs::read_to_string(&path).ok()?;
+    let cached: CachedSearch = serde_json::from_str(&data).ok()?;
+
+    if cached.version != SEARCH_CACHE_VERSION {
+        tracing::debug!("Search cache version mismatch, ignoring");
+        return None;
+    }
+    if cached.key != key {
+        tracing::debug!("Search cache key mismatch, ignoring");
+        return None;
+    }
+
+    tracing::debug!(path = %path.display(), "Loaded search index from cache");
+    Some(cached.index)
+}
+
+/// Persist a built search index under `key`, replacing any previous one.
+///
+/// Logs warnings on failure but does not propagate errors.
+pub fn write_search(key: &str, index: &Bm25Index) {
+    write_search_in(key, index, &cache_dir());
+}
+
+/// Persist a search index using a specific cache base directory.
+fn write_search_in(key: &str, index: &Bm25Index, base: &Path) {
+    if let Err(e) = std::fs::create_dir_all(base) {
+        tracing::warn!(error = %e, "Failed to create cache directory");
+        return;
+    }
+
+    let path = base.join(SEARCH_CACHE_FILE);
+    let cached = CachedSearchRef {
+        version: SEARCH_CACHE_VERSION,
+        key,
+        index,
+    };
+
+    match serde_json::to_string(&cached) {
+        Ok(data) => {
+            if let Err(e) = std::fs::write(&path, data) {
+                tracing::warn!(error = %e, path = %path.display(), "Failed to write search cache");
+            } else {
+                tracing::debug!(path = %path.display(), "Wrote search index cache");
+            }
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to serialize search cache");
+        }
+    }
+}
+
 /// Remove all index cache files.
 pub fn clear() -> crate::error::Result<()> {
     let dir = cache_dir();
@@ -235,6 +404,7 @@ mod tests {
             source: Default::default(),
             trust_tier: Default::default(),
             discovered_via: Vec::new(),
+            origin: None,
             versions: vec![SkillVersion {
                 version: "0.1.0".to_string(),
                 metadata: SkillMetadata {
@@ -248,7 +418,13 @@ mod tests {
                         author: None,
                         classification: None,
                         compatibility: None,
+                        language: None,
+                        files: None,
+                        activation: None,
+                        allowed_tools: None,
+                        model: None,
                     },
+                    frontmatter: Default::default(),
                 },
                 skill_md: "# Test".to_string(),
                 skill_toml_raw: "[skill]\nname = \"test-skill\"".to_string(),
@@ -290,6 +466,23 @@ mod tests {
         assert_eq!(entry.versions[0].skill_md, "# Test");
     }
 
+    #[test]
+    fn test_diagnostics_roundtrip() {
+        let tmp = tempfile::tempdir().unwrap();
+        let cache_base = tempfile::tempdir().unwrap();
+        let mut index = test_index();
+        index.diagnostics.push(LoadDiagnostic::error(
+            crate::state::DiagnosticKind::InvalidSkill,
+            tmp.path().join("owner/broken"),
+            "missing field `owner`",
+        ));
+        let source = temp_source(tmp.path());
+
+        write_in(&source, &index, cache_base.path());
+        let loaded = load_in(&source, Duration::from_secs(300), cache_base.path()).unwrap();
+        assert_eq!(loaded.diagnostics, index.diagnostics);
+    }
+
     #[test]
     fn test_version_mismatch() {
         let tmp = tempfile::tempdir().unwrap();
@@ -463,6 +656,7 @@ mod tests {
             SkillFile {
                 content: "#!/bin/bash\necho hello".to_string(),
                 mime_type: "text/x-shellscript".to_string(),
+                binary: false,
             },
         );
 
@@ -473,6 +667,7 @@ mod tests {
             source: Default::default(),
             trust_tier: Default::default(),
             discovered_via: Vec::new(),
+            origin: None,
             versions: vec![SkillVersion {
                 version: "1.0.0".to_string(),
                 metadata: SkillMetadata {
@@ -486,7 +681,13 @@ mod tests {
                         author: None,
                         classification: None,
                         compatibility: None,
+                        language: None,
+                        files: None,
+                        activation: None,
+                        allowed_tools: None,
+                        model: None,
                     },
+                    frontmatter: Default::default(),
                 },
                 skill_md: "# With Files".to_string(),
                 skill_toml_raw: "".to_string(),
@@ -515,4 +716,88 @@ mod tests {
         );
         assert_eq!(v.published, Some("2025-01-01T00:00:00Z".to_string()));
     }
+
+    fn test_bm25() -> Bm25Index {
+        let mut index = Bm25Index::new(crate::bm25::IndexOptions::default());
+        index.add_fields("a", &[("body", "rust development tooling")]);
+        index.add_fields("b", &[("body", "python testing")]);
+        index
+    }
+
+    #[test]
+    fn test_search_roundtrip() {
+        let cache_base = tempfile::tempdir().unwrap();
+        let index = test_bm25();
+
+        write_search_in("key-1", &index, cache_base.path());
+        let loaded = load_search_in("key-1", cache_base.path()).unwrap();
+
+        assert_eq!(loaded.doc_count, 2);
+        assert_eq!(loaded.options, index.options);
+        let hits = loaded.search("rust", 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, "a");
+    }
+
+    #[test]
+    fn test_search_key_mismatch() {
+        let cache_base = tempfile::tempdir().unwrap();
+        write_search_in("key-1", &test_bm25(), cache_base.path());
+        assert!(load_search_in("key-2", cache_base.path()).is_none());
+    }
+
+    #[test]
+    fn test_fingerprint_requires_cache_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        let cache_base = tempfile::tempdir().unwrap();
+        let clones = tempfile::tempdir().unwrap();
+        let paths = vec![tmp.path().to_path_buf()];
+
+        assert!(fingerprint_in(&paths, clones.path(), cache_base.path()).is_none());
+
+        write_in(&temp_source(tmp.path()), &test_index(), cache_base.path());
+        assert!(fingerprint_in(&paths, clones.path(), cache_base.path()).is_some());
+    }
+
+    #[test]
+    fn test_fingerprint_changes_when_cache_rewritten() {
+        let tmp = tempfile::tempdir().unwrap();
+        let cache_base = tempfile::tempdir().unwrap();
+        let clones = tempfile::tempdir().unwrap();
+        let source = temp_source(tmp.path());
+        let paths = vec![tmp.path().to_path_buf()];
+
+        write_in(&source, &test_index(), cache_base.path());
+        let first = fingerprint_in(&paths, clones.path(), cache_base.path()).unwrap();
+        assert_eq!(
+            fingerprint_in(&paths, clones.path(), cache_base.path()).unwrap(),
+            first
+        );
+
+        let mut index = test_index();
+        index.skills.values_mut().next().unwrap().versions[0].skill_md = "# Changed".to_string();
+        write_in(&source, &index, cache_base.path());
+        assert_ne!(
+            fingerprint_in(&paths, clones.path(), cache_base.path()).unwrap(),
+            first
+        );
+    }
+
+    #[test]
+    fn test_fingerprint_maps_remote_checkouts() {
+        let cache_base = tempfile::tempdir().unwrap();
+        let clones = tempfile::tempdir().unwrap();
+        let url = "https://github.com/owner/repo.git";
+        let checkout = crate::repo::cache_dir_for_url(clones.path(), url);
+        let source = RepoSource::Remote {
+            url: url.to_string(),
+            checkout: checkout.clone(),
+        };
+
+        write_in(&source, &test_index(), cache_base.path());
+
+        // The skills subdir of a checkout resolves to the checkout's cache file
+        let paths = vec![checkout.join("skills")];
+        assert!(fingerprint_in(&paths, clones.path(), cache_base.path()).is_some());
+    }
 }
@@ -0,0 +1,134 @@
+use std::process::ExitCode;
+
+use skillet_mcp::config;
+use skillet_mcp::search;
+
+/// Run the `config` subcommand: print the effective, validated settings.
+pub(crate) fn run_config() -> ExitCode {
+    let cfg = match config::load_config() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error loading config: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    let path = config::config_dir().join("config.toml");
+    if path.is_file() {
+        println!("Config: {}", path.display());
+    } else {
+        println!("Config: {} (not found, using defaults)", path.display());
+    }
+    println!();
+
+    let s = &cfg.search;
+    println!("[search]");
+    println!("  language .............. {}", s.language);
+    println!("  k1 .................... {}", s.k1);
+    println!("  b ..................... {}", s.b);
+    println!("  synonym weight ........ {}", s.synonym_weight);
+    println!("  builtin synonyms ...... {}", s.builtin_synonyms);
+    if !s.synonyms.is_empty() {
+        println!("  custom synonyms ....... {}", s.synonyms.len());
+    }
+    match s.stop_words {
+        Some(ref words) => println!("  stop words ............ {} (custom)", words.len()),
+        None => println!("  stop words ............ built-in"),
+    }
+    if !s.extra_fields.is_empty() {
+        println!("  extra fields .......... {}", s.extra_fields.join(", "));
+    }
+    println!();
+
+    println!("[search.field_weights]");
+    for (field, weight) in search::effective_field_weights(s) {
+        let marker = if s.field_weights.contains_key(&field) {
+            " (configured)"
+        } else {
+            ""
+        };
+        let label = format!("{field} ");
+        println!("  {label:.<23} {weight}{marker}");
+    }
+    println!();
+
+    let r = &s.ranking;
+    println!("[search.ranking]");
+    println!("  enabled ............... {}", r.enabled);
+    println!("  trust tier ............ {}", r.trust_tier);
+    println!("  recency ............... {}", r.recency);
+    println!("  half-life (days) ...... {}", r.recency_half_life_days);
+    println!("  verified .............. {}", r.verified);
+    println!("  metadata .............. {}", r.metadata);
+    println!("  repo priority ......... {}", r.repo_priority);
+
+    let a = &cfg.agent;
+    println!();
+    println!("[agent]");
+    println!("  compatibility ......... {}", a.compatibility);
+    let unset = || "unknown".to_string();
+    let list = |v: &Option<Vec<String>>| v.as_ref().map(|v| v.join(", ")).unwrap_or_else(unset);
+    let p = &a.profile;
+    println!(
+        "  tool use .............. {}",
+        p.tool_use.map(|b| b.to_string()).unwrap_or_else(unset)
+    );
+    println!(
+        "  vision ................ {}",
+        p.vision.map(|b| b.to_string()).unwrap_or_else(unset)
+    );
+    println!(
+        "  context tokens ........ {}",
+        p.context_tokens
+            .map(|n| n.to_string())
+            .unwrap_or_else(unset)
+    );
+    println!("  capabilities .......... {}", list(&p.capabilities));
+    println!("  mcp servers ........... {}", list(&p.mcp_servers));
+    if !a.clients.is_empty() {
+        let names: Vec<&str> = a.clients.keys().map(String::as_str).collect();
+        println!("  client overrides ...... {}", names.join(", "));
+    }
+
+    let repos = &cfg.repos;
+    println!();
+    println!("[repos]");
+    println!("  concurrency ........... {}", repos.concurrency);
+    println!("  conflict .............. {}", repos.conflict);
+    if !repos.priority.is_empty() {
+        println!("  priority .............. {}", repos.priority.join(", "));
+    }
+    if !repos.aliases.is_empty() {
+        println!();
+        println!("[repos.aliases]");
+        for (alias, repo) in &repos.aliases {
+            let label = format!("{alias} ");
+            println!("  {label:.<23} {repo}");
+        }
+    }
+    if !repos.import.is_empty() {
+        println!();
+        println!("[repos.import]");
+        for (repo, formats) in &repos.import {
+            let formats: Vec<String> = formats.iter().map(ToString::to_string).collect();
+            println!("  {repo}: {}", formats.join(", "));
+        }
+    }
+
+    let limits = skillet_mcp::index::FileLimits::from_config(&cfg.files);
+    println!();
+    println!("[files]");
+    println!("  max file size ......... {} bytes", limits.max_file_size);
+    println!("  max skill size ........ {} bytes", limits.max_skill_size);
+
+    if !cfg.taxonomy.aliases.is_empty() {
+        println!();
+        println!("[taxonomy.aliases]");
+        for (alias, target) in &cfg.taxonomy.aliases {
+            let label = format!("{alias} ");
+            println!("  {label:.<23} {target}");
+        }
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,80 @@
+use std::process::ExitCode;
+
+use skillet_mcp::{config, repo};
+
+use crate::ConflictsArgs;
+
+/// Run the `conflicts` subcommand: list every skill provided by more than
+/// one repo, which copy the `[repos].conflict` policy kept and which it
+/// dropped.
+pub(crate) fn run_conflicts(args: ConflictsArgs) -> ExitCode {
+    let mut cli_config = match config::load_config() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error loading config: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    if args.repos.no_cache {
+        cli_config.cache.enabled = false;
+    }
+
+    let (skill_index, _repo_paths) = match repo::load_repos(
+        &args.repos.repo,
+        &args.repos.remote,
+        &cli_config,
+        args.repos.subdir.as_deref(),
+        args.repos.no_suggest,
+    ) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error loading repos: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    let mut shadowed: Vec<_> = skill_index.shadowed.iter().collect();
+    shadowed.sort_by(|a, b| (&a.owner, &a.name).cmp(&(&b.owner, &b.name)));
+
+    if args.json {
+        match serde_json::to_string_pretty(&shadowed) {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                return ExitCode::from(1);
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    println!("Conflict policy: {}\n", cli_config.repos.conflict);
+    if shadowed.is_empty() {
+        println!("No conflicts: every skill comes from a single repo.");
+        return ExitCode::SUCCESS;
+    }
+
+    for s in &shadowed {
+        println!("  {}/{}", s.owner, s.name);
+        println!(
+            "    kept ....... v{} from {} ({})",
+            s.kept.version,
+            s.kept.source_label(),
+            s.kept.trust_tier
+        );
+        println!(
+            "    shadowed ... v{} from {} ({})",
+            s.dropped.version,
+            s.dropped.source_label(),
+            s.dropped.trust_tier
+        );
+        println!();
+    }
+    println!(
+        "{} shadowed skill{}",
+        shadowed.len(),
+        if shadowed.len() == 1 { "" } else { "s" }
+    );
+
+    ExitCode::SUCCESS
+}
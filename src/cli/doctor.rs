@@ -0,0 +1,111 @@
+use std::process::ExitCode;
+
+use skillet_mcp::mcp_config::{self, McpInventory};
+use skillet_mcp::{config, repo};
+
+use crate::DoctorArgs;
+
+/// Run the `doctor` subcommand: report which local MCP client configs exist
+/// and which skills require servers none of them define.
+///
+/// Exits non-zero when a skill requires an unconfigured server or a config
+/// file can't be read.
+pub(crate) fn run_doctor(args: DoctorArgs) -> ExitCode {
+    let mut cli_config = match config::load_config() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error loading config: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    if args.repos.no_cache {
+        cli_config.cache.enabled = false;
+    }
+
+    let (skill_index, _repo_paths) = match repo::load_repos(
+        &args.repos.repo,
+        &args.repos.remote,
+        &cli_config,
+        args.repos.subdir.as_deref(),
+        args.repos.no_suggest,
+    ) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error loading repos: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    let project = args
+        .project
+        .unwrap_or_else(skillet_mcp::project::resolve_project_root);
+    let inventory = McpInventory::discover(&project);
+
+    println!("MCP client configs:");
+    if inventory.configs.is_empty() && inventory.errors.is_empty() {
+        println!("  none found");
+    }
+    for c in &inventory.configs {
+        let servers = if c.servers.is_empty() {
+            "no servers".to_string()
+        } else {
+            c.servers.iter().cloned().collect::<Vec<_>>().join(", ")
+        };
+        println!("  {} ({})", c.path.display(), c.client);
+        println!("    {servers}");
+    }
+    for (path, err) in &inventory.errors {
+        println!("  {} (unreadable)", path.display());
+        println!("    {err}");
+    }
+    println!();
+
+    let mut requiring: Vec<_> = skill_index
+        .skills
+        .iter()
+        .filter(|(_, entry)| !mcp_config::required_servers(entry).is_empty())
+        .collect();
+    requiring.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut missing = 0;
+    if requiring.is_empty() {
+        println!("No skills require MCP servers.");
+    } else {
+        println!("Skills requiring MCP servers ({}):", requiring.len());
+        for ((owner, name), entry) in &requiring {
+            let check = inventory.check(entry);
+            let ok = check.iter().all(|(_, configured)| *configured);
+            if !ok {
+                missing += 1;
+            }
+            println!(
+                "  [{}] {owner}/{name}: {}",
+                if ok { "ok" } else { "missing" },
+                mcp_config::format_check(&check)
+            );
+        }
+    }
+
+    println!();
+    if missing == 0 && inventory.errors.is_empty() {
+        println!("No problems found.");
+        ExitCode::SUCCESS
+    } else {
+        if missing > 0 {
+            println!(
+                "{missing} skill{} require{} MCP servers that are not configured.",
+                if missing == 1 { "" } else { "s" },
+                if missing == 1 { "s" } else { "" },
+            );
+        }
+        if !inventory.errors.is_empty() {
+            println!(
+                "{} MCP client config{} could not be read.",
+                inventory.errors.len(),
+                if inventory.errors.len() == 1 { "" } else { "s" },
+            );
+        }
+        ExitCode::from(1)
+    }
+}
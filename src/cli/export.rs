@@ -0,0 +1,148 @@
+use std::process::ExitCode;
+
+use skillet_mcp::export::{self, ExportManifest};
+use skillet_mcp::{config, repo};
+
+use super::parse_skill_ref;
+use crate::ExportArgs;
+
+/// Run the `export` subcommand: write skills into an agent-native layout,
+/// or with `--check`, report drift from an earlier export.
+pub(crate) fn run_export(args: ExportArgs) -> ExitCode {
+    let mut cli_config = match config::load_config() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error loading config: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    if args.repos.no_cache {
+        cli_config.cache.enabled = false;
+    }
+
+    let (skill_index, _repo_paths) = match repo::load_repos(
+        &args.repos.repo,
+        &args.repos.remote,
+        &cli_config,
+        args.repos.subdir.as_deref(),
+        args.repos.no_suggest,
+    ) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error loading repos: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    if args.check {
+        return run_check(&args, &skill_index);
+    }
+
+    let mut entries = Vec::new();
+    for skill in &args.skills {
+        let (owner, name) = match parse_skill_ref(skill) {
+            Ok(r) => r,
+            Err(e) => {
+                eprintln!("Error: {e}");
+                return ExitCode::from(1);
+            }
+        };
+        match skill_index.lookup(owner, name).found(owner, name) {
+            Ok(entry) => entries.push(entry),
+            Err(msg) => {
+                eprintln!("Error: {msg}");
+                return ExitCode::from(1);
+            }
+        }
+    }
+
+    let Some(target) = args.target else {
+        eprintln!("Error: --target is required");
+        return ExitCode::from(1);
+    };
+    let rendered = match export::render(target, &entries, &skill_index) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return ExitCode::from(1);
+        }
+    };
+    for problem in &rendered.problems {
+        eprintln!("Unresolved include in {problem}");
+    }
+    if let Err(e) = export::write(&args.out, &rendered) {
+        eprintln!("Error: {e}");
+        return ExitCode::from(1);
+    }
+
+    println!(
+        "Exported {} skill{} to {} ({target}):\n",
+        rendered.skills.len(),
+        if rendered.skills.len() == 1 { "" } else { "s" },
+        args.out.display()
+    );
+    for s in &rendered.skills {
+        let label = format!("{}/{} ", s.owner, s.name);
+        println!(
+            "  {label:.<23} v{} ({} file{})",
+            s.version,
+            s.files.len(),
+            if s.files.len() == 1 { "" } else { "s" }
+        );
+    }
+    println!(
+        "\nRecorded in {}",
+        args.out.join(export::MANIFEST_FILE).display()
+    );
+
+    ExitCode::SUCCESS
+}
+
+/// `export --check`: exit 1 if anything drifted.
+fn run_check(args: &ExportArgs, skill_index: &skillet_mcp::state::SkillIndex) -> ExitCode {
+    let mut drift = match export::check(&args.out, skill_index) {
+        Ok(d) => d,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return ExitCode::from(1);
+        }
+    };
+    // Optionally narrow to the named skills
+    if !args.skills.is_empty() {
+        drift.retain(|d| args.skills.contains(&format!("{}/{}", d.owner, d.name)));
+    }
+
+    if args.json {
+        match serde_json::to_string_pretty(&drift) {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                return ExitCode::from(1);
+            }
+        }
+    } else if drift.is_empty() {
+        let exported = ExportManifest::load(&args.out)
+            .map(|m| m.skills.len())
+            .unwrap_or_default();
+        println!(
+            "No drift: {exported} exported skill{} up to date.",
+            if exported == 1 { "" } else { "s" }
+        );
+    } else {
+        for d in &drift {
+            println!("  {d}");
+        }
+        println!(
+            "\n{} problem{} found",
+            drift.len(),
+            if drift.len() == 1 { "" } else { "s" }
+        );
+    }
+
+    if drift.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(1)
+    }
+}
@@ -0,0 +1,68 @@
+use std::process::ExitCode;
+
+use skillet_mcp::index::FileLimits;
+use skillet_mcp::{config, lint};
+
+use crate::LintArgs;
+
+/// Run the `lint` subcommand: check every skill under a path and print
+/// diagnostics as `file:line: severity: message [rule]`.
+///
+/// Exits non-zero when any error is found, so it can gate CI.
+pub(crate) fn run_lint(args: LintArgs) -> ExitCode {
+    let limits = match config::load_config() {
+        Ok(c) => FileLimits::from_config(&c.files),
+        Err(e) => {
+            eprintln!("Error loading config: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    let report = match lint::lint(&args.path, &limits) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    if args.json {
+        let json = serde_json::json!({
+            "skills": report.skills,
+            "errors": report.errors(),
+            "warnings": report.warnings(),
+            "diagnostics": report.diagnostics,
+        });
+        match serde_json::to_string_pretty(&json) {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                return ExitCode::from(1);
+            }
+        }
+    } else if report.skills == 0 {
+        println!("No skills found under {}", args.path.display());
+    } else {
+        for d in &report.diagnostics {
+            println!("{d}");
+        }
+        if !report.diagnostics.is_empty() {
+            println!();
+        }
+        println!(
+            "Checked {} skill{}: {} error{}, {} warning{}",
+            report.skills,
+            if report.skills == 1 { "" } else { "s" },
+            report.errors(),
+            if report.errors() == 1 { "" } else { "s" },
+            report.warnings(),
+            if report.warnings() == 1 { "" } else { "s" },
+        );
+    }
+
+    if report.errors() > 0 {
+        ExitCode::from(1)
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -1,4 +1,9 @@
 pub(crate) mod author;
+pub(crate) mod config;
+pub(crate) mod conflicts;
+pub(crate) mod doctor;
+pub(crate) mod export;
+pub(crate) mod lint;
 pub(crate) mod repo;
 pub(crate) mod search;
 
@@ -1,9 +1,15 @@
 use std::process::ExitCode;
 
-use skillet_mcp::{config, repo, search, state};
+use skillet_mcp::mcp_config::{self, McpInventory};
+use skillet_mcp::{browse, compat, config, eval, project, repo, search, state, taxonomy};
 
 use super::parse_skill_ref;
-use crate::{CategoriesArgs, InfoArgs, SearchArgs};
+use crate::{
+    BrowseArgs, CategoriesArgs, InfoArgs, SearchArgs, SearchEvalArgs, SimilarArgs, TagsArgs,
+};
+
+/// Maximum ranked matches `search` lists.
+const SEARCH_LIMIT: usize = 20;
 
 /// Run the `search` subcommand.
 pub(crate) fn run_search(args: SearchArgs) -> ExitCode {
@@ -19,7 +25,7 @@ pub(crate) fn run_search(args: SearchArgs) -> ExitCode {
         cli_config.cache.enabled = false;
     }
 
-    let (skill_index, _repo_paths) = match repo::load_repos(
+    let (skill_index, repo_paths) = match repo::load_repos(
         &args.repos.repo,
         &args.repos.remote,
         &cli_config,
@@ -33,7 +39,7 @@ pub(crate) fn run_search(args: SearchArgs) -> ExitCode {
         }
     };
 
-    let skill_search = search::SkillSearch::build(&skill_index);
+    let skill_search = search::SkillSearch::load_or_build(&skill_index, &cli_config, &repo_paths);
 
     // Wildcard: list all skills
     let results: Vec<state::SkillSummary> = if args.query == "*" {
@@ -46,7 +52,7 @@ pub(crate) fn run_search(args: SearchArgs) -> ExitCode {
             })
             .collect()
     } else {
-        let hits = skill_search.search(&args.query, 20);
+        let hits = skill_search.search(&args.query, usize::MAX);
         hits.iter()
             .filter_map(|(owner, name, _score)| {
                 let entry = skill_index.skills.get(&(owner.clone(), name.clone()))?;
@@ -55,22 +61,57 @@ pub(crate) fn run_search(args: SearchArgs) -> ExitCode {
             .collect()
     };
 
-    // Apply structured filters
+    // Apply structured filters. Indexed labels are canonical, so
+    // canonicalize the filters too.
+    let category = args
+        .category
+        .as_deref()
+        .map(|c| taxonomy::canonical(c, &cli_config.taxonomy));
+    let tag = args
+        .tag
+        .as_deref()
+        .map(|t| taxonomy::canonical(t, &cli_config.taxonomy));
+    let inventory = args
+        .mcp_ready
+        .then(|| McpInventory::discover(&project::resolve_project_root()));
     let results: Vec<_> = results
         .into_iter()
         .filter(|s| {
-            if let Some(ref cat) = args.category
-                && !s.categories.iter().any(|c| c.eq_ignore_ascii_case(cat))
+            if let Some(ref cat) = category
+                && !s
+                    .categories
+                    .iter()
+                    .any(|c| taxonomy::category_matches(c, cat))
             {
                 return false;
             }
-            if let Some(ref tag) = args.tag
-                && !s.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+            if let Some(ref tag) = tag
+                && !s.tags.contains(tag)
             {
                 return false;
             }
             if let Some(ref owner) = args.owner
                 && !s.owner.eq_ignore_ascii_case(owner)
+                && !state::split_alias(&s.owner).1.eq_ignore_ascii_case(owner)
+            {
+                return false;
+            }
+            if let Some(ref inventory) = inventory
+                && let Some(entry) = skill_index.skills.get(&(s.owner.clone(), s.name.clone()))
+                && !inventory.missing_for(entry).is_empty()
+            {
+                return false;
+            }
+            if !args.meta.is_empty()
+                && !skill_index
+                    .skills
+                    .get(&(s.owner.clone(), s.name.clone()))
+                    .and_then(|e| e.latest())
+                    .is_some_and(|v| {
+                        args.meta
+                            .iter()
+                            .all(|(key, value)| v.metadata.frontmatter_matches(key, value))
+                    })
             {
                 return false;
             }
@@ -78,16 +119,36 @@ pub(crate) fn run_search(args: SearchArgs) -> ExitCode {
         })
         .collect();
 
+    // Hide or flag skills the agent described in [agent] can't use
+    let (mut results, hidden) = compat::screen(
+        results,
+        &skill_index,
+        &compat::AgentProfile::from_config(&cli_config.agent, None),
+        cli_config.agent.compatibility,
+    );
+
     if results.is_empty() {
         println!("No skills found.");
+        print_hidden(&hidden);
+        print_diagnostics(&skill_index.diagnostics);
         return ExitCode::SUCCESS;
     }
 
+    // Facets count every match; only the listing is capped
+    let facets = state::Facets::from_summaries(&results);
+    let shown = if args.query != "*" && results.len() > SEARCH_LIMIT {
+        format!(", showing the top {SEARCH_LIMIT}")
+    } else {
+        String::new()
+    };
     println!(
-        "Found {} skill{}:\n",
+        "Found {} skill{}{shown}:\n",
         results.len(),
         if results.len() == 1 { "" } else { "s" }
     );
+    if args.query != "*" {
+        results.truncate(SEARCH_LIMIT);
+    }
     for s in &results {
         let trust_label = s
             .trust_tier
@@ -102,12 +163,52 @@ pub(crate) fn run_search(args: SearchArgs) -> ExitCode {
         if !s.tags.is_empty() {
             println!("    tags: {}", s.tags.join(", "));
         }
+        if !s.incompatible.is_empty() {
+            println!("    incompatible: {}", s.incompatible.join("; "));
+        }
         println!();
     }
 
+    println!("Facets:");
+    for (name, counts) in facets.named() {
+        let label = format!("{name} ");
+        println!(
+            "  {label:.<23} {}",
+            state::Facets::format_counts(counts, 10)
+        );
+    }
+    print_hidden(&hidden);
+    print_diagnostics(&skill_index.diagnostics);
+
     ExitCode::SUCCESS
 }
 
+/// Print skills hidden as incompatible, with the reasons for each.
+fn print_hidden(hidden: &[state::SkillSummary]) {
+    if hidden.is_empty() {
+        return;
+    }
+    println!("\nHidden as incompatible ({}):", hidden.len());
+    for s in hidden {
+        println!("  {}/{}: {}", s.owner, s.name, s.incompatible.join("; "));
+    }
+}
+
+/// Print problems hit while loading repos (skipped skills, failed clones).
+fn print_diagnostics(diagnostics: &[state::LoadDiagnostic]) {
+    if diagnostics.is_empty() {
+        return;
+    }
+    println!(
+        "
+Load problems ({}):",
+        diagnostics.len()
+    );
+    for d in diagnostics {
+        println!("  {d}");
+    }
+}
+
 /// Run the `categories` subcommand.
 pub(crate) fn run_categories(args: CategoriesArgs) -> ExitCode {
     let mut cli_config = match config::load_config() {
@@ -159,6 +260,123 @@ pub(crate) fn run_categories(args: CategoriesArgs) -> ExitCode {
     ExitCode::SUCCESS
 }
 
+/// Run the `tags` subcommand.
+pub(crate) fn run_tags(args: TagsArgs) -> ExitCode {
+    let mut cli_config = match config::load_config() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error loading config: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    if args.repos.no_cache {
+        cli_config.cache.enabled = false;
+    }
+
+    let (skill_index, _repo_paths) = match repo::load_repos(
+        &args.repos.repo,
+        &args.repos.remote,
+        &cli_config,
+        args.repos.subdir.as_deref(),
+        args.repos.no_suggest,
+    ) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error loading repos: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    if skill_index.tags.is_empty() {
+        println!("No tags found.");
+        return ExitCode::SUCCESS;
+    }
+
+    let mut tags: Vec<(&String, &usize)> = skill_index.tags.iter().collect();
+    if args.by_count {
+        tags.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    }
+
+    println!(
+        "{} tag{}:\n",
+        tags.len(),
+        if tags.len() == 1 { "" } else { "s" },
+    );
+    for (name, count) in tags {
+        println!("  {name} ({count})");
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Run the `browse` subcommand.
+pub(crate) fn run_browse(args: BrowseArgs) -> ExitCode {
+    let mut cli_config = match config::load_config() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error loading config: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    if args.repos.no_cache {
+        cli_config.cache.enabled = false;
+    }
+
+    let (skill_index, _repo_paths) = match repo::load_repos(
+        &args.repos.repo,
+        &args.repos.remote,
+        &cli_config,
+        args.repos.subdir.as_deref(),
+        args.repos.no_suggest,
+    ) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error loading repos: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    let path = args
+        .path
+        .as_deref()
+        .map(|p| taxonomy::canonical(p, &cli_config.taxonomy))
+        .unwrap_or_default();
+    let listing = browse::browse(&skill_index, &path);
+
+    if listing.is_empty() {
+        if path.is_empty() {
+            println!("No categories found.");
+            return ExitCode::SUCCESS;
+        }
+        eprintln!("Error: no skills under '{path}'");
+        return ExitCode::from(1);
+    }
+
+    let title = if path.is_empty() { "/" } else { &path };
+    println!("{title}\n");
+    for group in &listing.groups {
+        let label = format!("{}/ ", group.name);
+        println!(
+            "  {label:.<23} {} skill{}",
+            group.count,
+            if group.count == 1 { "" } else { "s" }
+        );
+    }
+    if !listing.skills.is_empty() {
+        if !listing.groups.is_empty() {
+            println!();
+        }
+        for s in &listing.skills {
+            println!("  {}/{} v{}", s.owner, s.name, s.version);
+            println!("    {}", s.description);
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
 /// Run the `info` subcommand.
 pub(crate) fn run_info(args: InfoArgs) -> ExitCode {
     let (owner, name) = match parse_skill_ref(&args.skill) {
@@ -181,7 +399,7 @@ pub(crate) fn run_info(args: InfoArgs) -> ExitCode {
         cli_config.cache.enabled = false;
     }
 
-    let (skill_index, _repo_paths) = match repo::load_repos(
+    let (skill_index, repo_paths) = match repo::load_repos(
         &args.repos.repo,
         &args.repos.remote,
         &cli_config,
@@ -195,16 +413,22 @@ pub(crate) fn run_info(args: InfoArgs) -> ExitCode {
         }
     };
 
-    let entry = match skill_index
-        .skills
-        .get(&(owner.to_string(), name.to_string()))
-    {
-        Some(e) => e,
-        None => {
-            eprintln!("Error: skill '{owner}/{name}' not found in any repo");
+    let entry = match skill_index.lookup(owner, name).found(owner, name) {
+        Ok(e) => e,
+        Err(msg) => {
+            eprintln!("Error: {msg}");
+            // A matching directory may have failed to load
+            for d in skill_index
+                .diagnostics
+                .iter()
+                .filter(|d| d.path.file_name().is_some_and(|n| n == name))
+            {
+                eprintln!("  {d}");
+            }
             return ExitCode::from(1);
         }
     };
+    let (owner, name) = (entry.owner.as_str(), entry.name.as_str());
 
     let latest = match entry.latest() {
         Some(v) => v,
@@ -223,9 +447,15 @@ pub(crate) fn run_info(args: InfoArgs) -> ExitCode {
     if let Some(ref trigger) = info.trigger {
         println!("  trigger ............... {trigger}");
     }
+    if let Some(ref activation) = info.activation {
+        println!("  activation ............ {activation}");
+    }
     if let Some(ref license) = info.license {
         println!("  license ............... {license}");
     }
+    if let Some(ref language) = info.language {
+        println!("  language .............. {language}");
+    }
     if let Some(ref author) = info.author {
         if let Some(ref name) = author.name {
             println!("  author ................ {name}");
@@ -256,6 +486,16 @@ pub(crate) fn run_info(args: InfoArgs) -> ExitCode {
             compat.verified_with.join(", ")
         );
     }
+    if let Some(ref model) = info.model {
+        println!("  model ................. {model}");
+    }
+    if let Some(ref tools) = info.allowed_tools {
+        println!("  allowed tools ......... {}", tools.join(", "));
+    }
+    for (key, value) in latest.metadata.custom_metadata() {
+        let label = format!("{key} ");
+        println!("  {label:.<23} {value}");
+    }
 
     // Extra files
     if !latest.files.is_empty() {
@@ -292,6 +532,11 @@ pub(crate) fn run_info(args: InfoArgs) -> ExitCode {
         println!("  repo path ............. {rpath}");
     }
 
+    // Where embedded and imported skills live on disk
+    if let (Some(label), Some(path)) = (entry.source.label(), entry.source.path()) {
+        println!("  source ................ {label}: {}", path.display());
+    }
+
     // Trust tier and provenance
     if entry.trust_tier != skillet_mcp::state::TrustTier::Direct {
         let via = if entry.discovered_via.is_empty() {
@@ -302,5 +547,200 @@ pub(crate) fn run_info(args: InfoArgs) -> ExitCode {
         println!("  trust ................. {}{via}", entry.trust_tier);
     }
 
+    // Copies from other repos that lost the merge
+    for s in skill_index.shadowing_for(owner, name) {
+        println!(
+            "  shadows ............... v{} from {} ({})",
+            s.dropped.version,
+            s.dropped.source_label(),
+            s.dropped.trust_tier
+        );
+    }
+
+    // Required MCP servers vs. local client configs
+    let servers = McpInventory::discover(&project::resolve_project_root()).check(entry);
+    if !servers.is_empty() {
+        println!(
+            "  mcp servers ........... {}",
+            mcp_config::format_check(&servers)
+        );
+    }
+
+    // Compatibility with the agent described in [agent]
+    if cli_config.agent.compatibility != config::CompatibilityMode::Off {
+        let reasons = compat::AgentProfile::from_config(&cli_config.agent, None).check_entry(entry);
+        if !reasons.is_empty() {
+            println!("  incompatible .......... {}", reasons.join("; "));
+        }
+    }
+
+    // Related skills by indexed-text similarity
+    let skill_search = search::SkillSearch::load_or_build(&skill_index, &cli_config, &repo_paths);
+    let related = skill_search.similar(owner, name, 3);
+    if !related.is_empty() {
+        println!(
+            "  related ............... {}",
+            related
+                .iter()
+                .map(|r| format!("{}/{}", r.owner, r.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    print_diagnostics(&skill_index.diagnostics);
+
+    ExitCode::SUCCESS
+}
+
+/// Run the `similar` subcommand.
+pub(crate) fn run_similar(args: SimilarArgs) -> ExitCode {
+    let (owner, name) = match parse_skill_ref(&args.skill) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    let mut cli_config = match config::load_config() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error loading config: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    if args.repos.no_cache {
+        cli_config.cache.enabled = false;
+    }
+
+    let (skill_index, repo_paths) = match repo::load_repos(
+        &args.repos.repo,
+        &args.repos.remote,
+        &cli_config,
+        args.repos.subdir.as_deref(),
+        args.repos.no_suggest,
+    ) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error loading repos: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    let (owner, name) = match skill_index.lookup(owner, name).found(owner, name) {
+        Ok(e) => (e.owner.as_str(), e.name.as_str()),
+        Err(msg) => {
+            eprintln!("Error: {msg}");
+            return ExitCode::from(1);
+        }
+    };
+
+    let skill_search = search::SkillSearch::load_or_build(&skill_index, &cli_config, &repo_paths);
+    let similar = skill_search.similar(owner, name, args.limit);
+
+    if similar.is_empty() {
+        println!("No skills similar to {owner}/{name}.");
+        return ExitCode::SUCCESS;
+    }
+
+    println!("Skills similar to {owner}/{name}:\n");
+    for s in &similar {
+        println!("  {}/{} ({:.2})", s.owner, s.name, s.score);
+        if let Some(v) = skill_index
+            .skills
+            .get(&(s.owner.clone(), s.name.clone()))
+            .and_then(|e| e.latest())
+        {
+            println!("    {}", v.metadata.skill.description);
+        }
+        println!("    shared: {}", s.shared_terms.join(", "));
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Run the `search-eval` subcommand.
+pub(crate) fn run_search_eval(args: SearchEvalArgs) -> ExitCode {
+    let cases = match eval::load_cases(&args.file) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    let mut cli_config = match config::load_config() {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error loading config: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    if args.repos.no_cache {
+        cli_config.cache.enabled = false;
+    }
+
+    let (skill_index, repo_paths) = match repo::load_repos(
+        &args.repos.repo,
+        &args.repos.remote,
+        &cli_config,
+        args.repos.subdir.as_deref(),
+        args.repos.no_suggest,
+    ) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("Error loading repos: {e}");
+            return ExitCode::from(1);
+        }
+    };
+
+    let skill_search = search::SkillSearch::load_or_build(&skill_index, &cli_config, &repo_paths);
+    let report = eval::evaluate(&skill_search, &cases, args.k);
+
+    if args.json {
+        match serde_json::to_string_pretty(&report) {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                return ExitCode::from(1);
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let k = report.k;
+    println!(
+        "Evaluated {} quer{} (k = {k})\n",
+        report.queries.len(),
+        if report.queries.len() == 1 {
+            "y"
+        } else {
+            "ies"
+        }
+    );
+    println!(
+        "  {:<40} {:>6} {:>8} {:>8}",
+        "query",
+        "RR",
+        format!("R@{k}"),
+        format!("nDCG@{k}")
+    );
+    for q in &report.queries {
+        println!(
+            "  {:<40} {:>6.3} {:>8.3} {:>8.3}",
+            q.query, q.reciprocal_rank, q.recall, q.ndcg
+        );
+        if !q.missing.is_empty() {
+            println!("    missing: {}", q.missing.join(", "));
+        }
+    }
+    println!();
+    println!(
+        "  {:<40} {:>6.3} {:>8.3} {:>8.3}",
+        "mean", report.mrr, report.mean_recall, report.mean_ndcg
+    );
+
     ExitCode::SUCCESS
 }
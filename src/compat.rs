@@ -0,0 +1,275 @@
+//! Skill compatibility checks against the consuming agent.
+//!
+//! Skills declare what they need in `[skill.compatibility]`. The agent is
+//! described by an [`AgentProfile`], built from the `[agent]` config section
+//! and, when serving MCP, the client name and capabilities sent in
+//! `initialize`. A requirement only excludes a skill when the profile
+//! positively contradicts it; anything the profile doesn't know is assumed
+//! to be fine. Every exclusion comes with human-readable reasons.
+
+use std::collections::BTreeSet;
+
+use crate::config::{AgentConfig, CompatibilityMode};
+use crate::state::{Compatibility, SkillEntry, SkillIndex, SkillSummary};
+
+/// Client capabilities defined by MCP itself. Requirements naming one of
+/// these are checked against what the client declared in `initialize`;
+/// anything else is checked against the configured `capabilities`.
+pub const MCP_CAPABILITIES: &[&str] = &["roots", "sampling", "elicitation", "tasks"];
+
+tokio::task_local! {
+    static CLIENT_PROFILE: AgentProfile;
+}
+
+/// What is known about the agent consuming skills.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AgentProfile {
+    /// MCP client name from `initialize` (e.g. "claude-code").
+    pub client: Option<String>,
+    pub tool_use: Option<bool>,
+    pub vision: Option<bool>,
+    pub context_tokens: Option<u64>,
+    /// Agent capabilities (e.g. "shell_exec"); `None` when unknown.
+    pub capabilities: Option<BTreeSet<String>>,
+    /// MCP capabilities the client declared; `None` outside an MCP session.
+    pub client_capabilities: Option<BTreeSet<String>>,
+    /// MCP servers available to the agent; `None` when unknown.
+    pub mcp_servers: Option<BTreeSet<String>>,
+}
+
+impl AgentProfile {
+    /// Build a profile from config, applying `[agent.clients.<client>]`
+    /// overrides on top of the top-level `[agent]` fields.
+    pub fn from_config(config: &AgentConfig, client: Option<&str>) -> Self {
+        let base = &config.profile;
+        let over = client.and_then(|c| config.clients.get(c));
+        let pick = |f: fn(&crate::config::AgentOverrides) -> Option<Vec<String>>| {
+            over.and_then(f)
+                .or_else(|| f(base))
+                .map(|v| v.into_iter().collect::<BTreeSet<_>>())
+        };
+
+        Self {
+            client: client.map(str::to_string),
+            tool_use: over.and_then(|o| o.tool_use).or(base.tool_use),
+            vision: over.and_then(|o| o.vision).or(base.vision),
+            context_tokens: over.and_then(|o| o.context_tokens).or(base.context_tokens),
+            capabilities: pick(|o| o.capabilities.clone()),
+            client_capabilities: None,
+            mcp_servers: pick(|o| o.mcp_servers.clone()),
+        }
+    }
+
+    /// Reasons this agent can't meet `compat`. Empty means compatible.
+    pub fn incompatibilities(&self, compat: &Compatibility) -> Vec<String> {
+        let mut reasons = Vec::new();
+
+        if compat.requires_tool_use == Some(true) && self.tool_use == Some(false) {
+            reasons.push("requires tool use".to_string());
+        }
+        if compat.requires_vision == Some(true) && self.vision == Some(false) {
+            reasons.push("requires vision".to_string());
+        }
+        if let (Some(min), Some(have)) = (compat.min_context_tokens, self.context_tokens)
+            && have < min
+        {
+            reasons.push(format!("needs {min} context tokens (agent has {have})"));
+        }
+
+        for cap in &compat.required_capabilities {
+            let known = if MCP_CAPABILITIES.contains(&cap.as_str()) {
+                self.client_capabilities.as_ref()
+            } else {
+                self.capabilities.as_ref()
+            };
+            if let Some(known) = known
+                && !known.contains(cap)
+            {
+                reasons.push(format!("requires capability '{cap}'"));
+            }
+        }
+
+        if let Some(ref servers) = self.mcp_servers {
+            for server in &compat.required_mcp_servers {
+                if !servers.contains(server) {
+                    reasons.push(format!("requires MCP server '{server}'"));
+                }
+            }
+        }
+
+        reasons
+    }
+
+    /// Reasons this agent can't use the latest version of `entry`.
+    pub fn check_entry(&self, entry: &SkillEntry) -> Vec<String> {
+        entry
+            .latest()
+            .and_then(|v| v.metadata.skill.compatibility.as_ref())
+            .map(|c| self.incompatibilities(c))
+            .unwrap_or_default()
+    }
+}
+
+/// Check `summaries` against `profile`, filling in each incompatible
+/// skill's `incompatible` reasons. Returns `(kept, hidden)`: in `Hide` mode
+/// incompatible skills move to `hidden`; in `Flag` mode they stay in `kept`;
+/// in `Off` mode nothing is checked.
+pub fn screen(
+    summaries: Vec<SkillSummary>,
+    index: &SkillIndex,
+    profile: &AgentProfile,
+    mode: CompatibilityMode,
+) -> (Vec<SkillSummary>, Vec<SkillSummary>) {
+    if mode == CompatibilityMode::Off {
+        return (summaries, Vec::new());
+    }
+
+    let mut kept = Vec::with_capacity(summaries.len());
+    let mut hidden = Vec::new();
+    for mut summary in summaries {
+        if let Some(entry) = index
+            .skills
+            .get(&(summary.owner.clone(), summary.name.clone()))
+        {
+            summary.incompatible = profile.check_entry(entry);
+        }
+        if !summary.incompatible.is_empty() && mode == CompatibilityMode::Hide {
+            hidden.push(summary);
+        } else {
+            kept.push(summary);
+        }
+    }
+    (kept, hidden)
+}
+
+/// Run `fut` with `profile` as the current client profile (see [`current`]).
+pub async fn scope<F: std::future::Future>(profile: AgentProfile, fut: F) -> F::Output {
+    CLIENT_PROFILE.scope(profile, fut).await
+}
+
+/// The profile of the MCP client whose request is being handled, if any.
+pub fn current() -> Option<AgentProfile> {
+    CLIENT_PROFILE.try_with(Clone::clone).ok()
+}
+
+/// The profile to check against: the current MCP client's if inside a
+/// request, otherwise one built from config alone.
+pub fn current_or_config(config: &AgentConfig) -> AgentProfile {
+    current().unwrap_or_else(|| AgentProfile::from_config(config, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AgentOverrides;
+
+    fn compat() -> Compatibility {
+        Compatibility {
+            requires_tool_use: Some(true),
+            requires_vision: Some(true),
+            min_context_tokens: Some(100_000),
+            required_capabilities: vec!["shell_exec".to_string(), "sampling".to_string()],
+            required_mcp_servers: vec!["github".to_string()],
+            verified_with: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_unknown_profile_is_compatible() {
+        assert!(
+            AgentProfile::default()
+                .incompatibilities(&compat())
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_contradictions_are_reported() {
+        let profile = AgentProfile {
+            tool_use: Some(false),
+            vision: Some(false),
+            context_tokens: Some(32_000),
+            capabilities: Some(BTreeSet::from(["file_read".to_string()])),
+            client_capabilities: Some(BTreeSet::from(["roots".to_string()])),
+            mcp_servers: Some(BTreeSet::new()),
+            ..Default::default()
+        };
+        let reasons = profile.incompatibilities(&compat());
+        assert_eq!(
+            reasons,
+            vec![
+                "requires tool use",
+                "requires vision",
+                "needs 100000 context tokens (agent has 32000)",
+                "requires capability 'shell_exec'",
+                "requires capability 'sampling'",
+                "requires MCP server 'github'",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_client_overrides_apply() {
+        let mut config = AgentConfig::default();
+        config.profile.vision = Some(false);
+        config.profile.capabilities = Some(vec!["shell_exec".to_string()]);
+        config.clients.insert(
+            "claude-code".to_string(),
+            AgentOverrides {
+                vision: Some(true),
+                ..Default::default()
+            },
+        );
+
+        let generic = AgentProfile::from_config(&config, Some("other"));
+        assert_eq!(generic.vision, Some(false));
+        let claude = AgentProfile::from_config(&config, Some("claude-code"));
+        assert_eq!(claude.vision, Some(true));
+        assert_eq!(
+            claude.capabilities,
+            Some(BTreeSet::from(["shell_exec".to_string()]))
+        );
+    }
+
+    #[test]
+    fn test_screen_hides_or_flags() {
+        let repo = crate::testutil::TestRepo::standard();
+        let index =
+            crate::index::load_index(repo.path(), &crate::index::FileLimits::default()).unwrap();
+        let summaries: Vec<SkillSummary> = index
+            .skills
+            .values()
+            .filter_map(SkillSummary::from_entry)
+            .collect();
+        let total = summaries.len();
+        let profile = AgentProfile {
+            tool_use: Some(false),
+            ..Default::default()
+        };
+
+        let (kept, hidden) = screen(summaries.clone(), &index, &profile, CompatibilityMode::Hide);
+        assert!(!hidden.is_empty());
+        assert_eq!(kept.len() + hidden.len(), total);
+        assert!(kept.iter().all(|s| s.incompatible.is_empty()));
+        assert_eq!(hidden[0].incompatible, vec!["requires tool use"]);
+
+        let (kept, hidden) = screen(summaries.clone(), &index, &profile, CompatibilityMode::Flag);
+        assert!(hidden.is_empty());
+        assert_eq!(kept.len(), total);
+        assert!(kept.iter().any(|s| !s.incompatible.is_empty()));
+
+        let (kept, _) = screen(summaries, &index, &profile, CompatibilityMode::Off);
+        assert!(kept.iter().all(|s| s.incompatible.is_empty()));
+    }
+
+    #[tokio::test]
+    async fn test_scope_sets_current() {
+        assert_eq!(current(), None);
+        let profile = AgentProfile {
+            client: Some("test".to_string()),
+            ..Default::default()
+        };
+        let seen = scope(profile.clone(), async { current() }).await;
+        assert_eq!(seen, Some(profile));
+    }
+}
@@ -3,10 +3,12 @@
 //! The skillet config file lives at `~/.config/skillet/config.toml` and controls
 //! repos, server behavior, and other CLI settings.
 
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use crate::bm25::Language;
 use crate::error::Error;
 
 /// Top-level skillet CLI configuration.
@@ -18,6 +20,10 @@ pub struct SkilletConfig {
     pub cache: CacheConfig,
     pub server: ServerConfig,
     pub suggest: SuggestConfig,
+    pub search: SearchConfig,
+    pub taxonomy: TaxonomyConfig,
+    pub agent: AgentConfig,
+    pub files: FilesConfig,
     /// Consumer-side version pinning for specific repos.
     #[serde(default)]
     pub source: Vec<SourcePin>,
@@ -41,6 +47,270 @@ pub struct SourcePin {
     pub version: Option<String>,
 }
 
+/// `[search]` section: search tuning.
+///
+/// ```toml
+/// [search]
+/// synonym_weight = 0.5
+/// language = "english"        # stemmer for skills without a `language`
+/// stop_words = ["the", "a"]   # replaces the built-in list
+/// k1 = 1.2                    # term frequency saturation
+/// b = 0.75                    # document length normalization
+/// extra_fields = ["license", "metadata.keywords"]
+///
+/// [search.field_weights]
+/// trigger = 3.0
+/// content = 0.25
+///
+/// [search.synonyms]
+/// k8s = ["kubernetes"]
+/// gha = ["github actions"]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SearchConfig {
+    /// Whether to include the built-in synonym dictionary.
+    pub builtin_synonyms: bool,
+    /// Weight applied to query terms added by synonym expansion
+    /// (original query terms have weight 1.0).
+    pub synonym_weight: f64,
+    /// Extra synonym groups: term -> equivalent terms or phrases.
+    pub synonyms: BTreeMap<String, Vec<String>>,
+    /// Default analysis language (stemmer and stop words). Skills can
+    /// override it with their own `language` field.
+    pub language: Language,
+    /// Stop words for the default language. `None` uses the built-in list.
+    pub stop_words: Option<Vec<String>>,
+    /// BM25 term frequency saturation.
+    pub k1: f64,
+    /// BM25 document length normalization, `0.0..=1.0`.
+    pub b: f64,
+    /// Per-field weight overrides; unlisted fields keep their defaults
+    /// (see [`crate::search::DEFAULT_FIELD_WEIGHTS`]).
+    pub field_weights: BTreeMap<String, f64>,
+    /// Optional fields to index in addition to the defaults: one of
+    /// [`crate::search::EXTRA_FIELDS`] or `metadata.<key>` for a SKILL.md
+    /// frontmatter key.
+    pub extra_fields: Vec<String>,
+    /// Re-ranking signals blended into text relevance.
+    pub ranking: RankingConfig,
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        Self {
+            builtin_synonyms: true,
+            synonym_weight: crate::synonyms::DEFAULT_SYNONYM_WEIGHT,
+            synonyms: BTreeMap::new(),
+            language: Language::English,
+            stop_words: None,
+            k1: 1.2,
+            b: 0.75,
+            field_weights: BTreeMap::new(),
+            extra_fields: Vec::new(),
+            ranking: RankingConfig::default(),
+        }
+    }
+}
+
+impl SearchConfig {
+    /// Check that BM25 parameters, weights, and field names are usable.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        let invalid = |msg: String| Err(Error::Config(format!("[search] {msg}")));
+
+        if !self.k1.is_finite() || self.k1 < 0.0 {
+            return invalid(format!("k1 must be a non-negative number, got {}", self.k1));
+        }
+        if !(0.0..=1.0).contains(&self.b) {
+            return invalid(format!("b must be between 0.0 and 1.0, got {}", self.b));
+        }
+        if !self.synonym_weight.is_finite() || self.synonym_weight < 0.0 {
+            return invalid(format!(
+                "synonym_weight must be a non-negative number, got {}",
+                self.synonym_weight
+            ));
+        }
+        for field in &self.extra_fields {
+            if crate::search::DEFAULT_FIELD_WEIGHTS
+                .iter()
+                .any(|(f, _)| f == field)
+            {
+                return invalid(format!(
+                    "extra_fields: `{field}` is already indexed by default"
+                ));
+            }
+            if !crate::search::is_extra_field(field) {
+                return invalid(format!(
+                    "extra_fields: unknown field `{field}` (expected one of {}, or metadata.<key>)",
+                    crate::search::EXTRA_FIELDS.join(", ")
+                ));
+            }
+        }
+        for (field, weight) in &self.field_weights {
+            let known = crate::search::DEFAULT_FIELD_WEIGHTS
+                .iter()
+                .any(|(f, _)| f == field)
+                || self.extra_fields.contains(field);
+            if !known {
+                return invalid(format!("field_weights: `{field}` is not an indexed field"));
+            }
+            if !weight.is_finite() || *weight < 0.0 {
+                return invalid(format!(
+                    "field_weights: `{field}` must be a non-negative number, got {weight}"
+                ));
+            }
+        }
+        let ranking = &self.ranking;
+        for (name, weight) in [
+            ("trust_tier", ranking.trust_tier),
+            ("recency", ranking.recency),
+            ("recency_half_life_days", ranking.recency_half_life_days),
+            ("verified", ranking.verified),
+            ("metadata", ranking.metadata),
+            ("repo_priority", ranking.repo_priority),
+        ] {
+            if !weight.is_finite() || weight < 0.0 {
+                return invalid(format!(
+                    "ranking.{name} must be a non-negative number, got {weight}"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `[search.ranking]` section: weights for signals that adjust BM25 scores.
+///
+/// Each signal is normalized to 0.0..=1.0 and the final score is
+/// `bm25 * (1 + sum(weight * signal))`, so a weight of 0.2 lets a signal
+/// lift a skill by up to 20% over an otherwise equal match.
+///
+/// ```toml
+/// [search.ranking]
+/// trust_tier = 0.3        # direct > suggested > transitive
+/// recency = 0.1           # recently published versions
+/// recency_half_life_days = 180
+/// verified = 0.1          # has `verified_with` entries
+/// metadata = 0.1          # has trigger, categories, and tags
+/// repo_priority = 0.1     # earlier configured repos first
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RankingConfig {
+    /// Whether to re-rank at all. When false, results are pure BM25.
+    pub enabled: bool,
+    /// Weight of the trust tier signal (direct 1.0, suggested 0.5, transitive 0.0).
+    pub trust_tier: f64,
+    /// Weight of the publish recency signal.
+    pub recency: f64,
+    /// Age in days at which the recency signal halves.
+    pub recency_half_life_days: f64,
+    /// Weight of the `verified_with` presence signal.
+    pub verified: f64,
+    /// Weight of the metadata completeness signal.
+    pub metadata: f64,
+    /// Weight of the repo order signal (first configured repo 1.0).
+    pub repo_priority: f64,
+}
+
+impl Default for RankingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            trust_tier: 0.3,
+            recency: 0.1,
+            recency_half_life_days: 180.0,
+            verified: 0.1,
+            metadata: 0.1,
+            repo_priority: 0.1,
+        }
+    }
+}
+
+/// `[taxonomy]` section: tag and category normalization.
+///
+/// Labels are always lowercased with whitespace collapsed to `-`; aliases
+/// then merge synonyms into one canonical label (see [`crate::taxonomy`]).
+///
+/// ```toml
+/// [taxonomy.aliases]
+/// k8s = "kubernetes"
+/// "ci-cd" = "ci"
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaxonomyConfig {
+    /// Alias -> canonical label, applied to both tags and categories.
+    pub aliases: BTreeMap<String, String>,
+}
+
+/// `[agent]` section: describes the agent consuming skills, so skills whose
+/// `[skill.compatibility]` requirements it can't meet are hidden or flagged.
+///
+/// Top-level fields apply to every client; `[agent.clients.<name>]` overrides
+/// them for the MCP client that reports that name in `initialize`. Fields
+/// left unset are unknown, and unknown never excludes a skill.
+///
+/// ```toml
+/// [agent]
+/// compatibility = "hide"   # hide | flag | off
+/// tool_use = true
+/// capabilities = ["shell_exec", "file_read", "file_write"]
+///
+/// [agent.clients.claude-code]
+/// vision = true
+/// context_tokens = 200000
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgentConfig {
+    /// What to do with incompatible skills.
+    pub compatibility: CompatibilityMode,
+    #[serde(flatten)]
+    pub profile: AgentOverrides,
+    /// Per-client overrides, keyed by MCP client name.
+    pub clients: BTreeMap<String, AgentOverrides>,
+}
+
+/// How incompatible skills are treated in search, prompts, and info.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompatibilityMode {
+    /// Leave them out of search results and prompt listings.
+    #[default]
+    Hide,
+    /// Keep them, marked with the reasons they don't fit.
+    Flag,
+    /// Don't check compatibility.
+    Off,
+}
+
+impl std::fmt::Display for CompatibilityMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Hide => f.write_str("hide"),
+            Self::Flag => f.write_str("flag"),
+            Self::Off => f.write_str("off"),
+        }
+    }
+}
+
+/// Agent traits that can be set globally or per client.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgentOverrides {
+    /// Whether the agent can call tools.
+    pub tool_use: Option<bool>,
+    /// Whether the agent accepts images.
+    pub vision: Option<bool>,
+    /// Context window size in tokens.
+    pub context_tokens: Option<u64>,
+    /// Capabilities the agent has (e.g. "shell_exec", "file_read").
+    pub capabilities: Option<Vec<String>>,
+    /// MCP servers available alongside skillet.
+    pub mcp_servers: Option<Vec<String>>,
+}
+
 /// `[suggest]` section: controls `[[suggest]]` graph traversal.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -116,6 +386,39 @@ impl Default for CacheConfig {
     }
 }
 
+/// `[files]` section: size caps for skillpack files loaded into the index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FilesConfig {
+    /// Largest single file to load (e.g. "1MB"); bigger files are skipped.
+    pub max_file_size: String,
+    /// Total size of all extra files in one skill (e.g. "10MB").
+    pub max_skill_size: String,
+}
+
+impl FilesConfig {
+    /// Check that both sizes parse.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        for (key, value) in [
+            ("max_file_size", &self.max_file_size),
+            ("max_skill_size", &self.max_skill_size),
+        ] {
+            crate::repo::parse_size(value)
+                .map_err(|e| Error::Config(format!("[files] {key}: {e}")))?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for FilesConfig {
+    fn default() -> Self {
+        Self {
+            max_file_size: "1MB".to_string(),
+            max_skill_size: "10MB".to_string(),
+        }
+    }
+}
+
 /// `[repos]` section: default local and remote repos.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -128,6 +431,88 @@ pub struct ReposConfig {
     /// Maximum depth for following suggestion links (default: 1).
     #[serde(default = "default_suggest_depth")]
     pub suggest_depth: u32,
+    /// Maximum repos to clone and index at once, for both configured
+    /// remotes and each level of the suggest graph (default: 4).
+    pub concurrency: usize,
+    /// Which skill to keep when several repos provide the same owner/name
+    /// (default: first-wins).
+    pub conflict: ConflictPolicy,
+    /// Repo paths or URLs, highest priority first, for
+    /// `conflict = "priority"`.
+    pub priority: Vec<String>,
+    /// Alias -> repo path or URL. Skills from an aliased repo are
+    /// identified as `alias:owner/name`, so they never collide with
+    /// same-named skills from other repos.
+    pub aliases: BTreeMap<String, String>,
+    /// Repo path or URL -> agent instruction formats to import as skills
+    /// (Cursor rules, Copilot instructions, AGENTS.md).
+    pub import: BTreeMap<String, Vec<ImportFormat>>,
+}
+
+impl ReposConfig {
+    /// Check that aliases are usable in skill references and prompt names.
+    pub fn validate(&self) -> crate::error::Result<()> {
+        for alias in self.aliases.keys() {
+            if !crate::state::is_valid_alias(alias) {
+                return Err(Error::Config(format!(
+                    "[repos.aliases] invalid alias '{alias}': use lowercase letters, digits and hyphens"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How to choose between skills with the same owner and name from
+/// different repos.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConflictPolicy {
+    /// Keep the skill from the repo loaded first (configured order).
+    #[default]
+    FirstWins,
+    /// Keep the skill with the highest latest version.
+    HighestVersion,
+    /// Keep the skill from the most trusted repo (direct over suggested
+    /// over transitive).
+    TrustTier,
+    /// Keep the skill from the repo listed first in `[repos].priority`.
+    Priority,
+}
+
+impl std::fmt::Display for ConflictPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FirstWins => f.write_str("first-wins"),
+            Self::HighestVersion => f.write_str("highest-version"),
+            Self::TrustTier => f.write_str("trust-tier"),
+            Self::Priority => f.write_str("priority"),
+        }
+    }
+}
+
+/// Agent instruction formats that can be imported as skills; see
+/// [`crate::import`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ImportFormat {
+    /// `.cursor/rules/**/*.mdc` and legacy `.cursorrules`
+    Cursor,
+    /// `.github/copilot-instructions.md` and
+    /// `.github/instructions/**/*.instructions.md`
+    Copilot,
+    /// `AGENTS.md` at the repo root
+    AgentsMd,
+}
+
+impl std::fmt::Display for ImportFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cursor => f.write_str("cursor"),
+            Self::Copilot => f.write_str("copilot"),
+            Self::AgentsMd => f.write_str("agents-md"),
+        }
+    }
 }
 
 impl Default for ReposConfig {
@@ -137,6 +522,11 @@ impl Default for ReposConfig {
             remote: Vec::new(),
             follow_suggestions: true,
             suggest_depth: 1,
+            concurrency: 4,
+            conflict: ConflictPolicy::default(),
+            priority: Vec::new(),
+            aliases: BTreeMap::new(),
+            import: BTreeMap::new(),
         }
     }
 }
@@ -170,6 +560,9 @@ pub fn load_config_from(path: &Path) -> crate::error::Result<SkilletConfig> {
         path: path.to_path_buf(),
         source: e,
     })?;
+    config.search.validate()?;
+    config.files.validate()?;
+    config.repos.validate()?;
     Ok(config)
 }
 
@@ -322,6 +715,8 @@ remote = ["https://github.com/owner/repo.git"]
         let config = ReposConfig::default();
         assert!(config.follow_suggestions);
         assert_eq!(config.suggest_depth, 1);
+        assert_eq!(config.concurrency, 4);
+        assert_eq!(config.conflict, ConflictPolicy::FirstWins);
     }
 
     #[test]
@@ -334,6 +729,9 @@ remote = ["https://github.com/owner/repo.git"]
 [repos]
 follow_suggestions = false
 suggest_depth = 3
+concurrency = 8
+conflict = "priority"
+priority = ["/srv/skills", "https://github.com/acme/skills.git"]
 "#,
         )
         .unwrap();
@@ -341,6 +739,50 @@ suggest_depth = 3
         let config = load_config_from(&path).unwrap();
         assert!(!config.repos.follow_suggestions);
         assert_eq!(config.repos.suggest_depth, 3);
+        assert_eq!(config.repos.concurrency, 8);
+        assert_eq!(config.repos.conflict, ConflictPolicy::Priority);
+        assert_eq!(config.repos.priority.len(), 2);
+    }
+
+    #[test]
+    fn test_repos_aliases() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.toml");
+        std::fs::write(
+            &path,
+            r#"
+[repos.aliases]
+fork = "/srv/fork"
+upstream = "https://github.com/acme/skills.git"
+"#,
+        )
+        .unwrap();
+        let config = load_config_from(&path).unwrap();
+        assert_eq!(config.repos.aliases.len(), 2);
+        assert_eq!(config.repos.aliases["fork"], "/srv/fork");
+
+        std::fs::write(&path, "[repos.aliases]\n\"My_Fork\" = \"/srv/fork\"\n").unwrap();
+        let err = load_config_from(&path).unwrap_err().to_string();
+        assert!(err.contains("invalid alias 'My_Fork'"), "{err}");
+    }
+
+    #[test]
+    fn test_repos_import() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[repos.import]\n\"/srv/app\" = [\"cursor\", \"agents-md\"]\n",
+        )
+        .unwrap();
+        let config = load_config_from(&path).unwrap();
+        assert_eq!(
+            config.repos.import["/srv/app"],
+            vec![ImportFormat::Cursor, ImportFormat::AgentsMd]
+        );
+
+        std::fs::write(&path, "[repos.import]\n\"/srv/app\" = [\"windsurf\"]\n").unwrap();
+        assert!(load_config_from(&path).is_err());
     }
 
     #[test]
@@ -369,6 +811,144 @@ resources = ["skills", "metadata"]
         assert_eq!(config.server.resources, vec!["skills", "metadata"]);
     }
 
+    #[test]
+    fn test_search_config_defaults() {
+        let config = SkilletConfig::default();
+        assert!(config.search.builtin_synonyms);
+        assert!(config.search.synonyms.is_empty());
+        assert!(config.search.synonym_weight < 1.0);
+        assert_eq!(config.search.language, Language::English);
+        assert!(config.search.stop_words.is_none());
+    }
+
+    #[test]
+    fn test_search_ranking_parse() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[search.ranking]\ntrust_tier = 0.5\nenabled = false\n",
+        )
+        .unwrap();
+
+        let config = load_config_from(&path).unwrap();
+        assert!(!config.search.ranking.enabled);
+        assert_eq!(config.search.ranking.trust_tier, 0.5);
+        // Unset weights keep their defaults
+        assert_eq!(config.search.ranking.recency, 0.1);
+    }
+
+    #[test]
+    fn test_agent_config_parse() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[agent]\ncompatibility = \"flag\"\ntool_use = true\n\
+             capabilities = [\"shell_exec\"]\n\n\
+             [agent.clients.claude-code]\nvision = true\n",
+        )
+        .unwrap();
+
+        let config = load_config_from(&path).unwrap();
+        assert_eq!(config.agent.compatibility, CompatibilityMode::Flag);
+        assert_eq!(config.agent.profile.tool_use, Some(true));
+        assert_eq!(
+            config.agent.profile.capabilities,
+            Some(vec!["shell_exec".to_string()])
+        );
+        assert_eq!(config.agent.clients["claude-code"].vision, Some(true));
+        assert_eq!(config.agent.profile.vision, None);
+    }
+
+    #[test]
+    fn test_search_bm25_params_parse() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[search]\nk1 = 1.5\nb = 0.5\nextra_fields = [\"license\", \"metadata.keywords\"]\n\n\
+             [search.field_weights]\ntrigger = 3.0\nlicense = 0.5\n",
+        )
+        .unwrap();
+
+        let config = load_config_from(&path).unwrap();
+        assert_eq!(config.search.k1, 1.5);
+        assert_eq!(config.search.b, 0.5);
+        assert_eq!(config.search.field_weights["trigger"], 3.0);
+        assert_eq!(
+            config.search.extra_fields,
+            vec!["license", "metadata.keywords"]
+        );
+    }
+
+    #[test]
+    fn test_search_config_validation() {
+        let cases = [
+            ("[search]\nk1 = -1.0\n", "k1"),
+            ("[search]\nb = 1.5\n", "b must be"),
+            (
+                "[search]\nextra_fields = [\"bogus\"]\n",
+                "unknown field `bogus`",
+            ),
+            ("[search]\nextra_fields = [\"name\"]\n", "already indexed"),
+            (
+                "[search.field_weights]\nlicense = 1.0\n",
+                "not an indexed field",
+            ),
+            ("[search.field_weights]\ntrigger = -2.0\n", "`trigger`"),
+            ("[search.ranking]\nrecency = -0.1\n", "ranking.recency"),
+        ];
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.toml");
+        for (toml, expected) in cases {
+            std::fs::write(&path, toml).unwrap();
+            let err = load_config_from(&path).unwrap_err().to_string();
+            assert!(err.contains(expected), "{toml:?}: {err}");
+        }
+    }
+
+    #[test]
+    fn test_search_language_parse() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[search]\nlanguage = \"de\"\nstop_words = [\"der\"]\n",
+        )
+        .unwrap();
+
+        let config = load_config_from(&path).unwrap();
+        assert_eq!(config.search.language, Language::German);
+        assert_eq!(config.search.stop_words, Some(vec!["der".to_string()]));
+
+        std::fs::write(&path, "[search]\nlanguage = \"klingon\"\n").unwrap();
+        assert!(load_config_from(&path).is_err());
+    }
+
+    #[test]
+    fn test_search_synonyms_parse() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("config.toml");
+        std::fs::write(
+            &path,
+            r#"
+[search]
+synonym_weight = 0.3
+
+[search.synonyms]
+k8s = ["kubernetes"]
+gha = ["github actions"]
+"#,
+        )
+        .unwrap();
+
+        let config = load_config_from(&path).unwrap();
+        assert_eq!(config.search.synonym_weight, 0.3);
+        assert_eq!(config.search.synonyms["k8s"], vec!["kubernetes"]);
+        assert_eq!(config.search.synonyms["gha"], vec!["github actions"]);
+    }
+
     #[test]
     fn test_add_remote_deduplicates() {
         let mut config = SkilletConfig::default();
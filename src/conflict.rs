@@ -0,0 +1,253 @@
+//! Conflict resolution for skills with the same owner and name in
+//! several repos.
+//!
+//! Repos are merged one at a time in configured order (locals, remotes,
+//! then the suggest graph). When an incoming skill collides with one
+//! already in the index, the [`MergePolicy`] picks the winner and the
+//! loser is recorded as a [`Shadowing`](crate::state::Shadowing).
+
+use std::cmp::Ordering;
+use std::path::{Path, PathBuf};
+
+use crate::config::{ConflictPolicy, ReposConfig};
+use crate::state::{SkillEntry, TrustTier};
+
+/// A conflict policy plus the repo priority list it may need.
+#[derive(Debug, Clone, Default)]
+pub struct MergePolicy {
+    pub conflict: ConflictPolicy,
+    /// Repo directories, highest priority first (for
+    /// [`ConflictPolicy::Priority`])
+    pub priority: Vec<PathBuf>,
+}
+
+impl MergePolicy {
+    /// Build from `[repos]`, mapping priority URLs to their checkouts
+    /// under `cache_base`.
+    pub fn from_config(config: &ReposConfig, cache_base: &Path) -> Self {
+        let priority = config
+            .priority
+            .iter()
+            .map(|repo| {
+                if repo.contains("://") || repo.starts_with("git@") {
+                    crate::repo::cache_dir_for_url(cache_base, repo)
+                } else {
+                    PathBuf::from(repo)
+                }
+            })
+            .collect();
+        Self {
+            conflict: config.conflict,
+            priority,
+        }
+    }
+
+    /// Whether `incoming` should replace `existing`. Ties keep `existing`,
+    /// so every policy falls back to first-wins.
+    pub fn prefers(&self, existing: &SkillEntry, incoming: &SkillEntry) -> bool {
+        match self.conflict {
+            ConflictPolicy::FirstWins => false,
+            ConflictPolicy::HighestVersion => {
+                compare_versions(latest_version(incoming), latest_version(existing))
+                    == Ordering::Greater
+            }
+            ConflictPolicy::TrustTier => {
+                trust_rank(&incoming.trust_tier) < trust_rank(&existing.trust_tier)
+            }
+            ConflictPolicy::Priority => self.rank(incoming) < self.rank(existing),
+        }
+    }
+
+    /// Position of the entry's repo in the priority list; unlisted repos
+    /// (and embedded skills) rank last.
+    fn rank(&self, entry: &SkillEntry) -> usize {
+        let Some(ref origin) = entry.origin else {
+            return usize::MAX;
+        };
+        let origin = canonical(origin);
+        self.priority
+            .iter()
+            .position(|p| origin.starts_with(canonical(p)))
+            .unwrap_or(usize::MAX)
+    }
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn latest_version(entry: &SkillEntry) -> &str {
+    entry.latest().map(|v| v.version.as_str()).unwrap_or("")
+}
+
+fn trust_rank(tier: &TrustTier) -> u8 {
+    match tier {
+        TrustTier::Direct => 0,
+        TrustTier::Suggested => 1,
+        TrustTier::Transitive => 2,
+    }
+}
+
+/// Compare versions, ignoring a leading `v`.
+///
+/// Two semver versions compare by semver precedence, so pre-releases rank
+/// below their release (`1.0.0-beta` < `1.0.0`). Partial versions count as
+/// semver with the missing parts zero (`1.0` = `1.0.0`). Otherwise dotted
+/// versions compare numerically where possible (`1.10.0` > `1.9.0`,
+/// `2026.02.24` > `2026.01.31`) and non-numeric parts compare as text.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    if let (Some(x), Some(y)) = (parse_semver(a), parse_semver(b)) {
+        return x.cmp_precedence(&y);
+    }
+
+    let parts = |v: &str| -> Vec<String> {
+        v.trim_start_matches('v')
+            .split(['.', '-', '+'])
+            .map(str::to_string)
+            .collect()
+    };
+    let (a, b) = (parts(a), parts(b));
+    for i in 0..a.len().max(b.len()) {
+        let (x, y) = (
+            a.get(i).map(String::as_str).unwrap_or("0"),
+            b.get(i).map(String::as_str).unwrap_or("0"),
+        );
+        let ord = match (x.parse::<u64>(), y.parse::<u64>()) {
+            (Ok(x), Ok(y)) => x.cmp(&y),
+            _ => x.cmp(y),
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+    Ordering::Equal
+}
+
+/// Parse `v` as semver, padding a partial version core (`1`, `1.0`) to
+/// three parts first.
+fn parse_semver(v: &str) -> Option<semver::Version> {
+    let v = v.trim_start_matches('v');
+    let (core, rest) = v.split_at(v.find(['-', '+']).unwrap_or(v.len()));
+    let padding = match core.split('.').count() {
+        1 => ".0.0",
+        2 => ".0",
+        _ => "",
+    };
+    semver::Version::parse(&format!("{core}{padding}{rest}")).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{SkillInfo, SkillMetadata, SkillSource, SkillVersion};
+
+    fn entry(version: &str, tier: TrustTier, origin: &str) -> SkillEntry {
+        SkillEntry {
+            owner: "acme".to_string(),
+            name: "tool".to_string(),
+            repo_path: None,
+            versions: vec![SkillVersion {
+                version: version.to_string(),
+                metadata: SkillMetadata {
+                    skill: SkillInfo {
+                        name: "tool".to_string(),
+                        owner: "acme".to_string(),
+                        version: version.to_string(),
+                        description: "Tool".to_string(),
+                        trigger: None,
+                        license: None,
+                        author: None,
+                        classification: None,
+                        compatibility: None,
+                        language: None,
+                        files: None,
+                        activation: None,
+                        allowed_tools: None,
+                        model: None,
+                    },
+                    frontmatter: Default::default(),
+                },
+                skill_md: "# Tool".to_string(),
+                skill_toml_raw: String::new(),
+                yanked: false,
+                files: Default::default(),
+                published: None,
+                has_content: true,
+            }],
+            source: SkillSource::Repo,
+            trust_tier: tier,
+            discovered_via: Vec::new(),
+            origin: Some(PathBuf::from(origin)),
+        }
+    }
+
+    #[test]
+    fn test_compare_versions() {
+        assert_eq!(compare_versions("1.10.0", "1.9.0"), Ordering::Greater);
+        assert_eq!(compare_versions("v2.0", "2.0.0"), Ordering::Equal);
+        assert_eq!(
+            compare_versions("2026.02.24", "2026.1.31"),
+            Ordering::Greater
+        );
+        assert_eq!(compare_versions("1.0.0", "1.0.1"), Ordering::Less);
+        assert_eq!(compare_versions("1.0.0-beta", "1.0.0"), Ordering::Less);
+        assert_eq!(
+            compare_versions("1.0.0-beta", "1.0.0-alpha"),
+            Ordering::Greater
+        );
+        assert_eq!(compare_versions("1.0.0+build.2", "1.0.0"), Ordering::Equal);
+
+        // Partial versions are padded before semver, keeping the order
+        // transitive across formats
+        assert_eq!(compare_versions("1.0", "1.0.0-beta"), Ordering::Greater);
+        assert_eq!(compare_versions("1.0.0-beta", "1.0"), Ordering::Less);
+        assert_eq!(compare_versions("1.0", "1.0.0"), Ordering::Equal);
+        assert_eq!(compare_versions("2-rc.1", "2.0.0"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_policies() {
+        let first = entry("1.0.0", TrustTier::Suggested, "/repos/a");
+        let second = entry("2.0.0", TrustTier::Direct, "/repos/b");
+
+        let policy = |conflict| MergePolicy {
+            conflict,
+            priority: vec![PathBuf::from("/repos/b")],
+        };
+        assert!(!policy(ConflictPolicy::FirstWins).prefers(&first, &second));
+        assert!(policy(ConflictPolicy::HighestVersion).prefers(&first, &second));
+        assert!(!policy(ConflictPolicy::HighestVersion).prefers(&second, &first));
+        assert!(policy(ConflictPolicy::TrustTier).prefers(&first, &second));
+        assert!(policy(ConflictPolicy::Priority).prefers(&first, &second));
+        assert!(!policy(ConflictPolicy::Priority).prefers(&second, &first));
+    }
+
+    #[test]
+    fn test_ties_keep_existing() {
+        let a = entry("1.0.0", TrustTier::Direct, "/repos/a");
+        let b = entry("1.0.0", TrustTier::Direct, "/repos/b");
+        for conflict in [
+            ConflictPolicy::HighestVersion,
+            ConflictPolicy::TrustTier,
+            ConflictPolicy::Priority,
+        ] {
+            let policy = MergePolicy {
+                conflict,
+                priority: vec![],
+            };
+            assert!(!policy.prefers(&a, &b), "{conflict:?}");
+        }
+    }
+
+    #[test]
+    fn test_highest_version_ranks_release_above_prerelease() {
+        let beta = entry("1.0.0-beta", TrustTier::Direct, "/repos/a");
+        let release = entry("1.0.0", TrustTier::Direct, "/repos/b");
+        let policy = MergePolicy {
+            conflict: ConflictPolicy::HighestVersion,
+            priority: vec![],
+        };
+        assert!(policy.prefers(&beta, &release));
+        assert!(!policy.prefers(&release, &beta));
+    }
+}
@@ -33,6 +33,8 @@ pub enum Error {
     // -- Repo --
     #[error("invalid duration: {0}")]
     InvalidDuration(String),
+    #[error("invalid size: {0}")]
+    InvalidSize(String),
 
     // -- Index --
     #[error("failed to load skill at {path}: {reason}")]
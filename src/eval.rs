@@ -0,0 +1,273 @@
+//! Search quality evaluation against golden queries.
+//!
+//! A golden file lists queries with the `owner/name` skills they are
+//! expected to return. Running them through [`SkillSearch`] gives per-query
+//! and mean reciprocal rank, recall@k, and nDCG@k, so changes to stop
+//! words, stemming, or weights can be compared before and after.
+//!
+//! TOML:
+//!
+//! ```toml
+//! [[case]]
+//! query = "lint rust code"
+//! expected = ["joshrotenberg/rust-dev"]
+//! ```
+//!
+//! JSONL (`.jsonl` or `.json`), one case per line:
+//!
+//! ```text
+//! {"query": "lint rust code", "expected": ["joshrotenberg/rust-dev"]}
+//! ```
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Error;
+use crate::search::SkillSearch;
+
+/// A golden query and the skills it should find.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvalCase {
+    pub query: String,
+    /// Relevant skills as `owner/name`, in no particular order.
+    pub expected: Vec<String>,
+}
+
+/// TOML golden file: a list of `[[case]]` tables.
+#[derive(Debug, Deserialize)]
+struct EvalFile {
+    #[serde(default)]
+    case: Vec<EvalCase>,
+}
+
+/// Metrics for one query.
+#[derive(Debug, Clone, Serialize)]
+pub struct QueryMetrics {
+    pub query: String,
+    /// Reciprocal rank of the first relevant result (0.0 if none in top k).
+    pub reciprocal_rank: f64,
+    /// Fraction of expected skills found in the top k.
+    pub recall: f64,
+    /// Normalized discounted cumulative gain over the top k.
+    pub ndcg: f64,
+    /// Top k results as `owner/name`.
+    pub results: Vec<String>,
+    /// Expected skills not in the top k.
+    pub missing: Vec<String>,
+}
+
+/// Aggregate evaluation results.
+#[derive(Debug, Clone, Serialize)]
+pub struct EvalReport {
+    /// Cutoff used for recall and nDCG.
+    pub k: usize,
+    /// Mean reciprocal rank.
+    pub mrr: f64,
+    pub mean_recall: f64,
+    pub mean_ndcg: f64,
+    pub queries: Vec<QueryMetrics>,
+}
+
+/// Load golden queries from a TOML or JSONL file (by extension).
+pub fn load_cases(path: &Path) -> crate::error::Result<Vec<EvalCase>> {
+    let raw = std::fs::read_to_string(path).map_err(|e| Error::FileRead {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+
+    let is_json = path
+        .extension()
+        .is_some_and(|ext| ext == "jsonl" || ext == "json");
+    let cases = if is_json {
+        raw.lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line)
+                    .map_err(|e| Error::Other(format!("{}:{}: {e}", path.display(), i + 1)))
+            })
+            .collect::<crate::error::Result<Vec<EvalCase>>>()?
+    } else {
+        toml::from_str::<EvalFile>(&raw)
+            .map_err(|e| Error::TomlParse {
+                path: path.to_path_buf(),
+                source: e,
+            })?
+            .case
+    };
+
+    for case in &cases {
+        if let Some(bad) = case.expected.iter().find(|e| !is_skill_ref(e)) {
+            return Err(Error::Other(format!(
+                "{}: query {:?}: expected '{bad}' is not in owner/name format",
+                path.display(),
+                case.query
+            )));
+        }
+    }
+    Ok(cases)
+}
+
+fn is_skill_ref(s: &str) -> bool {
+    s.split_once('/')
+        .is_some_and(|(owner, name)| !owner.is_empty() && !name.is_empty())
+}
+
+/// Run every case through `search` and score the top `k` results.
+pub fn evaluate(search: &SkillSearch, cases: &[EvalCase], k: usize) -> EvalReport {
+    let queries: Vec<QueryMetrics> = cases
+        .iter()
+        .map(|case| {
+            let results: Vec<String> = search
+                .search(&case.query, k)
+                .into_iter()
+                .map(|(owner, name, _)| format!("{owner}/{name}"))
+                .collect();
+            score_case(case, results, k)
+        })
+        .collect();
+
+    let mean = |f: fn(&QueryMetrics) -> f64| {
+        if queries.is_empty() {
+            0.0
+        } else {
+            queries.iter().map(f).sum::<f64>() / queries.len() as f64
+        }
+    };
+
+    EvalReport {
+        k,
+        mrr: mean(|q| q.reciprocal_rank),
+        mean_recall: mean(|q| q.recall),
+        mean_ndcg: mean(|q| q.ndcg),
+        queries,
+    }
+}
+
+/// Score one query's ranked results against its expected skills.
+fn score_case(case: &EvalCase, mut results: Vec<String>, k: usize) -> QueryMetrics {
+    results.truncate(k);
+    let relevant = |id: &String| case.expected.contains(id);
+
+    let reciprocal_rank = results
+        .iter()
+        .position(relevant)
+        .map(|i| 1.0 / (i + 1) as f64)
+        .unwrap_or(0.0);
+
+    let found = results.iter().filter(|r| relevant(r)).count();
+    let recall = if case.expected.is_empty() {
+        0.0
+    } else {
+        found as f64 / case.expected.len() as f64
+    };
+
+    // Binary relevance; rank i (0-based) is discounted by log2(i + 2)
+    let dcg: f64 = results
+        .iter()
+        .enumerate()
+        .filter(|(_, r)| relevant(r))
+        .map(|(i, _)| 1.0 / (i as f64 + 2.0).log2())
+        .sum();
+    let ideal: f64 = (0..case.expected.len().min(k))
+        .map(|i| 1.0 / (i as f64 + 2.0).log2())
+        .sum();
+    let ndcg = if ideal > 0.0 { dcg / ideal } else { 0.0 };
+
+    let missing = case
+        .expected
+        .iter()
+        .filter(|e| !results.contains(e))
+        .cloned()
+        .collect();
+
+    QueryMetrics {
+        query: case.query.clone(),
+        reciprocal_rank,
+        recall,
+        ndcg,
+        results,
+        missing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn case(expected: &[&str]) -> EvalCase {
+        EvalCase {
+            query: "q".to_string(),
+            expected: expected.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    fn results(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_perfect_ranking() {
+        let m = score_case(&case(&["a/x", "a/y"]), results(&["a/x", "a/y", "a/z"]), 10);
+        assert_eq!(m.reciprocal_rank, 1.0);
+        assert_eq!(m.recall, 1.0);
+        assert!((m.ndcg - 1.0).abs() < 1e-10);
+        assert!(m.missing.is_empty());
+    }
+
+    #[test]
+    fn test_partial_ranking() {
+        let m = score_case(&case(&["a/x", "a/y"]), results(&["a/z", "a/x"]), 10);
+        assert_eq!(m.reciprocal_rank, 0.5);
+        assert_eq!(m.recall, 0.5);
+        // DCG = 1/log2(3); IDCG = 1 + 1/log2(3)
+        let expected = (1.0 / 3f64.log2()) / (1.0 + 1.0 / 3f64.log2());
+        assert!((m.ndcg - expected).abs() < 1e-10);
+        assert_eq!(m.missing, vec!["a/y"]);
+    }
+
+    #[test]
+    fn test_cutoff_applies() {
+        let m = score_case(&case(&["a/x"]), results(&["a/y", "a/z", "a/x"]), 2);
+        assert_eq!(m.reciprocal_rank, 0.0);
+        assert_eq!(m.recall, 0.0);
+        assert_eq!(m.ndcg, 0.0);
+        assert_eq!(m.results.len(), 2);
+    }
+
+    #[test]
+    fn test_load_toml_and_jsonl() {
+        let tmp = tempfile::tempdir().unwrap();
+        let toml_path = tmp.path().join("golden.toml");
+        std::fs::write(
+            &toml_path,
+            "[[case]]\nquery = \"rust\"\nexpected = [\"acme/rust-dev\"]\n",
+        )
+        .unwrap();
+        let jsonl_path = tmp.path().join("golden.jsonl");
+        std::fs::write(
+            &jsonl_path,
+            "{\"query\": \"rust\", \"expected\": [\"acme/rust-dev\"]}\n\n",
+        )
+        .unwrap();
+
+        let from_toml = load_cases(&toml_path).unwrap();
+        let from_jsonl = load_cases(&jsonl_path).unwrap();
+        assert_eq!(from_toml, from_jsonl);
+        assert_eq!(from_toml[0].expected, vec!["acme/rust-dev"]);
+    }
+
+    #[test]
+    fn test_load_rejects_bad_skill_ref() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("golden.jsonl");
+        std::fs::write(
+            &path,
+            "{\"query\": \"rust\", \"expected\": [\"rust-dev\"]}\n",
+        )
+        .unwrap();
+        let err = load_cases(&path).unwrap_err().to_string();
+        assert!(err.contains("owner/name"), "{err}");
+    }
+}
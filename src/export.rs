@@ -0,0 +1,804 @@
+//! Export skills to agent-native on-disk layouts.
+//!
+//! For agents that don't speak MCP, `skillet export` writes skills from the
+//! merged index into the files those agents already read:
+//!
+//! | Target | Layout |
+//! |---|---|
+//! | `claude` | `.claude/skills/<name>/SKILL.md` plus skillpack files |
+//! | `cursor` | `.cursor/rules/<name>.mdc`, files under `.cursor/rules/<name>/` |
+//! | `copilot` | `.github/instructions/<name>.instructions.md`, files under `.github/instructions/<name>/` |
+//! | `agents-md` | one managed block in `AGENTS.md`, files under `.agents/<name>/` |
+//!
+//! SKILL.md is exported with its include directives expanded (see
+//! [`crate::include`]), since agents reading these files can't resolve them.
+//!
+//! Every export records the skill versions and content hashes in
+//! [`MANIFEST_FILE`], so [`check`] can later report files edited on disk and
+//! skills that changed since they were exported, including changes that
+//! only reach a skill through its includes.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::error::{Error, Result};
+use crate::include;
+use crate::state::{ActivationMode, SkillEntry, SkillIndex, SkillVersion};
+
+/// Manifest written to the export directory.
+pub const MANIFEST_FILE: &str = "skillet-export.toml";
+
+/// Markers around the skillet-managed part of `AGENTS.md`; the rest of the
+/// file is left alone.
+const BLOCK_BEGIN: &str = "<!-- skillet:begin -->";
+const BLOCK_END: &str = "<!-- skillet:end -->";
+
+/// An agent-native layout to export to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ExportTarget {
+    Claude,
+    Cursor,
+    Copilot,
+    AgentsMd,
+}
+
+impl ExportTarget {
+    pub const ALL: &[ExportTarget] = &[
+        ExportTarget::Claude,
+        ExportTarget::Cursor,
+        ExportTarget::Copilot,
+        ExportTarget::AgentsMd,
+    ];
+
+    /// Directory that holds a skill's skillpack files.
+    fn files_dir(self, name: &str) -> String {
+        match self {
+            Self::Claude => format!(".claude/skills/{name}"),
+            Self::Cursor => format!(".cursor/rules/{name}"),
+            Self::Copilot => format!(".github/instructions/{name}"),
+            Self::AgentsMd => format!(".agents/{name}"),
+        }
+    }
+}
+
+impl std::fmt::Display for ExportTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Claude => f.write_str("claude"),
+            Self::Cursor => f.write_str("cursor"),
+            Self::Copilot => f.write_str("copilot"),
+            Self::AgentsMd => f.write_str("agents-md"),
+        }
+    }
+}
+
+impl FromStr for ExportTarget {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|t| t.to_string() == s)
+            .ok_or_else(|| {
+                let names: Vec<String> = Self::ALL.iter().map(ToString::to_string).collect();
+                format!(
+                    "unknown target '{s}' (expected one of {})",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+/// `skillet-export.toml`: what was exported where.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExportManifest {
+    #[serde(default, rename = "skill")]
+    pub skills: Vec<ExportedSkill>,
+}
+
+/// One skill exported to one target.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedSkill {
+    pub owner: String,
+    pub name: String,
+    pub version: String,
+    pub target: ExportTarget,
+    /// Path relative to the export directory -> SHA-256 of the content
+    /// skillet wrote (for `AGENTS.md`, of the managed block)
+    pub files: BTreeMap<String, String>,
+}
+
+impl ExportManifest {
+    /// Read the manifest from `out`, or an empty one if there is none.
+    pub fn load(out: &Path) -> Result<Self> {
+        let path = out.join(MANIFEST_FILE);
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        let raw = std::fs::read_to_string(&path).map_err(|e| Error::FileRead {
+            path: path.clone(),
+            source: e,
+        })?;
+        toml::from_str(&raw).map_err(|e| Error::TomlParse { path, source: e })
+    }
+
+    fn save(&self, out: &Path) -> Result<()> {
+        let raw = toml::to_string_pretty(self)
+            .map_err(|e| Error::Other(format!("failed to serialize export manifest: {e}")))?;
+        write_file(
+            &out.join(MANIFEST_FILE),
+            format!("# Written by `skillet export`; used by `skillet export --check`.\n\n{raw}")
+                .as_bytes(),
+        )
+    }
+}
+
+/// Where a rendered file's content goes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Content {
+    /// The whole file
+    File(Vec<u8>),
+    /// The managed block of a shared file
+    Block(String),
+}
+
+impl Content {
+    fn hash(&self) -> String {
+        match self {
+            Self::File(bytes) => sha256(bytes),
+            Self::Block(text) => sha256(text.as_bytes()),
+        }
+    }
+}
+
+/// Files to write for one target, and which skills they belong to.
+#[derive(Debug)]
+pub struct Export {
+    pub target: ExportTarget,
+    pub skills: Vec<ExportedSkill>,
+    /// Includes that couldn't be expanded, as `owner/name: problem`
+    pub problems: Vec<String>,
+    files: BTreeMap<String, Content>,
+}
+
+/// Render `entries` (their latest versions) for `target`, resolving
+/// include directives against `index`.
+///
+/// Fails if two skills would be written under the same name or a skill
+/// has no available version.
+pub fn render(target: ExportTarget, entries: &[&SkillEntry], index: &SkillIndex) -> Result<Export> {
+    let mut export = Export {
+        target,
+        skills: Vec::new(),
+        problems: Vec::new(),
+        files: BTreeMap::new(),
+    };
+    let mut agents_md = String::new();
+
+    for entry in entries {
+        if let Some(other) = export.skills.iter().find(|s| s.name == entry.name) {
+            return Err(Error::Other(format!(
+                "{}/{} and {}/{} would both export as '{}'; export them separately",
+                other.owner, other.name, entry.owner, entry.name, entry.name
+            )));
+        }
+        let latest = entry.latest().ok_or_else(|| {
+            Error::Other(format!(
+                "no available versions for {}/{} (all yanked)",
+                entry.owner, entry.name
+            ))
+        })?;
+
+        let skill_md = if include::has_includes(&latest.skill_md) {
+            let expansion = include::expand(entry, index);
+            export.problems.extend(
+                expansion
+                    .problems
+                    .into_iter()
+                    .map(|p| format!("{}/{}: {p}", entry.owner, entry.name)),
+            );
+            expansion.text
+        } else {
+            latest.skill_md.clone()
+        };
+
+        let mut files = BTreeMap::new();
+        match target {
+            ExportTarget::Claude => {
+                files.insert(
+                    format!(".claude/skills/{}/SKILL.md", entry.name),
+                    Content::File(claude_skill_md(latest, &skill_md).into_bytes()),
+                );
+            }
+            ExportTarget::Cursor => {
+                files.insert(
+                    format!(".cursor/rules/{}.mdc", entry.name),
+                    Content::File(cursor_rule(latest, &skill_md).into_bytes()),
+                );
+            }
+            ExportTarget::Copilot => {
+                files.insert(
+                    format!(".github/instructions/{}.instructions.md", entry.name),
+                    Content::File(copilot_instructions(latest, &skill_md).into_bytes()),
+                );
+            }
+            ExportTarget::AgentsMd => agents_md_section(&mut agents_md, entry, latest, &skill_md),
+        }
+
+        let dir = target.files_dir(&entry.name);
+        for (rel, file) in &latest.files {
+            let bytes = file
+                .bytes()
+                .map_err(|e| Error::Other(format!("{rel}: invalid file content: {e}")))?;
+            files.insert(format!("{dir}/{rel}"), Content::File(bytes));
+        }
+
+        export.skills.push(ExportedSkill {
+            owner: entry.owner.clone(),
+            name: entry.name.clone(),
+            version: latest.version.clone(),
+            target,
+            files: files.iter().map(|(p, c)| (p.clone(), c.hash())).collect(),
+        });
+        export.files.extend(files);
+    }
+
+    if target == ExportTarget::AgentsMd {
+        let block = Content::Block(agents_md);
+        let hash = block.hash();
+        for skill in &mut export.skills {
+            skill.files.insert("AGENTS.md".to_string(), hash.clone());
+        }
+        export.files.insert("AGENTS.md".to_string(), block);
+    }
+    Ok(export)
+}
+
+/// Write `export` under `out` and record it in the manifest, replacing
+/// earlier exports of the same skills to the same target. For `agents-md`
+/// the managed block holds exactly these skills, so earlier `agents-md`
+/// entries are replaced as a whole.
+pub fn write(out: &Path, export: &Export) -> Result<()> {
+    for (rel, content) in &export.files {
+        let path = out.join(rel);
+        match content {
+            Content::File(bytes) => write_file(&path, bytes)?,
+            Content::Block(block) => {
+                let existing = std::fs::read_to_string(&path).unwrap_or_default();
+                write_file(&path, replace_block(&existing, block).as_bytes())?;
+            }
+        }
+    }
+
+    let mut manifest = ExportManifest::load(out)?;
+    manifest.skills.retain(|s| {
+        s.target != export.target
+            || (export.target != ExportTarget::AgentsMd
+                && !export
+                    .skills
+                    .iter()
+                    .any(|e| e.owner == s.owner && e.name == s.name))
+    });
+    manifest.skills.extend(export.skills.iter().cloned());
+    manifest
+        .skills
+        .sort_by(|a, b| (a.target, &a.owner, &a.name).cmp(&(b.target, &b.owner, &b.name)));
+    manifest.save(out)
+}
+
+/// A difference between an export and the files on disk or the skills
+/// in the index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Drift {
+    pub owner: String,
+    pub name: String,
+    pub target: ExportTarget,
+    #[serde(flatten)]
+    pub kind: DriftKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub enum DriftKind {
+    /// An exported file was edited on disk
+    Modified { path: String },
+    /// An exported file (or the `AGENTS.md` block) is gone
+    Missing { path: String },
+    /// The skill changed in its repo since it was exported
+    Outdated { exported: String, current: String },
+    /// The skill is no longer in any loaded repo
+    SourceMissing,
+}
+
+impl std::fmt::Display for Drift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{} [{}]: ", self.owner, self.name, self.target)?;
+        match &self.kind {
+            DriftKind::Modified { path } => write!(f, "{path} was modified after export"),
+            DriftKind::Missing { path } => write!(f, "{path} is missing"),
+            DriftKind::Outdated { exported, current } if exported == current => {
+                write!(f, "skill content changed since export (v{current})")
+            }
+            DriftKind::Outdated { exported, current } => {
+                write!(f, "skill changed since export (v{exported} -> v{current})")
+            }
+            DriftKind::SourceMissing => f.write_str("skill is no longer in any repo"),
+        }
+    }
+}
+
+/// Compare the exports recorded under `out` with the files on disk and
+/// with a fresh render from `index`.
+pub fn check(out: &Path, index: &SkillIndex) -> Result<Vec<Drift>> {
+    let manifest = ExportManifest::load(out)?;
+    let mut drift = Vec::new();
+
+    for &target in ExportTarget::ALL {
+        let recorded: Vec<&ExportedSkill> = manifest
+            .skills
+            .iter()
+            .filter(|s| s.target == target)
+            .collect();
+        if recorded.is_empty() {
+            continue;
+        }
+
+        let drifted = |skill: &ExportedSkill, kind| Drift {
+            owner: skill.owner.clone(),
+            name: skill.name.clone(),
+            target,
+            kind,
+        };
+
+        // Local edits
+        for skill in &recorded {
+            for (rel, hash) in &skill.files {
+                match read_content(out, rel, target) {
+                    None => drift.push(drifted(skill, DriftKind::Missing { path: rel.clone() })),
+                    Some(content) if content.hash() != *hash => {
+                        drift.push(drifted(skill, DriftKind::Modified { path: rel.clone() }))
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+
+        // Upstream changes
+        let mut current = Vec::new();
+        for skill in &recorded {
+            match index.lookup(&skill.owner, &skill.name) {
+                crate::state::Lookup::Found(entry) => current.push(entry),
+                _ => drift.push(drifted(skill, DriftKind::SourceMissing)),
+            }
+        }
+        let fresh = render(target, &current, index)?;
+        for skill in &recorded {
+            let Some(now) = fresh
+                .skills
+                .iter()
+                .find(|s| s.owner == skill.owner && s.name == skill.name)
+            else {
+                continue;
+            };
+            if now.version != skill.version || now.files != skill.files {
+                drift.push(drifted(
+                    skill,
+                    DriftKind::Outdated {
+                        exported: skill.version.clone(),
+                        current: now.version.clone(),
+                    },
+                ));
+            }
+        }
+    }
+    Ok(drift)
+}
+
+/// What's on disk for an exported path, in the form it was hashed.
+fn read_content(out: &Path, rel: &str, target: ExportTarget) -> Option<Content> {
+    let path = out.join(rel);
+    if target == ExportTarget::AgentsMd && rel == "AGENTS.md" {
+        let text = std::fs::read_to_string(path).ok()?;
+        return extract_block(&text).map(|b| Content::Block(b.to_string()));
+    }
+    std::fs::read(path).ok().map(Content::File)
+}
+
+fn write_file(path: &Path, bytes: &[u8]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| Error::Io {
+            context: format!("failed to create {}", parent.display()),
+            source: e,
+        })?;
+    }
+    std::fs::write(path, bytes).map_err(|e| Error::Io {
+        context: format!("failed to write {}", path.display()),
+        source: e,
+    })
+}
+
+fn sha256(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// SKILL.md as is when it has frontmatter; otherwise with `name` and
+/// `description` frontmatter added, which Claude requires.
+fn claude_skill_md(version: &SkillVersion, skill_md: &str) -> String {
+    if crate::project::parse_frontmatter(skill_md).is_some() {
+        return skill_md.to_string();
+    }
+    let info = &version.metadata.skill;
+    format!(
+        "---\nname: {}\ndescription: {}\n---\n\n{}",
+        info.name,
+        yaml_string(&info.description),
+        body(skill_md)
+    )
+}
+
+/// A Cursor rule. Skills without activation metadata are agent-requested
+/// (picked by description), which is how skills are normally used.
+fn cursor_rule(version: &SkillVersion, skill_md: &str) -> String {
+    let info = &version.metadata.skill;
+    let (mode, globs) = activation(version);
+    let description = match mode {
+        ActivationMode::Manual => String::new(),
+        _ => one_line(&info.description),
+    };
+    format!(
+        "---\ndescription: {description}\nglobs: {}\nalwaysApply: {}\n---\n\n{}",
+        globs.join(","),
+        mode == ActivationMode::Always,
+        body(skill_md)
+    )
+}
+
+/// A Copilot instructions file: `applyTo` from the skill's globs (`**`
+/// for always-on skills), omitted for skills that are only used on
+/// request.
+fn copilot_instructions(version: &SkillVersion, skill_md: &str) -> String {
+    let info = &version.metadata.skill;
+    let (mode, globs) = activation(version);
+    let mut frontmatter = format!("description: {}\n", yaml_string(&info.description));
+    match mode {
+        ActivationMode::Always => frontmatter.push_str("applyTo: \"**\"\n"),
+        ActivationMode::AutoAttached => {
+            frontmatter.push_str(&format!("applyTo: \"{}\"\n", globs.join(",")));
+        }
+        ActivationMode::AgentRequested | ActivationMode::Manual => {}
+    }
+    format!("---\n{frontmatter}---\n\n{}", body(skill_md))
+}
+
+/// Append a skill's section to the `AGENTS.md` block, with its headings
+/// nested under the section heading.
+fn agents_md_section(out: &mut String, entry: &SkillEntry, version: &SkillVersion, skill_md: &str) {
+    let info = &version.metadata.skill;
+    out.push_str(&format!("## {}/{}\n\n", entry.owner, entry.name));
+    out.push_str(&format!("{}\n\n", one_line(&info.description)));
+    if let Some(ref trigger) = info.trigger {
+        out.push_str(&format!("_When to use:_ {}\n\n", one_line(trigger)));
+    }
+    out.push_str(demote_headings(body(skill_md), 2).trim_end());
+    out.push_str("\n\n");
+}
+
+fn activation(version: &SkillVersion) -> (ActivationMode, Vec<String>) {
+    match version.metadata.skill.activation {
+        Some(ref a) => (a.mode, a.globs.clone()),
+        None => (ActivationMode::AgentRequested, Vec::new()),
+    }
+}
+
+/// SKILL.md without its frontmatter.
+fn body(skill_md: &str) -> &str {
+    crate::import::split_frontmatter(skill_md).1
+}
+
+fn one_line(s: &str) -> String {
+    s.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// A YAML string scalar, quoted when needed.
+fn yaml_string(s: &str) -> String {
+    serde_yaml::to_string(&one_line(s))
+        .map(|y| y.trim_end().to_string())
+        .unwrap_or_else(|_| format!("{s:?}"))
+}
+
+/// Add `levels` to every Markdown heading outside code fences (capped at
+/// `######`).
+fn demote_headings(md: &str, levels: usize) -> String {
+    let mut in_fence = false;
+    let mut out = String::new();
+    for line in md.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+        let hashes = line.chars().take_while(|&c| c == '#').count();
+        if !in_fence && (1..=6).contains(&hashes) && line[hashes..].starts_with(' ') {
+            let level = (hashes + levels).min(6);
+            out.push_str(&"#".repeat(level));
+            out.push_str(&line[hashes..]);
+        } else {
+            out.push_str(line);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// Text between the skillet markers, if present.
+fn extract_block(text: &str) -> Option<&str> {
+    let start = text.find(BLOCK_BEGIN)? + BLOCK_BEGIN.len();
+    let end = start + text[start..].find(BLOCK_END)?;
+    Some(text[start..end].trim_matches('\n'))
+}
+
+/// `text` with its managed block replaced by `block`, or `block` appended
+/// when there is none yet.
+fn replace_block(text: &str, block: &str) -> String {
+    let managed = format!("{BLOCK_BEGIN}\n{block}\n{BLOCK_END}");
+    if let Some(start) = text.find(BLOCK_BEGIN)
+        && let Some(end) = text[start..].find(BLOCK_END)
+    {
+        let end = start + end + BLOCK_END.len();
+        return format!("{}{managed}{}", &text[..start], &text[end..]);
+    }
+    if text.trim().is_empty() {
+        format!("{managed}\n")
+    } else {
+        format!("{}\n\n{managed}\n", text.trim_end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{Activation, SkillFile};
+
+    fn skill(name: &str, version: &str, skill_md: &str) -> SkillEntry {
+        let mut index = SkillIndex::default();
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("acme").join(name);
+        std::fs::create_dir_all(dir.join("references")).unwrap();
+        std::fs::write(dir.join("SKILL.md"), skill_md).unwrap();
+        std::fs::write(dir.join("references/guide.md"), "# Guide\n").unwrap();
+        index.merge(
+            crate::index::load_index(tmp.path(), &crate::index::FileLimits::default()).unwrap(),
+        );
+        let mut entry = index.skills.into_values().next().unwrap();
+        for v in &mut entry.versions {
+            v.version = version.to_string();
+        }
+        entry
+    }
+
+    const SKILL_MD: &str = "---\nname: tool\ndescription: Does things\n---\n\n# Tool\n\nUse it.\n\n```sh\n# not a heading\n```\n";
+
+    #[test]
+    fn test_target_names() {
+        for &target in ExportTarget::ALL {
+            assert_eq!(target.to_string().parse::<ExportTarget>(), Ok(target));
+        }
+        assert!("windsurf".parse::<ExportTarget>().is_err());
+    }
+
+    #[test]
+    fn test_render_claude_includes_files() {
+        let entry = skill("tool", "1.0.0", SKILL_MD);
+        let export = render(ExportTarget::Claude, &[&entry], &SkillIndex::default()).unwrap();
+        assert_eq!(
+            export.files.keys().collect::<Vec<_>>(),
+            vec![
+                ".claude/skills/tool/SKILL.md",
+                ".claude/skills/tool/references/guide.md"
+            ]
+        );
+        assert_eq!(
+            export.files[".claude/skills/tool/SKILL.md"],
+            Content::File(SKILL_MD.as_bytes().to_vec())
+        );
+        assert_eq!(export.skills[0].files.len(), 2);
+    }
+
+    #[test]
+    fn test_render_cursor_and_copilot_activation() {
+        let mut entry = skill("tool", "1.0.0", SKILL_MD);
+        let text = |export: &Export, path: &str| match &export.files[path] {
+            Content::File(b) => String::from_utf8(b.clone()).unwrap(),
+            Content::Block(_) => panic!("expected a file"),
+        };
+
+        let cursor = render(ExportTarget::Cursor, &[&entry], &SkillIndex::default()).unwrap();
+        let rule = text(&cursor, ".cursor/rules/tool.mdc");
+        assert!(rule.starts_with(
+            "---\ndescription: Does things\nglobs: \nalwaysApply: false\n---\n\n# Tool"
+        ));
+
+        entry.versions[0].metadata.skill.activation = Some(Activation {
+            mode: ActivationMode::AutoAttached,
+            globs: vec!["*.rs".into(), "Cargo.toml".into()],
+        });
+        let cursor = render(ExportTarget::Cursor, &[&entry], &SkillIndex::default()).unwrap();
+        assert!(text(&cursor, ".cursor/rules/tool.mdc").contains("globs: *.rs,Cargo.toml\n"));
+        let copilot = render(ExportTarget::Copilot, &[&entry], &SkillIndex::default()).unwrap();
+        assert!(
+            text(&copilot, ".github/instructions/tool.instructions.md")
+                .contains("applyTo: \"*.rs,Cargo.toml\"\n")
+        );
+    }
+
+    #[test]
+    fn test_agents_md_block_preserves_rest_of_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("AGENTS.md"), "# Project\n\nHand-written.\n").unwrap();
+
+        let entry = skill("tool", "1.0.0", SKILL_MD);
+        let export = render(ExportTarget::AgentsMd, &[&entry], &SkillIndex::default()).unwrap();
+        write(tmp.path(), &export).unwrap();
+        write(tmp.path(), &export).unwrap();
+
+        let text = std::fs::read_to_string(tmp.path().join("AGENTS.md")).unwrap();
+        assert!(text.starts_with("# Project\n\nHand-written.\n\n<!-- skillet:begin -->"));
+        assert_eq!(text.matches(BLOCK_BEGIN).count(), 1);
+        assert!(text.contains("## acme/tool\n\nDoes things\n\n### Tool"));
+        assert!(text.contains("# not a heading"));
+        assert!(
+            tmp.path()
+                .join(".agents/tool/references/guide.md")
+                .is_file()
+        );
+    }
+
+    #[test]
+    fn test_duplicate_names_rejected() {
+        let a = skill("tool", "1.0.0", SKILL_MD);
+        let mut b = a.clone();
+        b.owner = "other".into();
+        let err = render(ExportTarget::Claude, &[&a, &b], &SkillIndex::default()).unwrap_err();
+        assert!(err.to_string().contains("would both export as 'tool'"));
+    }
+
+    #[test]
+    fn test_check_reports_drift() {
+        let tmp = tempfile::tempdir().unwrap();
+        let out = tmp.path();
+        let entry = skill("tool", "1.0.0", SKILL_MD);
+        write(
+            out,
+            &render(ExportTarget::Claude, &[&entry], &SkillIndex::default()).unwrap(),
+        )
+        .unwrap();
+
+        let mut index = SkillIndex::default();
+        index
+            .skills
+            .insert(("acme".into(), "tool".into()), entry.clone());
+        assert!(check(out, &index).unwrap().is_empty());
+
+        // Local edit
+        std::fs::write(out.join(".claude/skills/tool/SKILL.md"), "edited").unwrap();
+        // Upstream change
+        let updated = skill("tool", "1.1.0", SKILL_MD);
+        index.skills.insert(("acme".into(), "tool".into()), updated);
+
+        let drift = check(out, &index).unwrap();
+        let kinds: Vec<&DriftKind> = drift.iter().map(|d| &d.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &DriftKind::Modified {
+                    path: ".claude/skills/tool/SKILL.md".into()
+                },
+                &DriftKind::Outdated {
+                    exported: "1.0.0".into(),
+                    current: "1.1.0".into()
+                },
+            ]
+        );
+
+        index.skills.clear();
+        let drift = check(out, &index).unwrap();
+        assert!(drift.iter().any(|d| d.kind == DriftKind::SourceMissing));
+    }
+
+    #[test]
+    fn test_includes_are_expanded_and_tracked() {
+        let tmp = tempfile::tempdir().unwrap();
+        let out = tmp.path();
+        let style = skill("style", "1.0.0", "# Style\n\nUse tabs.\n");
+        let entry = skill(
+            "tool",
+            "1.0.0",
+            "---\nname: tool\ndescription: Does things\n---\n\n# Tool\n\n\
+             <!-- skillet:include references/guide.md -->\n{{> acme/style}}\n",
+        );
+        let mut index = SkillIndex::default();
+        index
+            .skills
+            .insert(("acme".into(), "style".into()), style.clone());
+        index
+            .skills
+            .insert(("acme".into(), "tool".into()), entry.clone());
+
+        let export = render(ExportTarget::Claude, &[&entry], &index).unwrap();
+        let Content::File(bytes) = &export.files[".claude/skills/tool/SKILL.md"] else {
+            panic!("expected a file");
+        };
+        let text = String::from_utf8(bytes.clone()).unwrap();
+        assert!(
+            text.contains("# Guide\n# Style\n\nUse tabs."),
+            "got: {text}"
+        );
+        assert!(!text.contains("skillet:include"));
+        assert!(export.problems.is_empty());
+        write(out, &export).unwrap();
+        assert!(check(out, &index).unwrap().is_empty());
+
+        // A change that only reaches the skill through an include is drift
+        let restyled = skill("style", "1.0.0", "# Style\n\nUse spaces.\n");
+        index
+            .skills
+            .insert(("acme".into(), "style".into()), restyled);
+        let drift = check(out, &index).unwrap();
+        assert_eq!(
+            drift.iter().map(|d| &d.kind).collect::<Vec<_>>(),
+            vec![&DriftKind::Outdated {
+                exported: "1.0.0".into(),
+                current: "1.0.0".into()
+            }]
+        );
+
+        // Unresolvable includes are reported
+        index.skills.remove(&("acme".into(), "style".into()));
+        let export = render(ExportTarget::Claude, &[&entry], &index).unwrap();
+        assert_eq!(export.problems.len(), 1);
+        assert!(export.problems[0].starts_with("acme/tool: 'acme/style'"));
+    }
+
+    #[test]
+    fn test_manifest_replaces_reexported_skills() {
+        let tmp = tempfile::tempdir().unwrap();
+        let entry = skill("tool", "1.0.0", SKILL_MD);
+        write(
+            tmp.path(),
+            &render(ExportTarget::Claude, &[&entry], &SkillIndex::default()).unwrap(),
+        )
+        .unwrap();
+        write(
+            tmp.path(),
+            &render(ExportTarget::Cursor, &[&entry], &SkillIndex::default()).unwrap(),
+        )
+        .unwrap();
+        write(
+            tmp.path(),
+            &render(ExportTarget::Claude, &[&entry], &SkillIndex::default()).unwrap(),
+        )
+        .unwrap();
+
+        let manifest = ExportManifest::load(tmp.path()).unwrap();
+        let targets: Vec<ExportTarget> = manifest.skills.iter().map(|s| s.target).collect();
+        assert_eq!(targets, vec![ExportTarget::Claude, ExportTarget::Cursor]);
+    }
+
+    #[test]
+    fn test_binary_files_round_trip() {
+        let mut entry = skill("tool", "1.0.0", SKILL_MD);
+        entry.versions[0].files.insert(
+            "assets/logo.png".into(),
+            SkillFile::from_bytes(vec![0x89, 0x50, 0xff, 0x00], "image/png".into()),
+        );
+        let export = render(ExportTarget::Claude, &[&entry], &SkillIndex::default()).unwrap();
+        assert_eq!(
+            export.files[".claude/skills/tool/assets/logo.png"],
+            Content::File(vec![0x89, 0x50, 0xff, 0x00])
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Minimal glob matching for skillpack file patterns.
+//!
+//! Patterns follow `.gitignore` conventions closely enough for authors to
+//! guess right:
+//!
+//! - `*` matches within one path segment, `?` matches one character
+//! - `**` matches any number of segments (`docs/**/*.md`)
+//! - a pattern without `/` matches a name at any depth (`*.png`)
+//! - a pattern with `/` is anchored at the skill root (`examples/basic`)
+//! - a pattern matching a directory matches everything below it
+//!   (`examples` and `examples/` both select `examples/a/b.md`)
+
+/// Whether `path` (relative, `/`-separated) matches `pattern`.
+pub fn matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim().trim_start_matches("./");
+    let anchored = pattern.starts_with('/') || pattern.trim_end_matches('/').contains('/');
+    let pattern = pattern.trim_matches('/');
+    if pattern.is_empty() {
+        return false;
+    }
+
+    let mut pat: Vec<&str> = pattern.split('/').collect();
+    if !anchored {
+        pat.insert(0, "**");
+    }
+    let segments: Vec<&str> = path.split('/').collect();
+
+    // The path itself, or any directory containing it
+    (1..=segments.len()).any(|n| match_segments(&pat, &segments[..n]))
+}
+
+/// Whether `path` matches any of `patterns`.
+pub fn matches_any(patterns: &[String], path: &str) -> bool {
+    patterns.iter().any(|p| matches(p, path))
+}
+
+fn match_segments(pat: &[&str], segments: &[&str]) -> bool {
+    match pat.split_first() {
+        None => segments.is_empty(),
+        Some((&"**", rest)) => (0..=segments.len()).any(|i| match_segments(rest, &segments[i..])),
+        Some((p, rest)) => match segments.split_first() {
+            Some((s, tail)) => {
+                match_segment(p.as_bytes(), s.as_bytes()) && match_segments(rest, tail)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Match one segment against a pattern with `*` and `?` wildcards.
+fn match_segment(pat: &[u8], s: &[u8]) -> bool {
+    match pat.split_first() {
+        None => s.is_empty(),
+        Some((b'*', rest)) => (0..=s.len()).any(|i| match_segment(rest, &s[i..])),
+        Some((b'?', rest)) => !s.is_empty() && match_segment(rest, &s[1..]),
+        Some((c, rest)) => s.first() == Some(c) && match_segment(rest, &s[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unanchored_matches_any_depth() {
+        assert!(matches("*.png", "logo.png"));
+        assert!(matches("*.png", "assets/img/logo.png"));
+        assert!(!matches("*.png", "assets/logo.svg"));
+        assert!(matches("notes?.md", "docs/notes1.md"));
+    }
+
+    #[test]
+    fn test_anchored_and_directories() {
+        assert!(matches("examples", "examples/basic/main.rs"));
+        assert!(matches("examples/", "examples/basic/main.rs"));
+        assert!(matches("docs/*.md", "docs/guide.md"));
+        assert!(!matches("docs/*.md", "docs/api/guide.md"));
+        assert!(!matches("/guide.md", "docs/guide.md"));
+        assert!(matches("/guide.md", "guide.md"));
+    }
+
+    #[test]
+    fn test_double_star() {
+        assert!(matches("docs/**/*.md", "docs/guide.md"));
+        assert!(matches("docs/**/*.md", "docs/api/v2/users.md"));
+        assert!(!matches("docs/**/*.md", "references/api.md"));
+        assert!(matches("**", "anything/at/all"));
+    }
+
+    #[test]
+    fn test_empty_pattern_matches_nothing() {
+        assert!(!matches("", "a.md"));
+        assert!(!matches("/", "a.md"));
+    }
+}
@@ -0,0 +1,541 @@
+//! Importers that index other agents' instruction files as skills.
+//!
+//! Repos often already carry instructions for a specific agent: Cursor
+//! rules, Copilot instructions or an `AGENTS.md`. For repos listed in
+//! `[repos.import]`, these files become searchable skills alongside any
+//! SKILL.md skills, with their glob and activation metadata carried over
+//! as an [`Activation`].
+//!
+//! | Format | Files | Skill name |
+//! |---|---|---|
+//! | `cursor` | `.cursor/rules/**/*.mdc`, `.cursorrules` | path below `rules/`, e.g. `frontend-react` |
+//! | `copilot` | `.github/copilot-instructions.md`, `.github/instructions/**/*.instructions.md` | `copilot-instructions`, or the path below `instructions/` |
+//! | `agents-md` | `AGENTS.md` | `agents` |
+//!
+//! Imported skills are owned like flat-repo skills (git remote owner, or
+//! the directory name). A name already taken in the repo keeps the
+//! existing skill and records a warning.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::ImportFormat;
+use crate::state::{
+    Activation, ActivationMode, Classification, DiagnosticKind, LoadDiagnostic, SkillEntry,
+    SkillIndex, SkillInfo, SkillMetadata, SkillSource, SkillVersion,
+};
+use crate::{index, project};
+
+/// Deepest directory level searched below `.cursor/rules` and
+/// `.github/instructions`.
+const MAX_RULE_DEPTH: usize = 4;
+
+/// Import `formats` from the repo at `repo_path` into `index`.
+pub fn import_into(repo_path: &Path, formats: &[ImportFormat], index: &mut SkillIndex) {
+    let owner = index::repo_owner(repo_path);
+    let mut imported = 0;
+
+    for &format in formats {
+        for source in find_sources(repo_path, format) {
+            match load_source(&owner, format, &source) {
+                Ok(Some(mut entry)) => {
+                    entry.origin = Some(repo_path.to_path_buf());
+                    let key = (owner.clone(), entry.name.clone());
+                    if index.skills.contains_key(&key) {
+                        index.diagnostics.push(LoadDiagnostic::warning(
+                            DiagnosticKind::DuplicateSkill,
+                            &source.path,
+                            format!(
+                                "{}/{} is already loaded; not importing this {format} file",
+                                key.0, key.1
+                            ),
+                        ));
+                        continue;
+                    }
+                    index.skills.insert(key, entry);
+                    imported += 1;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::warn!(
+                        path = %source.path.display(),
+                        error = %e,
+                        "Skipping unreadable {format} file"
+                    );
+                    index.diagnostics.push(LoadDiagnostic::error(
+                        DiagnosticKind::InvalidSkill,
+                        &source.path,
+                        e.to_string(),
+                    ));
+                }
+            }
+        }
+    }
+
+    if imported > 0 {
+        tracing::info!(
+            repo = %repo_path.display(),
+            count = imported,
+            "Imported agent instruction files as skills"
+        );
+        index.recount();
+    }
+}
+
+/// An instruction file and the skill name derived from its path.
+struct Source {
+    path: PathBuf,
+    name: String,
+    /// Repo-wide file (`.cursorrules`, `copilot-instructions.md`,
+    /// `AGENTS.md`) that always applies
+    repo_wide: bool,
+}
+
+/// Instruction files of `format` in the repo, in path order.
+fn find_sources(repo_path: &Path, format: ImportFormat) -> Vec<Source> {
+    let single = |rel: &str, name: &str| {
+        let path = repo_path.join(rel);
+        path.is_file().then(|| Source {
+            path,
+            name: name.to_string(),
+            repo_wide: true,
+        })
+    };
+    let mut sources = Vec::new();
+    match format {
+        ImportFormat::Cursor => {
+            let rules = repo_path.join(".cursor/rules");
+            sources.extend(
+                files_under(&rules, ".mdc")
+                    .into_iter()
+                    .map(|path| named_by_path(&rules, path, ".mdc")),
+            );
+            sources.extend(single(".cursorrules", "cursorrules"));
+        }
+        ImportFormat::Copilot => {
+            sources.extend(single(
+                ".github/copilot-instructions.md",
+                "copilot-instructions",
+            ));
+            let dir = repo_path.join(".github/instructions");
+            sources.extend(
+                files_under(&dir, ".instructions.md")
+                    .into_iter()
+                    .map(|path| named_by_path(&dir, path, ".instructions.md")),
+            );
+        }
+        ImportFormat::AgentsMd => sources.extend(single("AGENTS.md", "agents")),
+    }
+    sources
+}
+
+/// Files under `dir` whose names end in `suffix`, sorted.
+fn files_under(dir: &Path, suffix: &str) -> Vec<PathBuf> {
+    fn walk(dir: &Path, suffix: &str, depth: usize, out: &mut Vec<PathBuf>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.is_dir() {
+                if depth < MAX_RULE_DEPTH {
+                    walk(&path, suffix, depth + 1, out);
+                }
+            } else if entry.file_name().to_string_lossy().ends_with(suffix) {
+                out.push(path);
+            }
+        }
+    }
+    let mut files = Vec::new();
+    walk(dir, suffix, 0, &mut files);
+    files.sort();
+    files
+}
+
+/// Name a file by its path below `dir`, minus `suffix`:
+/// `frontend/React.mdc` becomes `frontend-react`.
+fn named_by_path(dir: &Path, path: PathBuf, suffix: &str) -> Source {
+    let rel = path
+        .strip_prefix(dir)
+        .unwrap_or(&path)
+        .to_string_lossy()
+        .replace('\\', "/");
+    let rel = rel.strip_suffix(suffix).unwrap_or(&rel);
+    Source {
+        name: slugify(rel),
+        path,
+        repo_wide: false,
+    }
+}
+
+/// Lowercase, with runs of anything but letters and digits turned into a
+/// single hyphen.
+fn slugify(s: &str) -> String {
+    let mut slug = String::new();
+    for c in s.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Build a skill from one instruction file. Files with no body are
+/// skipped.
+fn load_source(
+    owner: &str,
+    format: ImportFormat,
+    source: &Source,
+) -> crate::error::Result<Option<SkillEntry>> {
+    let text =
+        std::fs::read_to_string(&source.path).map_err(|e| crate::error::Error::FileRead {
+            path: source.path.clone(),
+            source: e,
+        })?;
+    let (fields, body) = split_frontmatter(&text);
+    let body = body.trim();
+    if body.is_empty() {
+        return Ok(None);
+    }
+
+    let description = fields
+        .get("description")
+        .filter(|d| !d.is_empty())
+        .cloned()
+        .or_else(|| project::first_paragraph(body))
+        .unwrap_or_else(|| format!("Imported {format} instructions"));
+    let activation = if source.repo_wide {
+        Activation {
+            mode: ActivationMode::Always,
+            globs: Vec::new(),
+        }
+    } else {
+        activation(format, &fields)
+    };
+    let trigger = match activation.mode {
+        ActivationMode::AutoAttached => Some(format!(
+            "When working on files matching {}",
+            activation.globs.join(", ")
+        )),
+        _ => None,
+    };
+
+    let version = "0.1.0".to_string();
+    let metadata = SkillMetadata {
+        skill: SkillInfo {
+            name: source.name.clone(),
+            owner: owner.to_string(),
+            version: version.clone(),
+            description,
+            trigger,
+            license: None,
+            author: None,
+            classification: Some(Classification {
+                categories: Vec::new(),
+                tags: vec![format.to_string()],
+            }),
+            compatibility: None,
+            language: None,
+            files: None,
+            activation: Some(activation),
+            allowed_tools: None,
+            model: None,
+        },
+        frontmatter: Default::default(),
+    };
+
+    Ok(Some(SkillEntry {
+        owner: owner.to_string(),
+        name: source.name.clone(),
+        repo_path: None,
+        versions: vec![SkillVersion {
+            version,
+            metadata,
+            skill_md: format!("{body}\n"),
+            skill_toml_raw: String::new(),
+            yanked: false,
+            files: HashMap::new(),
+            published: None,
+            has_content: true,
+        }],
+        source: SkillSource::Imported {
+            format,
+            path: source.path.clone(),
+        },
+        trust_tier: Default::default(),
+        discovered_via: Vec::new(),
+        origin: None,
+    }))
+}
+
+/// How the file's agent would apply a scoped (not repo-wide) file.
+///
+/// Cursor: `alwaysApply: true` is always, `globs` auto-attaches, a bare
+/// `description` is agent-requested, and nothing at all is manual.
+/// Copilot: `applyTo: "**"` or no `applyTo` is always, other globs
+/// auto-attach.
+fn activation(format: ImportFormat, fields: &HashMap<String, String>) -> Activation {
+    let globs = |key: &str| -> Vec<String> {
+        fields
+            .get(key)
+            .map(|v| {
+                v.split(',')
+                    .map(|g| unquote(g.trim()).to_string())
+                    .filter(|g| !g.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let always = Activation {
+        mode: ActivationMode::Always,
+        globs: Vec::new(),
+    };
+
+    match format {
+        ImportFormat::Cursor => {
+            if fields.get("alwaysApply").is_some_and(|v| v == "true") {
+                return always;
+            }
+            let globs = globs("globs");
+            let mode = if !globs.is_empty() {
+                ActivationMode::AutoAttached
+            } else if fields.get("description").is_some_and(|d| !d.is_empty()) {
+                ActivationMode::AgentRequested
+            } else {
+                ActivationMode::Manual
+            };
+            Activation { mode, globs }
+        }
+        ImportFormat::Copilot => {
+            let globs = globs("applyTo");
+            if globs.is_empty() || globs.iter().any(|g| g == "**" || g == "**/*") {
+                always
+            } else {
+                Activation {
+                    mode: ActivationMode::AutoAttached,
+                    globs,
+                }
+            }
+        }
+        ImportFormat::AgentsMd => always,
+    }
+}
+
+/// Split `key: value` frontmatter from the body.
+///
+/// Parsed line by line rather than as YAML: Cursor writes unquoted globs
+/// like `globs: *.ts`, which YAML reads as an alias. List items under a
+/// key are joined with commas.
+pub(crate) fn split_frontmatter(text: &str) -> (HashMap<String, String>, &str) {
+    let mut fields = HashMap::new();
+    let Some(rest) = text
+        .strip_prefix("---\n")
+        .or_else(|| text.strip_prefix("---\r\n"))
+    else {
+        return (fields, text);
+    };
+    let Some(end) = rest.find("\n---") else {
+        return (fields, text);
+    };
+    let body = rest[end + 4..].trim_start_matches(['\r', '\n']);
+
+    let mut last_key: Option<String> = None;
+    for line in rest[..end].lines() {
+        let trimmed = line.trim();
+        if let Some(item) = trimmed.strip_prefix("- ")
+            && let Some(ref key) = last_key
+        {
+            let value: &mut String = fields.entry(key.clone()).or_default();
+            if !value.is_empty() {
+                value.push_str(", ");
+            }
+            value.push_str(unquote(item.trim()));
+        } else if let Some((key, value)) = trimmed.split_once(':') {
+            let value = value.trim();
+            let value = value
+                .strip_prefix('[')
+                .and_then(|v| v.strip_suffix(']'))
+                .unwrap_or(value);
+            fields.insert(key.trim().to_string(), unquote(value).to_string());
+            last_key = Some(key.trim().to_string());
+        }
+    }
+    (fields, body)
+}
+
+fn unquote(s: &str) -> &str {
+    s.strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .or_else(|| s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')))
+        .unwrap_or(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn activation_of(index: &SkillIndex, name: &str) -> Activation {
+        let entry = index
+            .skills
+            .values()
+            .find(|e| e.name == name)
+            .unwrap_or_else(|| panic!("{name} not imported"));
+        entry
+            .latest()
+            .unwrap()
+            .metadata
+            .skill
+            .activation
+            .clone()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_split_frontmatter_lenient() {
+        let (fields, body) = split_frontmatter(
+            "---\ndescription: \"React rules\"\nglobs: *.tsx, src/**/*.ts\nalwaysApply: false\n---\n\n# Body\n",
+        );
+        assert_eq!(fields["description"], "React rules");
+        assert_eq!(fields["globs"], "*.tsx, src/**/*.ts");
+        assert_eq!(fields["alwaysApply"], "false");
+        assert_eq!(body, "# Body\n");
+
+        let (fields, _) = split_frontmatter("---\nglobs:\n  - \"*.rs\"\n  - build.rs\n---\nx");
+        assert_eq!(fields["globs"], "*.rs, build.rs");
+
+        let (fields, body) = split_frontmatter("No frontmatter");
+        assert!(fields.is_empty());
+        assert_eq!(body, "No frontmatter");
+    }
+
+    #[test]
+    fn test_import_cursor_rules() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        write(
+            root,
+            ".cursor/rules/frontend/React.mdc",
+            "---\ndescription: React conventions\nglobs: src/**/*.tsx\n---\nUse hooks.\n",
+        );
+        write(
+            root,
+            ".cursor/rules/style.mdc",
+            "---\nalwaysApply: true\n---\nBe concise.\n",
+        );
+        write(
+            root,
+            ".cursor/rules/db.mdc",
+            "---\ndescription: Database migrations\n---\nUse sqlx.\n",
+        );
+        write(
+            root,
+            ".cursor/rules/empty.mdc",
+            "---\nalwaysApply: true\n---\n",
+        );
+
+        let mut index = SkillIndex::default();
+        import_into(root, &[ImportFormat::Cursor], &mut index);
+
+        assert_eq!(index.skills.len(), 3);
+        let react = activation_of(&index, "frontend-react");
+        assert_eq!(react.mode, ActivationMode::AutoAttached);
+        assert_eq!(react.globs, vec!["src/**/*.tsx"]);
+        assert_eq!(activation_of(&index, "style").mode, ActivationMode::Always);
+        assert_eq!(
+            activation_of(&index, "db").mode,
+            ActivationMode::AgentRequested
+        );
+
+        let entry = index.skills.values().find(|e| e.name == "db").unwrap();
+        let latest = entry.latest().unwrap();
+        assert_eq!(latest.metadata.skill.description, "Database migrations");
+        assert_eq!(latest.skill_md, "Use sqlx.\n");
+        assert_eq!(index.tags.get("cursor"), Some(&3));
+        assert!(matches!(
+            entry.source,
+            SkillSource::Imported {
+                format: ImportFormat::Cursor,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_import_copilot_and_agents_md() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        write(
+            root,
+            ".github/copilot-instructions.md",
+            "# Repo\n\nRun cargo test before committing.\n",
+        );
+        write(
+            root,
+            ".github/instructions/rust.instructions.md",
+            "---\napplyTo: \"**/*.rs,Cargo.toml\"\n---\nPrefer thiserror.\n",
+        );
+        write(root, "AGENTS.md", "# Agents\n\nKeep PRs small.\n");
+
+        let mut index = SkillIndex::default();
+        import_into(
+            root,
+            &[ImportFormat::Copilot, ImportFormat::AgentsMd],
+            &mut index,
+        );
+
+        assert_eq!(index.skills.len(), 3);
+        assert_eq!(
+            activation_of(&index, "copilot-instructions").mode,
+            ActivationMode::Always
+        );
+        let rust = activation_of(&index, "rust");
+        assert_eq!(rust.mode, ActivationMode::AutoAttached);
+        assert_eq!(rust.globs, vec!["**/*.rs", "Cargo.toml"]);
+
+        let agents = index.skills.values().find(|e| e.name == "agents").unwrap();
+        assert_eq!(
+            agents.latest().unwrap().metadata.skill.description,
+            "Keep PRs small."
+        );
+    }
+
+    #[test]
+    fn test_import_skips_taken_names() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        write(root, "AGENTS.md", "Instructions.\n");
+
+        let mut index = SkillIndex::default();
+        import_into(root, &[ImportFormat::AgentsMd], &mut index);
+        import_into(root, &[ImportFormat::AgentsMd], &mut index);
+
+        assert_eq!(index.skills.len(), 1);
+        assert_eq!(index.diagnostics.len(), 1);
+        assert_eq!(index.diagnostics[0].kind, DiagnosticKind::DuplicateSkill);
+    }
+
+    #[test]
+    fn test_only_configured_formats() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        write(root, "AGENTS.md", "Instructions.\n");
+        write(root, ".cursorrules", "Legacy rules.\n");
+
+        let mut index = SkillIndex::default();
+        import_into(root, &[ImportFormat::Cursor], &mut index);
+
+        assert_eq!(index.skills.len(), 1);
+        assert_eq!(
+            activation_of(&index, "cursorrules").mode,
+            ActivationMode::Always
+        );
+    }
+}
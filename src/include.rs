@@ -0,0 +1,390 @@
+//! Include directives in SKILL.md, expanded when skills are served as prompts.
+//!
+//! Shared boilerplate (style rules, safety notes) can live in one place and
+//! be pulled into many skills with a directive on its own line:
+//!
+//! ```text
+//! <!-- skillet:include references/common.md -->
+//! {{> acme/house-style#Safety}}
+//! ```
+//!
+//! A target is first looked up among the including skill's own files (paths
+//! relative to the skill root), then as `owner/name` in the index, which
+//! includes that skill's SKILL.md without its frontmatter. A `#section`
+//! suffix narrows either to one heading. Included text is expanded in turn,
+//! up to [`MAX_DEPTH`] levels; cycles and unresolvable targets become an
+//! HTML comment saying what went wrong rather than failing the prompt.
+//!
+//! `SkillVersion::skill_md` always keeps the raw text; [`expand`] produces
+//! the expanded variant.
+
+use crate::state::{SkillEntry, SkillIndex};
+
+/// How deep includes may nest.
+pub const MAX_DEPTH: usize = 8;
+
+/// A SKILL.md with its include directives expanded.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expansion {
+    pub text: String,
+    /// Includes that couldn't be expanded (cycles, missing targets, depth)
+    pub problems: Vec<String>,
+}
+
+/// A parsed include directive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Directive<'a> {
+    /// File path or `owner/name`
+    pub target: &'a str,
+    /// Heading to narrow to, from a `#section` suffix
+    pub section: Option<&'a str>,
+}
+
+/// Parse a line as an include directive.
+///
+/// Only a directive standing alone on its line counts, so inline mentions
+/// of the syntax in prose are left untouched.
+pub fn parse_directive(line: &str) -> Option<Directive<'_>> {
+    let trimmed = line.trim();
+    let inner = trimmed
+        .strip_prefix("<!--")
+        .and_then(|s| s.strip_suffix("-->"))
+        .and_then(|s| s.trim().strip_prefix("skillet:include"))
+        .or_else(|| {
+            trimmed
+                .strip_prefix("{{>")
+                .and_then(|s| s.strip_suffix("}}"))
+        })?
+        .trim();
+    if inner.is_empty() || inner.contains(char::is_whitespace) {
+        return None;
+    }
+    let (target, section) = match inner.split_once('#') {
+        Some((target, section)) => (target, Some(section).filter(|s| !s.is_empty())),
+        None => (inner, None),
+    };
+    if target.is_empty() {
+        return None;
+    }
+    Some(Directive { target, section })
+}
+
+/// Whether any line outside code fences is an include directive.
+pub fn has_includes(skill_md: &str) -> bool {
+    directive_lines(skill_md).next().is_some()
+}
+
+/// Expand include directives in the latest version of `entry`.
+pub fn expand(entry: &SkillEntry, index: &SkillIndex) -> Expansion {
+    let raw = entry.latest().map(|v| v.skill_md.as_str()).unwrap_or("");
+    let mut expander = Expander {
+        index,
+        stack: vec![skill_key(entry)],
+        problems: Vec::new(),
+    };
+    let text = expander.expand_text(raw, entry, 0);
+    Expansion {
+        text,
+        problems: expander.problems,
+    }
+}
+
+struct Expander<'a> {
+    index: &'a SkillIndex,
+    /// Includes currently being expanded, outermost first
+    stack: Vec<String>,
+    problems: Vec<String>,
+}
+
+impl<'a> Expander<'a> {
+    fn expand_text(&mut self, text: &str, entry: &'a SkillEntry, depth: usize) -> String {
+        if !has_includes(text) {
+            return text.to_string();
+        }
+        let directives: Vec<usize> = directive_lines(text).collect();
+        let mut out = String::with_capacity(text.len());
+        for (i, line) in text.split_inclusive('\n').enumerate() {
+            let directive = directives
+                .binary_search(&i)
+                .ok()
+                .and_then(|_| parse_directive(line));
+            let Some(directive) = directive else {
+                out.push_str(line);
+                continue;
+            };
+            match self.include(&directive, entry, depth) {
+                Ok(included) => out.push_str(included.trim_end_matches('\n')),
+                Err(problem) => {
+                    out.push_str(&format!(
+                        "<!-- skillet: couldn't include '{}': {problem} -->",
+                        directive.target
+                    ));
+                    self.problems
+                        .push(format!("'{}': {problem}", directive.target));
+                }
+            }
+            if line.ends_with('\n') {
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    /// Resolve, narrow and recursively expand one directive.
+    fn include(
+        &mut self,
+        directive: &Directive<'_>,
+        entry: &'a SkillEntry,
+        depth: usize,
+    ) -> Result<String, String> {
+        if depth >= MAX_DEPTH {
+            return Err(format!("includes nest deeper than {MAX_DEPTH} levels"));
+        }
+
+        let (key, text, owner_entry) = self.resolve(directive.target, entry)?;
+        if self.stack.contains(&key) {
+            return Err(format!(
+                "include cycle ({} -> {key})",
+                self.stack.join(" -> ")
+            ));
+        }
+        let text = match directive.section {
+            Some(section) => crate::prompts::extract_section(text, section)
+                .ok_or_else(|| format!("no section '{section}'"))?,
+            None => text.to_string(),
+        };
+
+        self.stack.push(key);
+        let expanded = self.expand_text(&text, owner_entry, depth + 1);
+        self.stack.pop();
+        Ok(expanded)
+    }
+
+    /// Find the text a target names: a file in `entry`'s skillpack, or
+    /// another skill's SKILL.md body. Also returns the cycle-detection key
+    /// and the skill that nested includes resolve against.
+    fn resolve(
+        &self,
+        target: &str,
+        entry: &'a SkillEntry,
+    ) -> Result<(String, &'a str, &'a SkillEntry), String> {
+        let target = target.trim_start_matches("./");
+        if let Some(file) = entry.latest().and_then(|v| v.files.get(target)) {
+            if file.binary {
+                return Err("binary file".to_string());
+            }
+            let key = format!("{}:{target}", skill_key(entry));
+            return Ok((key, file.content.as_str(), entry));
+        }
+
+        // Skill names never contain dots, so `references/x.md` is a file
+        let Some((owner, name)) = target
+            .split_once('/')
+            .filter(|(_, n)| !n.contains(['/', '.']))
+        else {
+            return Err("no such file in this skill".to_string());
+        };
+        let included = self.index.lookup(owner, name).found(owner, name)?;
+        let Some(latest) = included.latest() else {
+            return Err("skill has no content".to_string());
+        };
+        let body = crate::import::split_frontmatter(&latest.skill_md).1;
+        Ok((skill_key(included), body, included))
+    }
+}
+
+fn skill_key(entry: &SkillEntry) -> String {
+    format!("{}/{}", entry.owner, entry.name)
+}
+
+/// Indices of lines holding include directives, skipping code fences.
+fn directive_lines(text: &str) -> impl Iterator<Item = usize> + '_ {
+    let mut in_fence = false;
+    text.lines().enumerate().filter_map(move |(i, line)| {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+            return None;
+        }
+        (!in_fence && parse_directive(line).is_some()).then_some(i)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{SkillFile, SkillInfo, SkillMetadata, SkillSource, SkillVersion};
+
+    fn entry(owner: &str, name: &str, skill_md: &str, files: &[(&str, &str)]) -> SkillEntry {
+        SkillEntry {
+            owner: owner.to_string(),
+            name: name.to_string(),
+            repo_path: None,
+            source: SkillSource::Repo,
+            trust_tier: Default::default(),
+            discovered_via: Vec::new(),
+            origin: None,
+            versions: vec![SkillVersion {
+                version: "1.0.0".to_string(),
+                metadata: SkillMetadata {
+                    skill: SkillInfo {
+                        name: name.to_string(),
+                        owner: owner.to_string(),
+                        version: "1.0.0".to_string(),
+                        description: String::new(),
+                        trigger: None,
+                        license: None,
+                        author: None,
+                        classification: None,
+                        compatibility: None,
+                        language: None,
+                        files: None,
+                        activation: None,
+                        allowed_tools: None,
+                        model: None,
+                    },
+                    frontmatter: Default::default(),
+                },
+                skill_md: skill_md.to_string(),
+                skill_toml_raw: String::new(),
+                yanked: false,
+                files: files
+                    .iter()
+                    .map(|(path, content)| {
+                        let file = SkillFile::from_bytes(
+                            content.as_bytes().to_vec(),
+                            "text/markdown".to_string(),
+                        );
+                        (path.to_string(), file)
+                    })
+                    .collect(),
+                published: None,
+                has_content: true,
+            }],
+        }
+    }
+
+    fn index(entries: Vec<SkillEntry>) -> SkillIndex {
+        let mut index = SkillIndex::default();
+        for e in entries {
+            index.skills.insert((e.owner.clone(), e.name.clone()), e);
+        }
+        index
+    }
+
+    #[test]
+    fn test_parse_directive() {
+        assert_eq!(
+            parse_directive("<!-- skillet:include references/common.md -->"),
+            Some(Directive {
+                target: "references/common.md",
+                section: None
+            })
+        );
+        assert_eq!(
+            parse_directive("  {{> acme/style#Safety}}  "),
+            Some(Directive {
+                target: "acme/style",
+                section: Some("Safety")
+            })
+        );
+        assert_eq!(parse_directive("see {{> acme/style}} for more"), None);
+        assert_eq!(parse_directive("<!-- a comment -->"), None);
+        assert_eq!(parse_directive("{{> }}"), None);
+    }
+
+    #[test]
+    fn test_expand_file_and_skill_includes() {
+        let style = entry(
+            "acme",
+            "style",
+            "---\nname: style\n---\n# Style\n\n## Safety\n\nNever force-push.\n\n## Naming\n\nsnake_case.\n",
+            &[],
+        );
+        let skill = entry(
+            "acme",
+            "deploy",
+            "# Deploy\n\n<!-- skillet:include references/common.md -->\n\n{{> acme/style#Safety}}\n\n```\n{{> acme/style}}\n```\n",
+            &[("references/common.md", "Be careful.\n")],
+        );
+        let idx = index(vec![style, skill.clone()]);
+
+        let expanded = expand(&skill, &idx);
+        assert!(expanded.problems.is_empty(), "{:?}", expanded.problems);
+        assert_eq!(
+            expanded.text,
+            "# Deploy\n\nBe careful.\n\n## Safety\n\nNever force-push.\n\n```\n{{> acme/style}}\n```\n"
+        );
+        // Raw content is untouched
+        assert!(skill.latest().unwrap().skill_md.contains("skillet:include"));
+    }
+
+    #[test]
+    fn test_expand_nested_resolves_against_included_skill() {
+        let base = entry(
+            "acme",
+            "base",
+            "Base rules.\n{{> notes.md}}\n",
+            &[("notes.md", "Base notes.\n")],
+        );
+        let skill = entry("acme", "top", "{{> acme/base}}\n", &[]);
+        let idx = index(vec![base, skill.clone()]);
+
+        let expanded = expand(&skill, &idx);
+        assert_eq!(expanded.text, "Base rules.\nBase notes.\n");
+    }
+
+    #[test]
+    fn test_expand_detects_cycles() {
+        let a = entry("acme", "a", "A\n{{> acme/b}}\n", &[]);
+        let b = entry("acme", "b", "B\n{{> acme/a}}\n", &[]);
+        let idx = index(vec![a.clone(), b]);
+
+        let expanded = expand(&a, &idx);
+        assert_eq!(expanded.problems.len(), 1);
+        assert!(expanded.problems[0].contains("acme/a -> acme/b -> acme/a"));
+        assert!(
+            expanded
+                .text
+                .starts_with("A\nB\n<!-- skillet: couldn't include 'acme/a'")
+        );
+    }
+
+    #[test]
+    fn test_expand_depth_limit() {
+        let files: Vec<(String, String)> = (0..=MAX_DEPTH + 1)
+            .map(|i| {
+                (
+                    format!("f{i}.md"),
+                    format!("{i}\n{{{{> f{}.md}}}}\n", i + 1),
+                )
+            })
+            .collect();
+        let files: Vec<(&str, &str)> = files
+            .iter()
+            .map(|(p, c)| (p.as_str(), c.as_str()))
+            .collect();
+        let skill = entry("acme", "deep", "{{> f0.md}}\n", &files);
+        let idx = index(vec![skill.clone()]);
+
+        let expanded = expand(&skill, &idx);
+        assert_eq!(expanded.problems.len(), 1);
+        assert!(expanded.problems[0].contains("deeper than"));
+    }
+
+    #[test]
+    fn test_expand_missing_targets() {
+        let skill = entry(
+            "acme",
+            "broken",
+            "{{> references/nope.md}}\n{{> acme/ghost}}\n{{> acme/broken#Nowhere}}\n",
+            &[],
+        );
+        let idx = index(vec![skill.clone()]);
+
+        let expanded = expand(&skill, &idx);
+        assert_eq!(expanded.problems.len(), 3, "{:?}", expanded.problems);
+        assert!(expanded.problems[0].contains("no such file"));
+        assert!(expanded.problems[1].contains("not found"));
+        assert!(expanded.problems[2].contains("cycle"));
+    }
+}
@@ -10,11 +10,12 @@
 
 use std::path::{Path, PathBuf};
 
+use crate::config::FilesConfig;
 use crate::error::Error;
 use crate::project;
 use crate::state::{
-    ServerConfig, SkillEntry, SkillFile, SkillIndex, SkillMetadata, SkillSource, SkillVersion,
-    VersionsManifest,
+    DiagnosticKind, FilePatterns, LoadDiagnostic, ServerConfig, SkillEntry, SkillFile, SkillIndex,
+    SkillMetadata, SkillSource, SkillVersion, VersionsManifest,
 };
 
 /// Maximum directory depth below an owner to search for skill directories.
@@ -65,7 +66,16 @@ pub fn load_config(repo_path: &Path) -> crate::error::Result<ServerConfig> {
 /// Intermediate directories (without `skill.toml`) are recursed into up to
 /// `MAX_NESTING_DEPTH` levels. If two nested paths under the same owner
 /// produce the same skill name, the first one wins and a warning is logged.
-pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
+pub fn load_index(repo_path: &Path, limits: &FileLimits) -> crate::error::Result<SkillIndex> {
+    let mut index = load_repo_skills(repo_path, limits)?;
+    for entry in index.skills.values_mut() {
+        entry.origin = Some(repo_path.to_path_buf());
+    }
+    Ok(index)
+}
+
+/// Discover and load every skill under a repo; see [`load_index`].
+fn load_repo_skills(repo_path: &Path, limits: &FileLimits) -> crate::error::Result<SkillIndex> {
     let mut index = SkillIndex::default();
 
     if !repo_path.is_dir() {
@@ -85,7 +95,7 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
             path = %repo_path.display(),
             "Loading npm-style skill repo via skillet.toml manifest"
         );
-        let embedded = project::load_embedded_skills(repo_path, &manifest);
+        let embedded = project::load_embedded_skills(repo_path, &manifest, limits);
         // Update category counts from embedded index
         for entry in embedded.skills.values() {
             if let Some(v) = entry.latest()
@@ -97,6 +107,7 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
             }
         }
         index.skills = embedded.skills;
+        index.diagnostics = embedded.diagnostics;
         return Ok(index);
     }
 
@@ -107,14 +118,7 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
     if skills_dir.is_dir() {
         let skill_dirs = find_skill_dirs(&skills_dir, 0);
         if !skill_dirs.is_empty() {
-            let git_root = find_git_root(repo_path).unwrap_or(repo_path.to_path_buf());
-            let owner = project::owner_from_git_remote(&git_root).unwrap_or_else(|| {
-                repo_path
-                    .file_name()
-                    .unwrap_or_default()
-                    .to_string_lossy()
-                    .to_string()
-            });
+            let owner = repo_owner(repo_path);
 
             tracing::info!(
                 owner = %owner,
@@ -129,7 +133,7 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
                     .to_string_lossy()
                     .to_string();
 
-                match load_skill(&owner, &skill_name, &skill_dir) {
+                match load_skill(&owner, &skill_name, &skill_dir, limits) {
                     Ok(entry) => {
                         let key = (owner.clone(), skill_name.clone());
                         if let Some(v) = entry.latest()
@@ -148,6 +152,11 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
                             error = %e,
                             "skills/ auto-detect: skipping skill"
                         );
+                        index.diagnostics.push(LoadDiagnostic::error(
+                            DiagnosticKind::InvalidSkill,
+                            &skill_dir,
+                            e.to_string(),
+                        ));
                     }
                 }
             }
@@ -155,6 +164,8 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
             if !index.skills.is_empty() {
                 return Ok(index);
             }
+            // The owner walk below revisits these directories
+            index.diagnostics.clear();
         }
     }
 
@@ -208,7 +219,7 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
                 None
             };
 
-            match load_skill(&owner_name, &skill_name, &skill_dir) {
+            match load_skill(&owner_name, &skill_name, &skill_dir, limits) {
                 Ok(mut entry) => {
                     // Check for collision: same (owner, name) from a different path
                     let key = (owner_name.clone(), skill_name.clone());
@@ -220,6 +231,14 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
                             new_path = ?repo_path_value,
                             "Duplicate skill name under same owner, keeping first"
                         );
+                        index.diagnostics.push(LoadDiagnostic::warning(
+                            DiagnosticKind::DuplicateSkill,
+                            &skill_dir,
+                            format!(
+                                "{owner_name}/{skill_name} is already loaded from {}; keeping the first",
+                                existing.repo_path.as_deref().unwrap_or(&owner_name)
+                            ),
+                        ));
                         continue;
                     }
 
@@ -242,6 +261,11 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
                         error = %e,
                         "Skipping skill with invalid metadata"
                     );
+                    index.diagnostics.push(LoadDiagnostic::error(
+                        DiagnosticKind::InvalidSkill,
+                        &skill_dir,
+                        e.to_string(),
+                    ));
                 }
             }
         }
@@ -254,15 +278,7 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
     if index.skills.is_empty() {
         let flat_skills = find_skill_dirs(repo_path, 0);
         if !flat_skills.is_empty() {
-            // Walk up from repo_path to find the git root (handles subdir case)
-            let git_root = find_git_root(repo_path).unwrap_or(repo_path.to_path_buf());
-            let owner = project::owner_from_git_remote(&git_root).unwrap_or_else(|| {
-                repo_path
-                    .file_name()
-                    .unwrap_or_default()
-                    .to_string_lossy()
-                    .to_string()
-            });
+            let owner = repo_owner(repo_path);
 
             tracing::info!(
                 owner = %owner,
@@ -277,10 +293,17 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
                     .to_string_lossy()
                     .to_string();
 
-                match load_skill(&owner, &skill_name, &skill_dir) {
+                match load_skill(&owner, &skill_name, &skill_dir, limits) {
                     Ok(mut entry) => {
                         let key = (owner.clone(), skill_name.clone());
                         if index.skills.contains_key(&key) {
+                            index.diagnostics.push(LoadDiagnostic::warning(
+                                DiagnosticKind::DuplicateSkill,
+                                &skill_dir,
+                                format!(
+                                    "{owner}/{skill_name} is already loaded; keeping the first"
+                                ),
+                            ));
                             continue;
                         }
                         if let Some(v) = entry.latest()
@@ -300,6 +323,11 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
                             error = %e,
                             "Flat fallback: skipping skill with invalid metadata"
                         );
+                        index.diagnostics.push(LoadDiagnostic::error(
+                            DiagnosticKind::InvalidSkill,
+                            &skill_dir,
+                            e.to_string(),
+                        ));
                     }
                 }
             }
@@ -315,6 +343,20 @@ pub fn load_index(repo_path: &Path) -> crate::error::Result<SkillIndex> {
     Ok(index)
 }
 
+/// Owner for skills in a repo without owner directories: the GitHub owner
+/// from the git remote, or the repo directory name.
+pub(crate) fn repo_owner(repo_path: &Path) -> String {
+    // Walk up from repo_path to find the git root (handles subdir case)
+    let git_root = find_git_root(repo_path).unwrap_or(repo_path.to_path_buf());
+    project::owner_from_git_remote(&git_root).unwrap_or_else(|| {
+        repo_path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string()
+    })
+}
+
 /// Walk up from a path to find the nearest directory containing `.git`.
 fn find_git_root(start: &Path) -> Option<PathBuf> {
     let mut current = start.to_path_buf();
@@ -383,7 +425,12 @@ fn find_skill_dirs(dir: &Path, remaining_depth: usize) -> Vec<PathBuf> {
 /// `SkillVersion` per record. Only the latest version (last entry) has full
 /// content loaded from disk; historical versions are placeholders with
 /// `has_content = false`.
-fn load_skill(owner: &str, name: &str, dir: &Path) -> crate::error::Result<SkillEntry> {
+fn load_skill(
+    owner: &str,
+    name: &str,
+    dir: &Path,
+    limits: &FileLimits,
+) -> crate::error::Result<SkillEntry> {
     let skill_toml_path = dir.join("skill.toml");
     let skill_md_path = dir.join("SKILL.md");
     let has_skill_toml = skill_toml_path.is_file();
@@ -434,6 +481,18 @@ fn load_skill(owner: &str, name: &str, dir: &Path) -> crate::error::Result<Skill
             if meta.skill.compatibility.is_none() {
                 meta.skill.compatibility = toml_meta.skill.compatibility;
             }
+            if meta.skill.language.is_none() {
+                meta.skill.language = toml_meta.skill.language;
+            }
+            if meta.skill.files.is_none() {
+                meta.skill.files = toml_meta.skill.files;
+            }
+            if meta.skill.allowed_tools.is_none() {
+                meta.skill.allowed_tools = toml_meta.skill.allowed_tools;
+            }
+            if meta.skill.model.is_none() {
+                meta.skill.model = toml_meta.skill.model;
+            }
             if meta.skill.classification.is_none() {
                 meta.skill.classification = toml_meta.skill.classification.clone();
             } else if let Some(ref mut cls) = meta.skill.classification {
@@ -491,11 +550,11 @@ fn load_skill(owner: &str, name: &str, dir: &Path) -> crate::error::Result<Skill
         (meta, String::new())
     };
 
-    let files = load_extra_files(dir)?;
+    let files = load_extra_files(dir, metadata.skill.files.as_ref(), limits)?;
 
     let versions_path = dir.join("versions.toml");
     let versions = if versions_path.is_file() {
-        load_versions_manifest(&versions_path, &metadata)?
+        load_versions_manifest(&versions_path, &metadata, limits)?
     } else {
         vec![SkillVersion {
             version: metadata.skill.version.clone(),
@@ -517,6 +576,7 @@ fn load_skill(owner: &str, name: &str, dir: &Path) -> crate::error::Result<Skill
         source: SkillSource::default(),
         trust_tier: Default::default(),
         discovered_via: Vec::new(),
+        origin: None,
     })
 }
 
@@ -528,6 +588,7 @@ fn load_skill(owner: &str, name: &str, dir: &Path) -> crate::error::Result<Skill
 fn load_versions_manifest(
     path: &Path,
     current_metadata: &SkillMetadata,
+    limits: &FileLimits,
 ) -> crate::error::Result<Vec<SkillVersion>> {
     let raw = std::fs::read_to_string(path).map_err(|e| Error::FileRead {
         path: path.to_path_buf(),
@@ -570,7 +631,7 @@ fn load_versions_manifest(
         path: md_path.clone(),
         source: e,
     })?;
-    let files = load_extra_files(skill_dir)?;
+    let files = load_extra_files(skill_dir, current_metadata.skill.files.as_ref(), limits)?;
 
     let total = manifest.versions.len();
     let mut versions = Vec::with_capacity(total);
@@ -606,7 +667,13 @@ fn load_versions_manifest(
                     author: None,
                     classification: None,
                     compatibility: None,
+                    language: None,
+                    files: None,
+                    activation: None,
+                    allowed_tools: None,
+                    model: None,
                 },
+                frontmatter: Default::default(),
             };
             versions.push(SkillVersion {
                 version: record.version,
@@ -624,70 +691,236 @@ fn load_versions_manifest(
     Ok(versions)
 }
 
-/// Allowed subdirectories in a skillpack (per Agent Skills spec).
+/// Default subdirectories in a skillpack (per Agent Skills spec), used
+/// when a skill declares no `[skill.files]` include globs.
 ///
 /// Includes `rules/` and `templates/` for compatibility with npm-style
 /// skill repos (redis/agent-skills, anthropics/skills, etc.).
 pub const EXTRA_DIRS: &[&str] = &["scripts", "references", "assets", "rules", "templates"];
 
-/// Load extra files from scripts/, references/, and assets/ subdirectories.
+/// Maximum directory depth inside an extra-files folder (e.g.
+/// `references/api/v2/` is depth 3).
+const MAX_FILE_DEPTH: usize = 8;
+
+/// Size caps applied when loading skillpack files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileLimits {
+    /// Files larger than this many bytes are skipped.
+    pub max_file_size: u64,
+    /// Once a skill's files add up to this many bytes, further files are skipped.
+    pub max_skill_size: u64,
+}
+
+impl FileLimits {
+    const DEFAULT: Self = Self {
+        max_file_size: 1024 * 1024,
+        max_skill_size: 10 * 1024 * 1024,
+    };
+
+    /// Limits from the `[files]` config section. Invalid sizes fall back
+    /// to the defaults with a warning.
+    pub fn from_config(config: &FilesConfig) -> Self {
+        let parse = |value: &str, default: u64| {
+            crate::repo::parse_size(value).unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Invalid [files] size, using default");
+                default
+            })
+        };
+        Self {
+            max_file_size: parse(&config.max_file_size, Self::DEFAULT.max_file_size),
+            max_skill_size: parse(&config.max_skill_size, Self::DEFAULT.max_skill_size),
+        }
+    }
+}
+
+impl Default for FileLimits {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Files at the skill root that make up the skill itself and are never
+/// loaded as extra files.
+const SKILL_FILES: &[&str] = &["SKILL.md", "skill.toml", "versions.toml", "skillet.toml"];
+
+/// Load a skill's extra files, recursively, within `limits`.
+///
+/// `patterns` come from the skill's `[skill.files]` or frontmatter. Without
+/// include globs the [`EXTRA_DIRS`] folders are loaded; exclude globs
+/// apply either way.
+///
+/// Text files are stored as-is; anything that isn't UTF-8 is stored
+/// base64-encoded. Hidden files and directories, nested skill directories
+/// and the skill's own files are skipped, as are unreadable files, files
+/// over `limits.max_file_size`, and files past the skill's
+/// `limits.max_skill_size` budget (files are visited in path order).
 pub fn load_extra_files(
     skill_dir: &Path,
+    patterns: Option<&FilePatterns>,
+    limits: &FileLimits,
 ) -> crate::error::Result<std::collections::HashMap<String, SkillFile>> {
+    let paths = skillpack_paths(skill_dir, patterns)?;
+
     let mut files = std::collections::HashMap::new();
+    let mut budget = limits.max_skill_size;
 
-    for subdir_name in EXTRA_DIRS {
-        let subdir = skill_dir.join(subdir_name);
-        if !subdir.is_dir() {
+    for (relative_path, path) in paths {
+        let size = match std::fs::metadata(&path) {
+            Ok(meta) => meta.len(),
+            Err(e) => {
+                tracing::debug!(path = %path.display(), error = %e, "Skipping unreadable file");
+                continue;
+            }
+        };
+        if size > limits.max_file_size {
+            tracing::warn!(
+                path = %path.display(),
+                size,
+                max = limits.max_file_size,
+                "Skipping skillpack file over the size limit"
+            );
+            continue;
+        }
+        if size > budget {
+            tracing::warn!(
+                path = %path.display(),
+                max = limits.max_skill_size,
+                "Skipping skillpack file past the skill size limit"
+            );
             continue;
         }
 
-        let entries = std::fs::read_dir(&subdir).map_err(|e| Error::FileRead {
-            path: subdir.clone(),
-            source: e,
-        })?;
-
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if !path.is_file() {
+        let bytes = match std::fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                tracing::warn!(path = %path.display(), error = %e, "Skipping unreadable file");
                 continue;
             }
+        };
+        budget -= size;
 
-            let file_name = entry.file_name().to_string_lossy().to_string();
-            let relative_path = format!("{subdir_name}/{file_name}");
-
-            // Only load text files
-            let content = match std::fs::read_to_string(&path) {
-                Ok(c) => c,
-                Err(_) => {
-                    tracing::debug!(
-                        path = %path.display(),
-                        "Skipping non-text file in skillpack"
-                    );
-                    continue;
-                }
-            };
+        let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+        let mut file = SkillFile::from_bytes(bytes, guess_mime_type(&file_name));
+        if file.binary && file.mime_type.starts_with("text/") {
+            file.mime_type = "application/octet-stream".to_string();
+        }
+        files.insert(relative_path, file);
+    }
 
-            let mime_type = guess_mime_type(&file_name);
+    Ok(files)
+}
 
-            files.insert(relative_path, SkillFile { content, mime_type });
+/// The `(relative path, path)` of every file selected by `patterns` (or
+/// the default [`EXTRA_DIRS`]), sorted by path, before size limits apply.
+pub fn skillpack_paths(
+    skill_dir: &Path,
+    patterns: Option<&FilePatterns>,
+) -> crate::error::Result<Vec<(String, PathBuf)>> {
+    let include = patterns.map(|p| p.include.as_slice()).unwrap_or_default();
+    let exclude = patterns.map(|p| p.exclude.as_slice()).unwrap_or_default();
+
+    let mut paths = Vec::new();
+    if include.is_empty() {
+        for subdir_name in EXTRA_DIRS {
+            let subdir = skill_dir.join(subdir_name);
+            if subdir.symlink_metadata().is_ok_and(|m| m.is_dir()) {
+                collect_files(&subdir, subdir_name, 0, &mut paths)?;
+            }
         }
+    } else {
+        collect_files(skill_dir, "", 0, &mut paths)?;
+        paths.retain(|(relative_path, _)| {
+            !SKILL_FILES.contains(&relative_path.as_str())
+                && crate::glob::matches_any(include, relative_path)
+        });
     }
+    paths.retain(|(relative_path, _)| !crate::glob::matches_any(exclude, relative_path));
+    Ok(paths)
+}
 
-    Ok(files)
+/// Collect `(relative path, path)` for every file under `dir`, sorted by
+/// path, skipping hidden entries, symlinks (which can point outside the
+/// skill) and directories holding their own `SKILL.md`.
+fn collect_files(
+    dir: &Path,
+    prefix: &str,
+    depth: usize,
+    out: &mut Vec<(String, PathBuf)>,
+) -> crate::error::Result<()> {
+    let mut entries: Vec<_> = std::fs::read_dir(dir)
+        .map_err(|e| Error::FileRead {
+            path: dir.to_path_buf(),
+            source: e,
+        })?
+        .flatten()
+        .collect();
+    entries.sort_by_key(|e| e.file_name());
+
+    for entry in entries {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if file_name.starts_with('.') {
+            continue;
+        }
+        let path = entry.path();
+        let relative_path = if prefix.is_empty() {
+            file_name
+        } else {
+            format!("{prefix}/{file_name}")
+        };
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_symlink() {
+            tracing::debug!(path = %path.display(), "Skipping symlink in skillpack");
+            continue;
+        }
+
+        if file_type.is_dir() {
+            if path.join("SKILL.md").is_file() {
+                continue;
+            }
+            if depth + 1 >= MAX_FILE_DEPTH {
+                tracing::debug!(path = %path.display(), "Skillpack directory too deep, skipping");
+                continue;
+            }
+            collect_files(&path, &relative_path, depth + 1, out)?;
+        } else if file_type.is_file() {
+            out.push((relative_path, path));
+        }
+    }
+    Ok(())
 }
 
 /// Simple mime type guessing based on file extension.
 pub fn guess_mime_type(filename: &str) -> String {
-    match filename.rsplit('.').next() {
-        Some("md") => "text/markdown",
-        Some("sh" | "bash") => "text/x-shellscript",
-        Some("py") => "text/x-python",
-        Some("js") => "text/javascript",
-        Some("ts") => "text/typescript",
-        Some("json") => "application/json",
-        Some("toml") => "application/toml",
-        Some("yaml" | "yml") => "text/yaml",
+    let ext = filename
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+    match ext.as_str() {
+        "md" => "text/markdown",
+        "sh" | "bash" => "text/x-shellscript",
+        "py" => "text/x-python",
+        "js" => "text/javascript",
+        "ts" => "text/typescript",
+        "json" => "application/json",
+        "toml" => "application/toml",
+        "yaml" | "yml" => "text/yaml",
+        "html" | "htm" => "text/html",
+        "css" => "text/css",
+        "csv" => "text/csv",
+        "xml" => "application/xml",
+        "svg" => "image/svg+xml",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "ico" => "image/x-icon",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "gz" | "tgz" => "application/gzip",
+        "wasm" => "application/wasm",
+        "woff2" => "font/woff2",
         _ => "text/plain",
     }
     .to_string()
@@ -709,7 +942,8 @@ mod tests {
     #[test]
     fn test_load_index_from_test_repo() {
         let test_dir = test_repo();
-        let index = load_index(test_dir).expect("Failed to load test index");
+        let index =
+            load_index(test_dir, &FileLimits::default()).expect("Failed to load test index");
         assert!(
             !index.skills.is_empty(),
             "Index should have at least one skill"
@@ -719,7 +953,8 @@ mod tests {
     #[test]
     fn test_multi_version_loading() {
         let test_dir = test_repo();
-        let index = load_index(test_dir).expect("Failed to load test index");
+        let index =
+            load_index(test_dir, &FileLimits::default()).expect("Failed to load test index");
 
         // rust-dev has versions.toml with 3 versions
         let entry = index
@@ -757,7 +992,8 @@ mod tests {
     #[test]
     fn test_yanked_version_handling() {
         let test_dir = test_repo();
-        let index = load_index(test_dir).expect("Failed to load test index");
+        let index =
+            load_index(test_dir, &FileLimits::default()).expect("Failed to load test index");
 
         // python-dev has 2 versions, first is yanked
         let entry = index
@@ -777,7 +1013,8 @@ mod tests {
     #[test]
     fn test_backward_compat_without_versions_toml() {
         let test_dir = test_repo();
-        let index = load_index(test_dir).expect("Failed to load test index");
+        let index =
+            load_index(test_dir, &FileLimits::default()).expect("Failed to load test index");
 
         // skillet/setup has no versions.toml -- should load as single version
         let entry = index
@@ -824,7 +1061,7 @@ published = "2026-01-01T00:00:00Z"
         )
         .unwrap();
 
-        let result = load_skill("testowner", "testskill", &skill_dir);
+        let result = load_skill("testowner", "testskill", &skill_dir, &FileLimits::default());
         assert!(result.is_err());
         let err = result.unwrap_err().to_string();
         assert!(
@@ -888,6 +1125,7 @@ description = "A skill collection"
             source: SkillSource::default(),
             trust_tier: Default::default(),
             discovered_via: Vec::new(),
+            origin: None,
             versions: vec![
                 SkillVersion {
                     version: "1.0.0".to_string(),
@@ -902,7 +1140,13 @@ description = "A skill collection"
                             author: None,
                             classification: None,
                             compatibility: None,
+                            language: None,
+                            files: None,
+                            activation: None,
+                            allowed_tools: None,
+                            model: None,
                         },
+                        frontmatter: Default::default(),
                     },
                     skill_md: String::new(),
                     skill_toml_raw: String::new(),
@@ -924,7 +1168,13 @@ description = "A skill collection"
                             author: None,
                             classification: None,
                             compatibility: None,
+                            language: None,
+                            files: None,
+                            activation: None,
+                            allowed_tools: None,
+                            model: None,
                         },
+                        frontmatter: Default::default(),
                     },
                     skill_md: "content".to_string(),
                     skill_toml_raw: String::new(),
@@ -944,7 +1194,8 @@ description = "A skill collection"
     #[test]
     fn test_nested_skill_discovery() {
         let test_dir = test_repo();
-        let index = load_index(test_dir).expect("Failed to load test index");
+        let index =
+            load_index(test_dir, &FileLimits::default()).expect("Failed to load test index");
 
         // Nested skills should be found
         assert!(
@@ -972,7 +1223,8 @@ description = "A skill collection"
     #[test]
     fn test_nested_skill_categories_indexed() {
         let test_dir = test_repo();
-        let index = load_index(test_dir).expect("Failed to load test index");
+        let index =
+            load_index(test_dir, &FileLimits::default()).expect("Failed to load test index");
 
         // Categories from nested skills should be in the index
         assert!(
@@ -990,7 +1242,8 @@ description = "A skill collection"
     #[test]
     fn test_nested_repo_path_set() {
         let test_dir = test_repo();
-        let index = load_index(test_dir).expect("Failed to load test index");
+        let index =
+            load_index(test_dir, &FileLimits::default()).expect("Failed to load test index");
 
         // Nested skills should have repo_path set
         let maven = index
@@ -1037,7 +1290,7 @@ description = "A skill collection"
         ).unwrap();
         std::fs::write(nested_dir.join("SKILL.md"), "# Nested\n\nNested skill.\n").unwrap();
 
-        let index = load_index(reg).expect("Failed to load index");
+        let index = load_index(reg, &FileLimits::default()).expect("Failed to load index");
 
         assert_eq!(index.skills.len(), 2);
         assert!(
@@ -1094,7 +1347,7 @@ description = "A skill collection"
         ).unwrap();
         std::fs::write(too_deep.join("SKILL.md"), "# Too Deep\n").unwrap();
 
-        let index = load_index(reg).expect("Failed to load index");
+        let index = load_index(reg, &FileLimits::default()).expect("Failed to load index");
 
         assert!(
             index
@@ -1132,7 +1385,7 @@ description = "A skill collection"
         ).unwrap();
         std::fs::write(path_b.join("SKILL.md"), "# Second\n\nSecond collider.\n").unwrap();
 
-        let index = load_index(reg).expect("Failed to load index");
+        let index = load_index(reg, &FileLimits::default()).expect("Failed to load index");
 
         // Only one should be present (first-wins based on sorted directory order)
         let entry = index
@@ -1146,6 +1399,37 @@ description = "A skill collection"
             latest.metadata.skill.description, "First collider",
             "first collision path (a/) should win"
         );
+
+        // The dropped one is reported
+        assert_eq!(index.diagnostics.len(), 1);
+        assert_eq!(index.diagnostics[0].kind, DiagnosticKind::DuplicateSkill);
+        assert_eq!(index.diagnostics[0].path, path_b);
+    }
+
+    #[test]
+    fn test_invalid_skill_recorded_as_diagnostic() {
+        let tmp = tempfile::tempdir().unwrap();
+        let good = tmp.path().join("owner").join("good");
+        std::fs::create_dir_all(&good).unwrap();
+        std::fs::write(
+            good.join("SKILL.md"),
+            "---\nname: good\ndescription: Fine\n---\n\n# Good\n",
+        )
+        .unwrap();
+
+        let bad = tmp.path().join("owner").join("bad");
+        std::fs::create_dir_all(&bad).unwrap();
+        std::fs::write(bad.join("skill.toml"), "[skill]\nname = \"bad\"\n").unwrap();
+        std::fs::write(bad.join("SKILL.md"), "# Bad\n").unwrap();
+
+        let index = load_index(tmp.path(), &FileLimits::default()).unwrap();
+        assert_eq!(index.skills.len(), 1);
+        assert_eq!(index.diagnostics.len(), 1);
+        let d = &index.diagnostics[0];
+        assert_eq!(d.path, bad);
+        assert_eq!(d.severity, crate::state::Severity::Error);
+        assert_eq!(d.kind, DiagnosticKind::InvalidSkill);
+        assert!(d.message.contains("skill.toml"), "{}", d.message);
     }
 
     // ── npm-style repo compatibility tests ───────────────────────────
@@ -1167,7 +1451,8 @@ description = "A skill collection"
         std::fs::create_dir_all(skill_dir.join("templates")).unwrap();
         std::fs::write(skill_dir.join("templates/config.toml"), "[default]\n").unwrap();
 
-        let files = load_extra_files(&skill_dir).expect("should load extra files");
+        let files = load_extra_files(&skill_dir, None, &FileLimits::default())
+            .expect("should load extra files");
         assert!(
             files.contains_key("rules/cache-patterns.md"),
             "rules/ files should be loaded: {files:?}"
@@ -1178,6 +1463,186 @@ description = "A skill collection"
         );
     }
 
+    #[test]
+    fn test_load_extra_files_nested_and_binary() {
+        let tmp = tempfile::tempdir().unwrap();
+        let skill_dir = tmp.path().join("my-skill");
+        std::fs::create_dir_all(skill_dir.join("references/api/v2")).unwrap();
+        std::fs::write(skill_dir.join("references/api/v2/users.md"), "# Users\n").unwrap();
+        std::fs::create_dir_all(skill_dir.join("assets/.cache")).unwrap();
+        std::fs::write(skill_dir.join("assets/.cache/junk"), "x").unwrap();
+        let png = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a, 0xff];
+        std::fs::write(skill_dir.join("assets/logo.PNG"), png).unwrap();
+        std::fs::write(skill_dir.join("assets/data.bin"), [0xffu8, 0xfe]).unwrap();
+
+        let files = load_extra_files(&skill_dir, None, &FileLimits::default()).unwrap();
+        assert_eq!(files.len(), 3, "{files:?}");
+
+        let md = &files["references/api/v2/users.md"];
+        assert!(!md.binary);
+        assert_eq!(md.mime_type, "text/markdown");
+
+        let logo = &files["assets/logo.PNG"];
+        assert!(logo.binary);
+        assert_eq!(logo.mime_type, "image/png");
+        assert_eq!(logo.bytes().unwrap(), png);
+
+        assert_eq!(
+            files["assets/data.bin"].mime_type,
+            "application/octet-stream"
+        );
+    }
+
+    #[test]
+    fn test_load_extra_files_size_limits() {
+        let tmp = tempfile::tempdir().unwrap();
+        let skill_dir = tmp.path().join("my-skill");
+        std::fs::create_dir_all(skill_dir.join("references")).unwrap();
+        std::fs::write(skill_dir.join("references/a.md"), "a".repeat(40)).unwrap();
+        std::fs::write(skill_dir.join("references/b.md"), "b".repeat(200)).unwrap();
+        std::fs::write(skill_dir.join("references/c.md"), "c".repeat(40)).unwrap();
+        std::fs::write(skill_dir.join("references/d.md"), "d".repeat(40)).unwrap();
+
+        let limits = FileLimits {
+            max_file_size: 100,
+            max_skill_size: 90,
+        };
+        let files = load_extra_files(&skill_dir, None, &limits).unwrap();
+        let mut names: Vec<&str> = files.keys().map(String::as_str).collect();
+        names.sort();
+        // b.md is over the per-file cap; d.md no longer fits the skill budget
+        assert_eq!(names, vec!["references/a.md", "references/c.md"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_load_extra_files_skips_unreadable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let tmp = tempfile::tempdir().unwrap();
+        let skill_dir = tmp.path().join("my-skill");
+        std::fs::create_dir_all(skill_dir.join("references")).unwrap();
+        std::fs::write(skill_dir.join("references/ok.md"), "ok").unwrap();
+        let locked = skill_dir.join("references/locked.md");
+        std::fs::write(&locked, "secret").unwrap();
+        std::fs::set_permissions(&locked, std::fs::Permissions::from_mode(0o000)).unwrap();
+        if std::fs::read(&locked).is_ok() {
+            // Permissions aren't enforced (e.g. running as root)
+            return;
+        }
+
+        let files = load_extra_files(&skill_dir, None, &FileLimits::default()).unwrap();
+        assert!(files.contains_key("references/ok.md"));
+        assert!(!files.contains_key("references/locked.md"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_load_extra_files_skips_symlinks() {
+        let tmp = tempfile::tempdir().unwrap();
+        let outside = tmp.path().join("outside");
+        std::fs::create_dir_all(&outside).unwrap();
+        std::fs::write(outside.join("id_rsa"), "secret").unwrap();
+
+        let skill_dir = tmp.path().join("my-skill");
+        std::fs::create_dir_all(skill_dir.join("references")).unwrap();
+        std::fs::write(skill_dir.join("references/ok.md"), "ok").unwrap();
+        std::os::unix::fs::symlink(
+            outside.join("id_rsa"),
+            skill_dir.join("references/notes.md"),
+        )
+        .unwrap();
+        std::os::unix::fs::symlink(&outside, skill_dir.join("scripts")).unwrap();
+        std::os::unix::fs::symlink(outside.join("id_rsa"), skill_dir.join("notes.md")).unwrap();
+
+        let files = load_extra_files(&skill_dir, None, &FileLimits::default()).unwrap();
+        assert_eq!(files.keys().collect::<Vec<_>>(), vec!["references/ok.md"]);
+
+        let patterns = FilePatterns {
+            include: vec!["*.md".into(), "scripts".into()],
+            exclude: vec![],
+        };
+        let files = load_extra_files(&skill_dir, Some(&patterns), &FileLimits::default()).unwrap();
+        assert_eq!(files.keys().collect::<Vec<_>>(), vec!["references/ok.md"]);
+    }
+
+    #[test]
+    fn test_load_extra_files_with_patterns() {
+        let tmp = tempfile::tempdir().unwrap();
+        let skill_dir = tmp.path().join("my-skill");
+        for path in [
+            "SKILL.md",
+            "CHEATSHEET.md",
+            "examples/basic/main.rs",
+            "examples/basic/main.rs.tmp",
+            "docs/guide.md",
+            "references/api.md",
+            "nested/SKILL.md",
+            "nested/other.md",
+        ] {
+            let path = skill_dir.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "x").unwrap();
+        }
+
+        let patterns = FilePatterns {
+            include: vec!["examples".into(), "docs/*.md".into(), "*.md".into()],
+            exclude: vec!["*.tmp".into()],
+        };
+        let files = load_extra_files(&skill_dir, Some(&patterns), &FileLimits::default()).unwrap();
+        let mut names: Vec<&str> = files.keys().map(String::as_str).collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "CHEATSHEET.md",
+                "docs/guide.md",
+                "examples/basic/main.rs",
+                "references/api.md",
+            ]
+        );
+
+        // Exclude-only keeps the default folders
+        let patterns = FilePatterns {
+            include: Vec::new(),
+            exclude: vec!["references/api.md".into()],
+        };
+        let files = load_extra_files(&skill_dir, Some(&patterns), &FileLimits::default()).unwrap();
+        assert!(files.is_empty(), "{files:?}");
+    }
+
+    #[test]
+    fn test_skill_toml_file_patterns() {
+        let tmp = tempfile::tempdir().unwrap();
+        let skill_dir = tmp.path().join("acme").join("tool");
+        std::fs::create_dir_all(skill_dir.join("examples")).unwrap();
+        std::fs::create_dir_all(skill_dir.join("scripts")).unwrap();
+        std::fs::write(skill_dir.join("examples/demo.sh"), "echo demo\n").unwrap();
+        std::fs::write(skill_dir.join("scripts/run.sh"), "echo run\n").unwrap();
+        std::fs::write(
+            skill_dir.join("skill.toml"),
+            "[skill]\nname = \"tool\"\nowner = \"acme\"\nversion = \"1.0.0\"\ndescription = \"Tool\"\n\n[skill.files]\ninclude = [\"examples\"]\n",
+        )
+        .unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), "# Tool\n").unwrap();
+
+        let index = load_index(tmp.path(), &FileLimits::default()).unwrap();
+        let entry = &index.skills[&("acme".to_string(), "tool".to_string())];
+        let files: Vec<&String> = entry.latest().unwrap().files.keys().collect();
+        assert_eq!(files, vec!["examples/demo.sh"]);
+    }
+
+    #[test]
+    fn test_file_limits_from_config() {
+        let config = FilesConfig {
+            max_file_size: "64KB".to_string(),
+            max_skill_size: "bogus".to_string(),
+        };
+        let limits = FileLimits::from_config(&config);
+        assert_eq!(limits.max_file_size, 64 * 1024);
+        assert_eq!(limits.max_skill_size, FileLimits::default().max_skill_size);
+    }
+
     #[test]
     fn test_load_index_npm_style() {
         let tmp = tempfile::tempdir().unwrap();
@@ -1211,7 +1676,7 @@ path = "skills"
         )
         .unwrap();
 
-        let index = load_index(root).expect("should load npm-style repo");
+        let index = load_index(root, &FileLimits::default()).expect("should load npm-style repo");
         assert_eq!(index.skills.len(), 2, "should find both skills");
         assert!(
             index
@@ -1236,7 +1701,7 @@ path = "skills"
     #[test]
     fn test_load_index_npm_fixture() {
         let npm_dir = test_npm_repo();
-        let index = load_index(npm_dir).expect("should load test-npm-repo");
+        let index = load_index(npm_dir, &FileLimits::default()).expect("should load test-npm-repo");
 
         assert_eq!(index.skills.len(), 3, "should find 3 skills");
 
@@ -1308,7 +1773,7 @@ path = "skills"
         std::fs::create_dir_all(&skill_b).unwrap();
         std::fs::write(skill_b.join("SKILL.md"), "# Skill B\n\nAnother skill.\n").unwrap();
 
-        let index = load_index(&root).expect("should load flat repo");
+        let index = load_index(&root, &FileLimits::default()).expect("should load flat repo");
         assert_eq!(index.skills.len(), 2, "should find both flat skills");
 
         // Owner comes from directory name since there's no git remote
@@ -1363,7 +1828,8 @@ path = "skills"
         )
         .unwrap();
 
-        let index = load_index(root).expect("should load flat repo with git remote");
+        let index = load_index(root, &FileLimits::default())
+            .expect("should load flat repo with git remote");
         assert_eq!(index.skills.len(), 1);
         assert!(
             index
@@ -1410,7 +1876,8 @@ path = "skills"
         .unwrap();
 
         // load_index is called with the subdirectory (as repo.rs does)
-        let index = load_index(&skills_dir).expect("should load flat repo from subdir");
+        let index = load_index(&skills_dir, &FileLimits::default())
+            .expect("should load flat repo from subdir");
         assert_eq!(index.skills.len(), 1);
         assert!(
             index
@@ -1472,7 +1939,7 @@ Content here.
         )
         .unwrap();
 
-        let index = load_index(root).expect("should load index");
+        let index = load_index(root, &FileLimits::default()).expect("should load index");
         let entry = index
             .skills
             .get(&("testowner".to_string(), "merge-test".to_string()))
@@ -1549,7 +2016,7 @@ No skill.toml needed.
         )
         .unwrap();
 
-        let index = load_index(root).expect("should load index");
+        let index = load_index(root, &FileLimits::default()).expect("should load index");
         let entry = index
             .skills
             .get(&("testowner".to_string(), "fm-only".to_string()))
@@ -1598,7 +2065,7 @@ tags = ["toml"]
         )
         .unwrap();
 
-        let index = load_index(root).expect("should load index");
+        let index = load_index(root, &FileLimits::default()).expect("should load index");
         let entry = index
             .skills
             .get(&("testowner".to_string(), "toml-only".to_string()))
@@ -1618,7 +2085,8 @@ tags = ["toml"]
     fn test_frontmatter_primary_in_standard_repo() {
         // Verify the standard test repo's frontmatter-only skills load correctly
         let test_dir = test_repo();
-        let index = load_index(test_dir).expect("Failed to load test index");
+        let index =
+            load_index(test_dir, &FileLimits::default()).expect("Failed to load test index");
 
         // code-review is frontmatter-only (no skill.toml in fixture)
         let entry = index
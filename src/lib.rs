@@ -6,20 +6,33 @@
 
 pub mod annotations;
 pub mod bm25;
+pub mod browse;
 pub mod cache;
+pub mod compat;
 pub mod config;
+pub mod conflict;
 pub mod discover;
 pub mod error;
+pub mod eval;
+pub mod export;
 pub mod git;
+pub mod glob;
+pub mod import;
+pub mod include;
 pub mod index;
+pub mod lint;
+pub mod mcp_config;
 pub mod project;
 pub mod prompts;
+pub mod ranking;
 pub mod repo;
 pub mod resolve;
 pub mod scaffold;
 pub mod search;
 pub mod state;
 pub mod suggest;
+pub mod synonyms;
+pub mod taxonomy;
 
 #[cfg(any(test, feature = "testutil"))]
 pub mod testutil;
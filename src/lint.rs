@@ -0,0 +1,1123 @@
+//! Authoring checks for skills (`skillet lint`).
+//!
+//! Indexing is forgiving: bad metadata is inferred around, mismatches are
+//! logged and files are skipped. The linter checks skill directories up
+//! front and reports every problem with a file and line, so authors (and
+//! CI) can fix them before publishing. Errors are problems that break
+//! loading or violate the Agent Skills spec; warnings are likely mistakes.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::index::FileLimits;
+use crate::project;
+pub use crate::state::Severity;
+use crate::state::{
+    FilePatterns, KNOWN_CAPABILITIES, KNOWN_FRONTMATTER_FIELDS, SkillMetadata, VersionsManifest,
+};
+
+/// Longest allowed `name` (Agent Skills spec).
+const NAME_MAX: usize = 64;
+/// Longest allowed `description` (Agent Skills spec).
+const DESCRIPTION_MAX: usize = 1024;
+/// Longest allowed `compatibility` string (Agent Skills spec).
+const COMPATIBILITY_MAX: usize = 500;
+/// SKILL.md bodies longer than this should move detail into references.
+const BODY_LINES_MAX: usize = 500;
+/// How deep to search for skill directories below the lint root.
+const MAX_SEARCH_DEPTH: usize = 8;
+
+/// One problem found in a skill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Short rule id (e.g. "broken-link")
+    pub rule: &'static str,
+    pub path: PathBuf,
+    /// 1-based line, when the problem has a location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.path.display())?;
+        if let Some(line) = self.line {
+            write!(f, ":{line}")?;
+        }
+        write!(f, ": {}: {} [{}]", self.severity, self.message, self.rule)
+    }
+}
+
+/// Diagnostics for every skill under a lint root.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Report {
+    /// Number of skill directories checked
+    pub skills: usize,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl Report {
+    pub fn errors(&self) -> usize {
+        self.count(Severity::Error)
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == severity)
+            .count()
+    }
+}
+
+/// Lint every skill directory (one holding a `SKILL.md`) at or below `root`.
+pub fn lint(root: &Path, limits: &FileLimits) -> crate::error::Result<Report> {
+    if !root.is_dir() {
+        return Err(Error::Other(format!(
+            "{} is not a directory",
+            root.display()
+        )));
+    }
+
+    let mut dirs = Vec::new();
+    find_skill_dirs(root, 0, &mut dirs);
+
+    let mut report = Report {
+        skills: dirs.len(),
+        diagnostics: Vec::new(),
+    };
+    for dir in &dirs {
+        report.diagnostics.extend(lint_skill(dir, limits));
+    }
+    Ok(report)
+}
+
+/// Collect directories holding a `SKILL.md`, skipping hidden directories
+/// (other than `.skillet`) and build output.
+fn find_skill_dirs(dir: &Path, depth: usize, out: &mut Vec<PathBuf>) {
+    if dir.join("SKILL.md").is_file() {
+        out.push(dir.to_path_buf());
+    }
+    if depth >= MAX_SEARCH_DEPTH {
+        return;
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut subdirs: Vec<PathBuf> = entries
+        .flatten()
+        .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
+        .filter(|e| {
+            let name = e.file_name().to_string_lossy().to_string();
+            (name == ".skillet" || !name.starts_with('.'))
+                && name != "node_modules"
+                && name != "target"
+        })
+        .map(|e| e.path())
+        .collect();
+    subdirs.sort();
+    for sub in subdirs {
+        find_skill_dirs(&sub, depth + 1, out);
+    }
+}
+
+/// Lint a single skill directory.
+pub fn lint_skill(dir: &Path, limits: &FileLimits) -> Vec<Diagnostic> {
+    let mut linter = Linter {
+        dir: dir.to_path_buf(),
+        diagnostics: Vec::new(),
+    };
+    linter.run(limits);
+    linter.diagnostics
+}
+
+/// Parsed SKILL.md frontmatter with its location.
+struct Frontmatter {
+    map: serde_yaml::Mapping,
+    /// The frontmatter's lines, with their 1-based line numbers
+    lines: Vec<(usize, String)>,
+}
+
+impl Frontmatter {
+    /// Line of a top-level `key:` in the frontmatter.
+    fn line_of(&self, key: &str) -> Option<usize> {
+        let prefix = format!("{key}:");
+        self.lines
+            .iter()
+            .find(|(_, l)| l.starts_with(&prefix))
+            .map(|(n, _)| *n)
+    }
+
+    fn str(&self, key: &str) -> Option<&str> {
+        self.map.get(key).and_then(|v| v.as_str())
+    }
+}
+
+struct Linter {
+    dir: PathBuf,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Linter {
+    fn push(
+        &mut self,
+        severity: Severity,
+        rule: &'static str,
+        file: &str,
+        line: Option<usize>,
+        message: String,
+    ) {
+        self.diagnostics.push(Diagnostic {
+            severity,
+            rule,
+            path: self.dir.join(file),
+            line,
+            message,
+        });
+    }
+
+    fn error(&mut self, rule: &'static str, file: &str, line: Option<usize>, message: String) {
+        self.push(Severity::Error, rule, file, line, message);
+    }
+
+    fn warning(&mut self, rule: &'static str, file: &str, line: Option<usize>, message: String) {
+        self.push(Severity::Warning, rule, file, line, message);
+    }
+
+    fn dir_name(&self) -> String {
+        self.dir
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default()
+    }
+
+    fn run(&mut self, limits: &FileLimits) {
+        let skill_md = match std::fs::read_to_string(self.dir.join("SKILL.md")) {
+            Ok(s) => s,
+            Err(e) => {
+                self.error(
+                    "read",
+                    "SKILL.md",
+                    None,
+                    format!("can't read SKILL.md: {e}"),
+                );
+                return;
+            }
+        };
+        if skill_md.trim().is_empty() {
+            self.error("empty", "SKILL.md", None, "SKILL.md is empty".to_string());
+            return;
+        }
+
+        let toml_meta = self.check_skill_toml();
+        let frontmatter = self.parse_frontmatter(&skill_md);
+
+        match frontmatter {
+            Some(ref fm) => self.check_frontmatter(fm, toml_meta.as_ref()),
+            None if toml_meta.is_some() => self.warning(
+                "frontmatter-missing",
+                "SKILL.md",
+                Some(1),
+                "no frontmatter; agents reading SKILL.md directly won't see name and description"
+                    .to_string(),
+            ),
+            None if self.dir.join("skill.toml").is_file() => {}
+            None => self.warning(
+                "frontmatter-missing",
+                "SKILL.md",
+                Some(1),
+                "no frontmatter or skill.toml; name and description will be inferred".to_string(),
+            ),
+        }
+
+        let trigger = frontmatter
+            .as_ref()
+            .and_then(|fm| fm.str("trigger"))
+            .map(str::to_string)
+            .or_else(|| toml_meta.as_ref().and_then(|m| m.skill.trigger.clone()));
+        if trigger.is_none_or(|t| t.trim().is_empty()) {
+            self.warning(
+                "missing-trigger",
+                "SKILL.md",
+                frontmatter.as_ref().map(|_| 1),
+                "no trigger; agents can't tell when to use this skill".to_string(),
+            );
+        }
+
+        let patterns = frontmatter
+            .as_ref()
+            .and_then(|_| project::parse_frontmatter(&skill_md))
+            .and_then(|fm| fm.files)
+            .or_else(|| toml_meta.as_ref().and_then(|m| m.skill.files.clone()))
+            .or_else(|| self.manifest_patterns());
+
+        self.check_body(&skill_md, limits);
+        self.check_links(&skill_md, patterns.as_ref());
+        self.check_includes(&skill_md, patterns.as_ref());
+        self.check_files(patterns.as_ref(), limits);
+
+        let version = frontmatter
+            .as_ref()
+            .and_then(|fm| fm.map.get("version"))
+            .and_then(yaml_scalar)
+            .or_else(|| toml_meta.as_ref().map(|m| m.skill.version.clone()));
+        if let (Some(fm), Some(toml)) = (frontmatter.as_ref(), toml_meta.as_ref())
+            && let Some(ref v) = version
+            && fm.map.contains_key("version")
+            && *v != toml.skill.version
+        {
+            self.warning(
+                "version-mismatch",
+                "skill.toml",
+                line_of(
+                    &std::fs::read_to_string(self.dir.join("skill.toml")).unwrap_or_default(),
+                    "version",
+                ),
+                format!(
+                    "skill.toml version '{}' differs from frontmatter version '{v}'",
+                    toml.skill.version
+                ),
+            );
+        }
+        self.check_versions(version.as_deref());
+    }
+
+    /// `[skill].files` from a `skillet.toml` next to SKILL.md.
+    fn manifest_patterns(&self) -> Option<FilePatterns> {
+        project::load_skillet_toml(&self.dir)
+            .ok()
+            .flatten()
+            .and_then(|m| m.skill)
+            .and_then(|s| s.files)
+    }
+
+    /// Parse skill.toml, checking name and owner against the directories.
+    fn check_skill_toml(&mut self) -> Option<SkillMetadata> {
+        let path = self.dir.join("skill.toml");
+        if !path.is_file() {
+            return None;
+        }
+        let raw = match std::fs::read_to_string(&path) {
+            Ok(raw) => raw,
+            Err(e) => {
+                self.error(
+                    "read",
+                    "skill.toml",
+                    None,
+                    format!("can't read skill.toml: {e}"),
+                );
+                return None;
+            }
+        };
+        let meta: SkillMetadata = match toml::from_str(&raw) {
+            Ok(m) => m,
+            Err(e) => {
+                let line = e.span().map(|s| line_at(&raw, s.start));
+                self.error("toml-parse", "skill.toml", line, e.message().to_string());
+                return None;
+            }
+        };
+
+        let has_frontmatter = self
+            .dir
+            .join("SKILL.md")
+            .is_file()
+            .then(|| std::fs::read_to_string(self.dir.join("SKILL.md")).unwrap_or_default())
+            .is_some_and(|md| project::parse_frontmatter(&md).is_some());
+        // With frontmatter the directory names win and skill.toml's are
+        // ignored; without it a mismatch fails loading.
+        let severity = if has_frontmatter {
+            Severity::Warning
+        } else {
+            Severity::Error
+        };
+
+        let dir_name = self.dir_name();
+        if meta.skill.name != dir_name {
+            self.push(
+                severity,
+                "name-mismatch",
+                "skill.toml",
+                line_of(&raw, "name"),
+                format!(
+                    "name '{}' doesn't match directory '{dir_name}'",
+                    meta.skill.name
+                ),
+            );
+        }
+        let in_owner_dir = self
+            .dir
+            .ancestors()
+            .skip(1)
+            .take(MAX_SEARCH_DEPTH)
+            .any(|a| {
+                a.file_name()
+                    .is_some_and(|n| n == meta.skill.owner.as_str())
+            });
+        if !in_owner_dir {
+            self.push(
+                severity,
+                "owner-mismatch",
+                "skill.toml",
+                line_of(&raw, "owner"),
+                format!(
+                    "owner '{}' doesn't match any parent directory",
+                    meta.skill.owner
+                ),
+            );
+        }
+        if meta.skill.description.trim().is_empty() {
+            self.error(
+                "missing-description",
+                "skill.toml",
+                line_of(&raw, "description"),
+                "description is empty".to_string(),
+            );
+        }
+
+        if let Some(ref compat) = meta.skill.compatibility {
+            for cap in &compat.required_capabilities {
+                let known = KNOWN_CAPABILITIES.contains(&cap.as_str())
+                    || crate::compat::MCP_CAPABILITIES.contains(&cap.as_str());
+                if !known {
+                    self.warning(
+                        "unknown-capability",
+                        "skill.toml",
+                        line_of(&raw, &format!("\"{cap}\"")),
+                        format!(
+                            "unknown capability '{cap}' (known: {})",
+                            KNOWN_CAPABILITIES
+                                .iter()
+                                .chain(crate::compat::MCP_CAPABILITIES)
+                                .copied()
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    );
+                }
+            }
+        }
+
+        Some(meta)
+    }
+
+    /// Split and parse the frontmatter block, reporting syntax errors.
+    fn parse_frontmatter(&mut self, skill_md: &str) -> Option<Frontmatter> {
+        let mut lines = skill_md
+            .lines()
+            .enumerate()
+            .skip_while(|(_, l)| l.trim().is_empty());
+        let (_, first) = lines.next()?;
+        if first.trim_end() != "---" {
+            return None;
+        }
+
+        let mut block = Vec::new();
+        let mut closed = false;
+        for (i, line) in lines {
+            if line.trim_end() == "---" {
+                closed = true;
+                break;
+            }
+            block.push((i + 1, line.to_string()));
+        }
+        if !closed {
+            self.error(
+                "frontmatter-unclosed",
+                "SKILL.md",
+                Some(1),
+                "frontmatter has no closing '---'".to_string(),
+            );
+            return None;
+        }
+
+        let first_line = block.first().map(|(n, _)| *n).unwrap_or(1);
+        let yaml: String = block
+            .iter()
+            .map(|(_, l)| l.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        match serde_yaml::from_str::<serde_yaml::Value>(&yaml) {
+            Ok(serde_yaml::Value::Mapping(map)) => Some(Frontmatter { map, lines: block }),
+            Ok(serde_yaml::Value::Null) => Some(Frontmatter {
+                map: serde_yaml::Mapping::new(),
+                lines: block,
+            }),
+            Ok(_) => {
+                self.error(
+                    "frontmatter-parse",
+                    "SKILL.md",
+                    Some(first_line),
+                    "frontmatter must be a YAML mapping".to_string(),
+                );
+                None
+            }
+            Err(e) => {
+                let line = e.location().map(|l| first_line + l.line() - 1);
+                self.error("frontmatter-parse", "SKILL.md", line, e.to_string());
+                None
+            }
+        }
+    }
+
+    /// Check frontmatter fields against the Agent Skills spec.
+    fn check_frontmatter(&mut self, fm: &Frontmatter, toml_meta: Option<&SkillMetadata>) {
+        let dir_name = self.dir_name();
+
+        match fm.str("name") {
+            None if toml_meta.is_some() => {}
+            None => self.error(
+                "spec-name",
+                "SKILL.md",
+                Some(1),
+                "missing required field 'name'".to_string(),
+            ),
+            Some(name) => {
+                let line = fm.line_of("name");
+                if let Some(problem) = name_problem(name) {
+                    self.error("spec-name", "SKILL.md", line, problem);
+                }
+                if name != dir_name {
+                    self.error(
+                        "name-mismatch",
+                        "SKILL.md",
+                        line,
+                        format!("name '{name}' doesn't match directory '{dir_name}'"),
+                    );
+                }
+            }
+        }
+
+        let description = fm
+            .str("description")
+            .map(str::trim)
+            .filter(|d| !d.is_empty());
+        match description {
+            None if toml_meta.is_some_and(|m| !m.skill.description.trim().is_empty()) => {}
+            None => self.error(
+                "missing-description",
+                "SKILL.md",
+                fm.line_of("description").or(Some(1)),
+                "missing required field 'description'".to_string(),
+            ),
+            Some(d) if d.chars().count() > DESCRIPTION_MAX => self.error(
+                "spec-description",
+                "SKILL.md",
+                fm.line_of("description"),
+                format!(
+                    "description is {} characters (max {DESCRIPTION_MAX})",
+                    d.chars().count()
+                ),
+            ),
+            Some(_) => {}
+        }
+
+        if let Some(compat) = fm.map.get("compatibility") {
+            let line = fm.line_of("compatibility");
+            match compat.as_str() {
+                Some(c) if c.chars().count() > COMPATIBILITY_MAX => self.error(
+                    "spec-compatibility",
+                    "SKILL.md",
+                    line,
+                    format!(
+                        "compatibility is {} characters (max {COMPATIBILITY_MAX})",
+                        c.chars().count()
+                    ),
+                ),
+                Some(_) => {}
+                None => self.warning(
+                    "spec-compatibility",
+                    "SKILL.md",
+                    line,
+                    "compatibility should be a string; use [skill.compatibility] in skill.toml \
+                     for structured requirements"
+                        .to_string(),
+                ),
+            }
+        }
+
+        if let Some(metadata) = fm.map.get("metadata")
+            && !metadata.is_mapping()
+        {
+            self.error(
+                "spec-metadata",
+                "SKILL.md",
+                fm.line_of("metadata"),
+                "metadata must be a mapping".to_string(),
+            );
+        }
+
+        if let Some(tools) = fm.map.get("allowed-tools")
+            && !tools.is_string()
+            && !tools.is_sequence()
+        {
+            self.error(
+                "spec-allowed-tools",
+                "SKILL.md",
+                fm.line_of("allowed-tools"),
+                "allowed-tools must be a space-separated string".to_string(),
+            );
+        }
+
+        for key in fm.map.keys().filter_map(|k| k.as_str()) {
+            if !KNOWN_FRONTMATTER_FIELDS.contains(&key) {
+                self.warning(
+                    "unknown-field",
+                    "SKILL.md",
+                    fm.line_of(key),
+                    format!("unknown frontmatter field '{key}' (put custom keys under metadata)"),
+                );
+            }
+        }
+    }
+
+    /// Warn about SKILL.md bodies too long to load comfortably.
+    fn check_body(&mut self, skill_md: &str, limits: &FileLimits) {
+        let lines = skill_md.lines().count();
+        if lines > BODY_LINES_MAX {
+            self.warning(
+                "oversized",
+                "SKILL.md",
+                None,
+                format!(
+                    "SKILL.md is {lines} lines (keep it under {BODY_LINES_MAX}; move detail into references/)"
+                ),
+            );
+        }
+        if skill_md.len() as u64 > limits.max_file_size {
+            self.warning(
+                "oversized",
+                "SKILL.md",
+                None,
+                format!(
+                    "SKILL.md is {} bytes (file limit {})",
+                    skill_md.len(),
+                    limits.max_file_size
+                ),
+            );
+        }
+    }
+
+    /// Check relative markdown links in SKILL.md point at shipped files.
+    fn check_links(&mut self, skill_md: &str, patterns: Option<&FilePatterns>) {
+        let shipped: HashSet<String> = crate::index::skillpack_paths(&self.dir, patterns)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(rel, _)| rel)
+            .collect();
+
+        let mut in_fence = false;
+        for (i, line) in skill_md.lines().enumerate() {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+                continue;
+            }
+            if in_fence {
+                continue;
+            }
+            for target in link_targets(line) {
+                let Some(rel) = relative_target(&target) else {
+                    continue;
+                };
+                let path = self.dir.join(&rel);
+                if !path.exists() {
+                    self.error(
+                        "broken-link",
+                        "SKILL.md",
+                        Some(i + 1),
+                        format!("link target '{target}' doesn't exist"),
+                    );
+                } else if path.is_file() && !shipped.contains(&rel) && rel != "SKILL.md" {
+                    self.warning(
+                        "unshipped-link",
+                        "SKILL.md",
+                        Some(i + 1),
+                        format!(
+                            "link target '{target}' isn't shipped with the skill (add it to [skill.files])"
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Check include directives that name files resolve to shipped files.
+    ///
+    /// `owner/name` includes depend on the index at serve time, so only
+    /// file includes are checked here.
+    fn check_includes(&mut self, skill_md: &str, patterns: Option<&FilePatterns>) {
+        if !crate::include::has_includes(skill_md) {
+            return;
+        }
+        let shipped: HashSet<String> = crate::index::skillpack_paths(&self.dir, patterns)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(rel, _)| rel)
+            .collect();
+
+        let mut in_fence = false;
+        for (i, line) in skill_md.lines().enumerate() {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+                continue;
+            }
+            let Some(directive) = crate::include::parse_directive(line).filter(|_| !in_fence)
+            else {
+                continue;
+            };
+            let rel = directive.target.trim_start_matches("./");
+            let path = self.dir.join(rel);
+            let problem = if shipped.contains(rel) {
+                directive.section.and_then(|section| {
+                    let text = std::fs::read_to_string(&path).unwrap_or_default();
+                    crate::prompts::extract_section(&text, section)
+                        .is_none()
+                        .then(|| format!("include target '{rel}' has no section '{section}'"))
+                })
+            } else if path.is_file() {
+                Some(format!(
+                    "include target '{rel}' isn't shipped with the skill (add it to [skill.files])"
+                ))
+            } else if rel
+                .split_once('/')
+                .is_some_and(|(_, name)| !name.contains(['/', '.']))
+            {
+                None // owner/name, resolved against the index when served
+            } else {
+                Some(format!("include target '{rel}' doesn't exist"))
+            };
+            if let Some(message) = problem {
+                self.error("broken-include", "SKILL.md", Some(i + 1), message);
+            }
+        }
+    }
+
+    /// Warn about skillpack files the size limits will drop.
+    fn check_files(&mut self, patterns: Option<&FilePatterns>, limits: &FileLimits) {
+        let paths = match crate::index::skillpack_paths(&self.dir, patterns) {
+            Ok(p) => p,
+            Err(e) => {
+                self.error("read", "", None, e.to_string());
+                return;
+            }
+        };
+        let mut total = 0;
+        for (rel, path) in paths {
+            let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+            if size > limits.max_file_size {
+                self.warning(
+                    "oversized-file",
+                    &rel,
+                    None,
+                    format!(
+                        "{size} bytes exceeds the {}-byte file limit and will be skipped",
+                        limits.max_file_size
+                    ),
+                );
+            } else {
+                total += size;
+            }
+        }
+        if total > limits.max_skill_size {
+            self.warning(
+                "oversized-file",
+                "",
+                None,
+                format!(
+                    "skill files total {total} bytes, over the {}-byte skill limit; some will be skipped",
+                    limits.max_skill_size
+                ),
+            );
+        }
+    }
+
+    /// Check versions.toml parses and agrees with the skill's version.
+    fn check_versions(&mut self, version: Option<&str>) {
+        let path = self.dir.join("versions.toml");
+        if !path.is_file() {
+            return;
+        }
+        let raw = match std::fs::read_to_string(&path) {
+            Ok(raw) => raw,
+            Err(e) => {
+                self.error(
+                    "read",
+                    "versions.toml",
+                    None,
+                    format!("can't read versions.toml: {e}"),
+                );
+                return;
+            }
+        };
+        let manifest: VersionsManifest = match toml::from_str(&raw) {
+            Ok(m) => m,
+            Err(e) => {
+                let line = e.span().map(|s| line_at(&raw, s.start));
+                self.error("toml-parse", "versions.toml", line, e.message().to_string());
+                return;
+            }
+        };
+
+        let Some(last) = manifest.versions.last() else {
+            self.error(
+                "versions-empty",
+                "versions.toml",
+                None,
+                "versions.toml has no entries".to_string(),
+            );
+            return;
+        };
+
+        // Line of each `version = ...` entry, in order
+        let version_lines: Vec<usize> = raw
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| l.trim_start().starts_with("version"))
+            .map(|(i, _)| i + 1)
+            .collect();
+        let line_for = |i: usize| version_lines.get(i).copied();
+
+        let mut seen = HashSet::new();
+        for (i, record) in manifest.versions.iter().enumerate() {
+            if !seen.insert(record.version.as_str()) {
+                self.error(
+                    "versions-duplicate",
+                    "versions.toml",
+                    line_for(i),
+                    format!("version '{}' is listed more than once", record.version),
+                );
+            }
+        }
+        for (i, pair) in manifest.versions.windows(2).enumerate() {
+            if pair[1].published < pair[0].published {
+                self.warning(
+                    "versions-order",
+                    "versions.toml",
+                    line_for(i + 1),
+                    format!(
+                        "'{}' was published before '{}'; list versions oldest first",
+                        pair[1].version, pair[0].version
+                    ),
+                );
+            }
+        }
+
+        // An inferred "0.1.0" adopts the versions.toml version when loading
+        if let Some(version) = version
+            && version != last.version
+            && version != "0.1.0"
+        {
+            self.error(
+                "version-mismatch",
+                "versions.toml",
+                line_for(manifest.versions.len() - 1),
+                format!(
+                    "last entry is '{}' but the skill's version is '{version}'",
+                    last.version
+                ),
+            );
+        }
+    }
+}
+
+/// Why `name` breaks the spec's naming rules, if it does.
+fn name_problem(name: &str) -> Option<String> {
+    if name.is_empty() {
+        return Some("name is empty".to_string());
+    }
+    if name.chars().count() > NAME_MAX {
+        return Some(format!("name is longer than {NAME_MAX} characters"));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Some(format!(
+            "name '{name}' may only contain lowercase letters, digits and hyphens"
+        ));
+    }
+    if name.starts_with('-') || name.ends_with('-') || name.contains("--") {
+        return Some(format!(
+            "name '{name}' can't start or end with a hyphen or contain '--'"
+        ));
+    }
+    None
+}
+
+/// Targets of the markdown links and images on one line.
+fn link_targets(line: &str) -> Vec<String> {
+    let mut targets = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find("](") {
+        let after = &rest[start + 2..];
+        let Some(end) = after.find(')') else {
+            break;
+        };
+        let target = after[..end]
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .trim_matches(['<', '>']);
+        if !target.is_empty() {
+            targets.push(target.to_string());
+        }
+        rest = &after[end..];
+    }
+    targets
+}
+
+/// The skill-relative path a link points at, or `None` for URLs, anchors
+/// and absolute paths.
+fn relative_target(target: &str) -> Option<String> {
+    if target.contains("://")
+        || target.starts_with('#')
+        || target.starts_with('/')
+        || target.starts_with("mailto:")
+    {
+        return None;
+    }
+    let path = target.split(['#', '?']).next().unwrap_or_default();
+    let path = path.trim_start_matches("./").replace("%20", " ");
+    (!path.is_empty()).then_some(path)
+}
+
+/// Render a YAML scalar as a string.
+fn yaml_scalar(value: &serde_yaml::Value) -> Option<String> {
+    match value {
+        serde_yaml::Value::String(s) => Some(s.clone()),
+        serde_yaml::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// 1-based line of the first line starting with `key` (TOML keys) or
+/// containing it (quoted values).
+fn line_of(text: &str, needle: &str) -> Option<usize> {
+    text.lines()
+        .position(|l| {
+            let l = l.trim_start();
+            if needle.starts_with('"') {
+                l.contains(needle)
+            } else {
+                l.starts_with(needle) && l[needle.len()..].trim_start().starts_with(['=', ':'])
+            }
+        })
+        .map(|i| i + 1)
+}
+
+/// 1-based line containing byte `offset`.
+fn line_at(text: &str, offset: usize) -> usize {
+    text[..offset.min(text.len())].matches('\n').count() + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, content: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    fn rules(diagnostics: &[Diagnostic]) -> Vec<(&'static str, Option<usize>)> {
+        diagnostics.iter().map(|d| (d.rule, d.line)).collect()
+    }
+
+    #[test]
+    fn test_clean_skill_has_no_diagnostics() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("acme/clean-skill");
+        write(
+            &dir.join("SKILL.md"),
+            "---\nname: clean-skill\ndescription: Does one thing well\ntrigger: Use when testing\n---\n\nSee [the guide](references/guide.md).\n",
+        );
+        write(&dir.join("references/guide.md"), "# Guide\n");
+
+        let report = lint(tmp.path(), &FileLimits::default()).unwrap();
+        assert_eq!(report.skills, 1);
+        assert!(report.diagnostics.is_empty(), "{:?}", report.diagnostics);
+    }
+
+    #[test]
+    fn test_frontmatter_spec_violations() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("acme/my-skill");
+        write(
+            &dir.join("SKILL.md"),
+            "---\nname: My_Skill\nmetadata: nope\nfavorite: blue\n---\n\n# Body\n",
+        );
+
+        let diagnostics = lint_skill(&dir, &FileLimits::default());
+        assert_eq!(
+            rules(&diagnostics),
+            vec![
+                ("spec-name", Some(2)),
+                ("name-mismatch", Some(2)),
+                ("missing-description", Some(1)),
+                ("spec-metadata", Some(3)),
+                ("unknown-field", Some(4)),
+                ("missing-trigger", Some(1)),
+            ]
+        );
+        assert_eq!(
+            diagnostics[0].to_string(),
+            format!(
+                "{}:2: error: name 'My_Skill' may only contain lowercase letters, digits and hyphens [spec-name]",
+                dir.join("SKILL.md").display()
+            )
+        );
+    }
+
+    #[test]
+    fn test_yaml_and_toml_errors_have_lines() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("acme/broken");
+        write(
+            &dir.join("SKILL.md"),
+            "---\nname: broken\ndescription: [unclosed\n---\n",
+        );
+        write(
+            &dir.join("skill.toml"),
+            "[skill]\nname = \"broken\"\nowner = acme\n",
+        );
+
+        let diagnostics = lint_skill(&dir, &FileLimits::default());
+        let toml = diagnostics.iter().find(|d| d.rule == "toml-parse").unwrap();
+        assert_eq!(toml.line, Some(3));
+        let yaml = diagnostics
+            .iter()
+            .find(|d| d.rule == "frontmatter-parse")
+            .unwrap();
+        assert!(yaml.line.is_some_and(|l| l >= 3), "{yaml:?}");
+    }
+
+    #[test]
+    fn test_skill_toml_checks() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("acme/tool");
+        write(&dir.join("SKILL.md"), "# Tool\n\nDoes things.\n");
+        write(
+            &dir.join("skill.toml"),
+            "[skill]\nname = \"tool\"\nowner = \"someone\"\nversion = \"1.0.0\"\ndescription = \"Tool\"\ntrigger = \"Use it\"\n\n[skill.compatibility]\nrequired_capabilities = [\"shell_exec\", \"teleport\"]\n",
+        );
+
+        let diagnostics = lint_skill(&dir, &FileLimits::default());
+        assert_eq!(
+            rules(&diagnostics),
+            vec![
+                ("owner-mismatch", Some(3)),
+                ("unknown-capability", Some(9)),
+                ("frontmatter-missing", Some(1)),
+            ]
+        );
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[1].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_links() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("acme/linker");
+        write(
+            &dir.join("SKILL.md"),
+            "---\nname: linker\ndescription: Links\ntrigger: Always\n---\n\n\
+             [ok](references/a.md#intro) [web](https://example.com) [top](#top)\n\
+             ![missing](assets/missing.png)\n\
+             ```\n[ignored](nowhere.md)\n```\n\
+             [unshipped](notes/todo.md)\n",
+        );
+        write(&dir.join("references/a.md"), "# A\n");
+        write(&dir.join("notes/todo.md"), "todo\n");
+
+        let diagnostics = lint_skill(&dir, &FileLimits::default());
+        assert_eq!(
+            rules(&diagnostics),
+            vec![("broken-link", Some(8)), ("unshipped-link", Some(12))]
+        );
+    }
+
+    #[test]
+    fn test_includes() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("acme/includer");
+        write(
+            &dir.join("SKILL.md"),
+            "---\nname: includer\ndescription: Includes\ntrigger: Always\n---\n\n\
+             <!-- skillet:include references/common.md -->\n\
+             {{> references/common.md#Safety}}\n\
+             {{> references/common.md#Nowhere}}\n\
+             {{> acme/house-style}}\n\
+             {{> references/missing.md}}\n\
+             {{> notes/todo.md}}\n\
+             ```\n{{> nowhere.md}}\n```\n",
+        );
+        write(
+            &dir.join("references/common.md"),
+            "# Common\n\n## Safety\n\nCare.\n",
+        );
+        write(&dir.join("notes/todo.md"), "todo\n");
+
+        let diagnostics = lint_skill(&dir, &FileLimits::default());
+        assert_eq!(
+            rules(&diagnostics),
+            vec![
+                ("broken-include", Some(9)),
+                ("broken-include", Some(11)),
+                ("broken-include", Some(12)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_versions_consistency() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("acme/versioned");
+        write(
+            &dir.join("SKILL.md"),
+            "---\nname: versioned\ndescription: Versioned\ntrigger: Always\nversion: 2.0.0\n---\n",
+        );
+        write(
+            &dir.join("versions.toml"),
+            "[[versions]]\nversion = \"1.0.0\"\npublished = \"2026-02-01T00:00:00Z\"\n\n\
+             [[versions]]\nversion = \"1.0.0\"\npublished = \"2026-01-01T00:00:00Z\"\n",
+        );
+
+        let diagnostics = lint_skill(&dir, &FileLimits::default());
+        assert_eq!(
+            rules(&diagnostics),
+            vec![
+                ("versions-duplicate", Some(6)),
+                ("versions-order", Some(6)),
+                ("version-mismatch", Some(6)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_oversized_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dir = tmp.path().join("acme/big");
+        write(
+            &dir.join("SKILL.md"),
+            "---\nname: big\ndescription: Big\ntrigger: Always\n---\n",
+        );
+        write(&dir.join("assets/huge.bin"), &"x".repeat(200));
+
+        let limits = FileLimits {
+            max_file_size: 100,
+            max_skill_size: 1000,
+        };
+        let diagnostics = lint_skill(&dir, &limits);
+        assert_eq!(rules(&diagnostics), vec![("oversized-file", None)]);
+        assert!(diagnostics[0].path.ends_with("assets/huge.bin"));
+    }
+}
@@ -4,25 +4,29 @@
 //! and transport management. Core logic lives in the library crate.
 
 mod cli;
+mod middleware;
 mod tools;
 
 use std::collections::HashSet;
 use std::io::IsTerminal;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 use std::sync::Arc;
 use std::time::Duration;
 
 use clap::{Parser, Subcommand};
-use tower_mcp::registry::DynamicPromptRegistry;
+use tower_mcp::registry::{DynamicPromptRegistry, DynamicResourceRegistry};
 use tower_mcp::transport::http::HttpTransport;
 use tower_mcp::{McpRouter, StdioTransport};
 
 use skillet_mcp::cache::{self, RepoSource};
 use skillet_mcp::config;
-use skillet_mcp::repo::{cache_dir_for_url, default_cache_dir, parse_duration};
+use skillet_mcp::conflict::MergePolicy;
+use skillet_mcp::project::ProjectContext;
+use skillet_mcp::repo::{RepoSettings, cache_dir_for_url, default_cache_dir, parse_duration};
 use skillet_mcp::state::AppState;
-use skillet_mcp::{git, index, prompts, repo, search, state};
+use skillet_mcp::suggest::DiscoveredRepo;
+use skillet_mcp::{git, index, prompts, repo, search, state, synonyms, taxonomy};
 
 #[derive(Parser, Debug)]
 #[command(name = "skillet")]
@@ -49,12 +53,89 @@ enum Command {
     Search(SearchArgs),
     /// List all skill categories with counts
     Categories(CategoriesArgs),
+    /// List all skill tags with counts
+    Tags(TagsArgs),
+    /// Browse the category tree one level at a time
+    Browse(BrowseArgs),
     /// Show detailed information about a skill
     Info(InfoArgs),
+    /// List skills similar to a skill
+    Similar(SimilarArgs),
+    /// Evaluate search quality against golden queries
+    SearchEval(SearchEvalArgs),
     /// Manage configured repos
     Repo(RepoCommand),
     /// Discover skill repos on GitHub
     Discover(DiscoverArgs),
+    /// Show the effective configuration
+    Config,
+    /// Check local MCP client configs against skills' required servers
+    Doctor(DoctorArgs),
+    /// Check skills for problems before publishing
+    Lint(LintArgs),
+    /// List skills provided by more than one repo and which copy wins
+    Conflicts(ConflictsArgs),
+    /// Write skills into agent-native layouts for agents without MCP
+    Export(ExportArgs),
+}
+
+#[derive(clap::Args, Debug)]
+struct ConflictsArgs {
+    /// Print conflicts as JSON
+    #[arg(long)]
+    json: bool,
+
+    #[command(flatten)]
+    repos: RepoArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct ExportArgs {
+    /// Skills to export (owner/name)
+    #[arg(required_unless_present = "check")]
+    skills: Vec<String>,
+
+    /// Layout to write: claude, cursor, copilot, or agents-md
+    #[arg(long, required_unless_present = "check")]
+    target: Option<skillet_mcp::export::ExportTarget>,
+
+    /// Directory to export into (default: current dir)
+    #[arg(long, default_value = ".")]
+    out: PathBuf,
+
+    /// Report exported files edited on disk or whose skills changed,
+    /// instead of exporting (exits 1 on drift)
+    #[arg(long, conflicts_with = "target")]
+    check: bool,
+
+    /// Print drift as JSON (with --check)
+    #[arg(long, requires = "check")]
+    json: bool,
+
+    #[command(flatten)]
+    repos: RepoArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct LintArgs {
+    /// Skill directory, or a repo containing skills
+    #[arg(default_value = ".")]
+    path: PathBuf,
+
+    /// Print diagnostics as JSON
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(clap::Args, Debug)]
+struct DoctorArgs {
+    /// Project directory to read MCP client configs from (default: the
+    /// nearest `skillet.toml` root, else the current dir)
+    #[arg(long)]
+    project: Option<PathBuf>,
+
+    #[command(flatten)]
+    repos: RepoArgs,
 }
 
 #[derive(clap::Args, Debug)]
@@ -102,7 +183,7 @@ struct ServeArgs {
     #[arg(long, conflicts_with = "tools")]
     read_only: bool,
 
-    /// Explicit tool allowlist (comma-separated: search,categories,owner,info)
+    /// Explicit tool allowlist (comma-separated: search,categories,tags,browse,owner,info,similar,annotate,diagnostics)
     #[arg(long, value_delimiter = ',')]
     tools: Vec<String>,
 
@@ -175,6 +256,15 @@ struct SearchArgs {
     #[arg(long)]
     owner: Option<String>,
 
+    /// Filter on a SKILL.md frontmatter field, top-level or under
+    /// `metadata:` (e.g. `--meta audience=backend`); repeatable
+    #[arg(long = "meta", value_name = "KEY=VALUE", value_parser = parse_key_value)]
+    meta: Vec<(String, String)>,
+
+    /// Only show skills whose required MCP servers are configured locally
+    #[arg(long)]
+    mcp_ready: bool,
+
     #[command(flatten)]
     repos: RepoArgs,
 }
@@ -185,6 +275,25 @@ struct CategoriesArgs {
     repos: RepoArgs,
 }
 
+#[derive(clap::Args, Debug)]
+struct TagsArgs {
+    /// Sort by skill count instead of name
+    #[arg(long)]
+    by_count: bool,
+
+    #[command(flatten)]
+    repos: RepoArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct BrowseArgs {
+    /// Category path to list (e.g. "development/rust"); omit for the top level
+    path: Option<String>,
+
+    #[command(flatten)]
+    repos: RepoArgs,
+}
+
 #[derive(clap::Args, Debug)]
 struct InfoArgs {
     /// Skill to show in owner/name format
@@ -194,6 +303,36 @@ struct InfoArgs {
     repos: RepoArgs,
 }
 
+#[derive(clap::Args, Debug)]
+struct SimilarArgs {
+    /// Skill to compare against in owner/name format
+    skill: String,
+
+    /// Maximum number of similar skills to show
+    #[arg(long, default_value_t = 5)]
+    limit: usize,
+
+    #[command(flatten)]
+    repos: RepoArgs,
+}
+
+#[derive(clap::Args, Debug)]
+struct SearchEvalArgs {
+    /// Golden query file (TOML with [[case]] tables, or JSONL)
+    file: PathBuf,
+
+    /// Number of results to score per query
+    #[arg(short, long, default_value_t = 10)]
+    k: usize,
+
+    /// Print the report as JSON
+    #[arg(long)]
+    json: bool,
+
+    #[command(flatten)]
+    repos: RepoArgs,
+}
+
 #[derive(clap::Args, Debug)]
 struct RepoCommand {
     #[command(subcommand)]
@@ -237,9 +376,18 @@ async fn main() -> ExitCode {
         Some(Command::Init(args)) => cli::author::run_init(args),
         Some(Command::Search(args)) => cli::search::run_search(args),
         Some(Command::Categories(args)) => cli::search::run_categories(args),
+        Some(Command::Tags(args)) => cli::search::run_tags(args),
+        Some(Command::Browse(args)) => cli::search::run_browse(args),
         Some(Command::Info(args)) => cli::search::run_info(args),
+        Some(Command::Similar(args)) => cli::search::run_similar(args),
+        Some(Command::SearchEval(args)) => cli::search::run_search_eval(args),
         Some(Command::Repo(args)) => cli::repo::run_repo(args),
         Some(Command::Discover(args)) => run_discover(args),
+        Some(Command::Config) => cli::config::run_config(),
+        Some(Command::Doctor(args)) => cli::doctor::run_doctor(args),
+        Some(Command::Lint(args)) => cli::lint::run_lint(args),
+        Some(Command::Conflicts(args)) => cli::conflicts::run_conflicts(args),
+        Some(Command::Export(args)) => cli::export::run_export(args),
         Some(Command::Serve(args)) => run_serve(args).await,
         None if interactive_tty => {
             eprintln!("Skillet - skill discovery for AI agents\n");
@@ -256,8 +404,28 @@ async fn main() -> ExitCode {
     }
 }
 
+/// Parse a `KEY=VALUE` argument.
+fn parse_key_value(s: &str) -> Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => {
+            Ok((key.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!("expected KEY=VALUE, got '{s}'")),
+    }
+}
+
 /// All known tool short names.
-const ALL_TOOL_NAMES: &[&str] = &["search", "categories", "owner", "info", "annotate"];
+const ALL_TOOL_NAMES: &[&str] = &[
+    "search",
+    "categories",
+    "tags",
+    "browse",
+    "owner",
+    "info",
+    "similar",
+    "annotate",
+    "diagnostics",
+];
 
 /// Resolved set of capabilities to expose from the MCP server.
 struct ServerCapabilities {
@@ -283,14 +451,24 @@ impl ServerCapabilities {
     }
 }
 
+/// Dynamic registries updated when the index or project context reloads.
+#[derive(Clone)]
+struct Registries {
+    prompts: DynamicPromptRegistry,
+    resources: DynamicResourceRegistry,
+}
+
 /// Build an MCP router from a loaded AppState and resolved capabilities.
 ///
-/// Returns the router and a `DynamicPromptRegistry` handle for updating
-/// prompts on index refresh.
+/// Returns the router and the dynamic prompt/resource registries for
+/// updating skills and project context on refresh. `watch` says whether
+/// the project context can change after startup.
 fn build_router(
     state: Arc<AppState>,
     caps: &ServerCapabilities,
-) -> (McpRouter, DynamicPromptRegistry) {
+    project: Option<&ProjectContext>,
+    watch: bool,
+) -> (McpRouter, Registries) {
     let mut router = McpRouter::new().server_info(&state.config.name, env!("CARGO_PKG_VERSION"));
 
     // Register tools conditionally
@@ -300,27 +478,50 @@ fn build_router(
     if caps.tools.contains("categories") {
         router = router.tool(tools::list_categories::build(state.clone()));
     }
+    if caps.tools.contains("tags") {
+        router = router.tool(tools::list_tags::build(state.clone()));
+    }
+    if caps.tools.contains("browse") {
+        router = router.tool(tools::browse_skills::build(state.clone()));
+    }
     if caps.tools.contains("owner") {
         router = router.tool(tools::list_skills_by_owner::build(state.clone()));
     }
     if caps.tools.contains("info") {
         router = router.tool(tools::info_skill::build(state.clone()));
     }
+    if caps.tools.contains("similar") {
+        router = router.tool(tools::similar_skills::build(state.clone()));
+    }
     if caps.tools.contains("annotate") {
-        router = router.tool(tools::annotate_skill::build());
+        router = router.tool(tools::annotate_skill::build(state.clone()));
+    }
+    if caps.tools.contains("diagnostics") {
+        router = router.tool(tools::load_diagnostics::build(state.clone()));
     }
 
     // Build dynamic instructions based on exposed capabilities
-    router = router.instructions(build_instructions(caps));
+    router = router.instructions(build_instructions(caps, project, watch));
 
     // Enable dynamic prompts -- skills are registered as prompts
-    let (router, prompt_registry) = router.with_dynamic_prompts();
+    let (router, prompts) = router.with_dynamic_prompts();
+    // Dynamic resources carry the project's AGENTS.md
+    let (router, resources) = router.with_dynamic_resources();
 
-    (router, prompt_registry)
+    (router, Registries { prompts, resources })
 }
 
 /// Generate MCP instructions text listing only exposed tools.
-fn build_instructions(caps: &ServerCapabilities) -> String {
+///
+/// When serving from inside a project with `[project].agents_md`, the
+/// instructions point the agent at the project context first. Instructions
+/// are sent once per session, so with `watch` (where AGENTS.md can appear,
+/// change, or go away later) they only say where to look for it.
+fn build_instructions(
+    caps: &ServerCapabilities,
+    project: Option<&ProjectContext>,
+    watch: bool,
+) -> String {
     let mut text = String::from(
         "Skillet is a skill discovery tool for AI agents. Use it to discover and \
          fetch skills relevant to your current task.\n\n",
@@ -329,11 +530,19 @@ fn build_instructions(caps: &ServerCapabilities) -> String {
     // Tools section
     let mut tool_lines = Vec::new();
     if caps.tools.contains("search") {
-        tool_lines.push("- search_skills: Search for skills by keyword, category, tag, or model");
+        tool_lines.push(
+            "- search_skills: Search for skills by keyword, category, tag, model, or metadata",
+        );
     }
     if caps.tools.contains("categories") {
         tool_lines.push("- list_categories: Browse all skill categories");
     }
+    if caps.tools.contains("tags") {
+        tool_lines.push("- list_tags: Browse all skill tags");
+    }
+    if caps.tools.contains("browse") {
+        tool_lines.push("- browse_skills: Walk the category tree one level at a time");
+    }
     if caps.tools.contains("owner") {
         tool_lines.push("- list_skills_by_owner: List all skills by a publisher");
     }
@@ -342,11 +551,18 @@ fn build_instructions(caps: &ServerCapabilities) -> String {
             "- info_skill: Get detailed information about a specific skill (version, author, tags, files, etc.)",
         );
     }
+    if caps.tools.contains("similar") {
+        tool_lines.push("- similar_skills: Find skills related to a given skill");
+    }
     if caps.tools.contains("annotate") {
         tool_lines.push(
             "- annotate_skill: Attach a persistent note to a skill (records gaps, tips, corrections)",
         );
     }
+    if caps.tools.contains("diagnostics") {
+        tool_lines
+            .push("- load_diagnostics: List skills and repos that failed to load or were skipped");
+    }
     if !tool_lines.is_empty() {
         text.push_str("Tools:\n");
         for line in &tool_lines {
@@ -363,6 +579,26 @@ fn build_instructions(caps: &ServerCapabilities) -> String {
          and get_prompt to retrieve skill content for your current session.\n",
     );
 
+    if watch {
+        text.push_str(&format!(
+            "\nProject context: if prompts/list includes `{}`, read the project's \
+             conventions (AGENTS.md) before starting work -- get_prompt `{}` or read \
+             the `{}` resource.\n",
+            prompts::PROJECT_CONTEXT_PROMPT,
+            prompts::PROJECT_CONTEXT_PROMPT,
+            prompts::PROJECT_CONTEXT_URI,
+        ));
+    } else if let Some(project) = project {
+        text.push_str(&format!(
+            "\nProject context: this server is running in the {} project. Read its \
+             conventions (AGENTS.md) before starting work -- get_prompt `{}` or read \
+             the `{}` resource.\n",
+            project.project,
+            prompts::PROJECT_CONTEXT_PROMPT,
+            prompts::PROJECT_CONTEXT_URI,
+        ));
+    }
+
     text
 }
 
@@ -432,6 +668,8 @@ async fn run_serve(args: ServeArgs) -> ExitCode {
 async fn run_serve_inner(args: ServeArgs) -> Result<(), tower_mcp::BoxError> {
     let cache_base = args.cache_dir.clone().unwrap_or_else(default_cache_dir);
     let cli_config = config::load_config().unwrap_or_default();
+    let limits = index::FileLimits::from_config(&cli_config.files);
+    let project_root = skillet_mcp::project::resolve_project_root();
     let mut repo_paths = Vec::new();
 
     // Resolve local repos
@@ -445,23 +683,32 @@ async fn run_serve_inner(args: ServeArgs) -> Result<(), tower_mcp::BoxError> {
     }
 
     // Resolve remote repos (clone/pull)
-    for url in &args.remote {
-        let target = cache_dir_for_url(&cache_base, url);
-        if let Some(parent) = target.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-        git::clone_or_pull(url, &target)?;
+    let concurrency = cli_config.repos.concurrency;
+    let remotes = repo::parallel_map(
+        &args.remote,
+        concurrency,
+        |url| -> Result<_, tower_mcp::BoxError> {
+            let target = cache_dir_for_url(&cache_base, url);
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            git::clone_or_pull(url, &target)?;
 
-        // Resolve release model: checkout appropriate tag/ref
-        if let Err(e) = skillet_mcp::resolve::resolve_and_checkout(&target, url, &cli_config.source)
-        {
-            tracing::warn!(url, error = %e, "Failed to resolve release ref, using default branch");
-        }
+            // Resolve release model: checkout appropriate tag/ref
+            if let Err(e) =
+                skillet_mcp::resolve::resolve_and_checkout(&target, url, &cli_config.source)
+            {
+                tracing::warn!(url, error = %e, "Failed to resolve release ref, using default branch");
+            }
 
-        let path = match &args.subdir {
-            Some(sub) => target.join(sub),
-            None => target,
-        };
+            Ok(match &args.subdir {
+                Some(sub) => target.join(sub),
+                None => target,
+            })
+        },
+    );
+    for (url, path) in args.remote.iter().zip(remotes) {
+        let path = path?;
         tracing::info!(repo = %path.display(), remote = %url, "Adding remote repo");
         repo_paths.push(path);
     }
@@ -490,16 +737,30 @@ async fn run_serve_inner(args: ServeArgs) -> Result<(), tower_mcp::BoxError> {
     let mut merged_index = state::SkillIndex::default();
     let mut config = state::ServerConfig::default();
 
-    for (i, path) in repo_paths.iter().enumerate() {
-        if i == 0 {
-            // Use first repo's config for server name
-            config = index::load_config(path)?;
-        }
-        let idx = index::load_index(path)?;
-        merged_index.merge(idx);
+    if let Some(first) = repo_paths.first() {
+        // Use first repo's config for server name
+        config = index::load_config(first)?;
+    }
+    // Index in parallel, merge in order so ties go to earlier repos
+    let merge_policy = MergePolicy::from_config(&cli_config.repos, &cache_base);
+    let repo_settings = RepoSettings::from_config(&cli_config.repos);
+    let mut remote_urls = args.remote.clone();
+    remote_urls.extend(default_remote_urls);
+    let loaded = repo::parallel_map(&repo_paths, concurrency, |path| {
+        index::load_index(path, &limits).map_err(tower_mcp::BoxError::from)
+    });
+    for (path, idx) in repo_paths.iter().zip(loaded) {
+        let mut idx = idx?;
+        repo_settings.apply(
+            &repo_source_for_path(path, &remote_urls, &[], &cache_base),
+            path,
+            &mut idx,
+        );
+        merged_index.merge_with(idx, &merge_policy);
     }
 
     // Follow [[suggest]] entries from loaded repos
+    let mut suggested = Vec::new();
     if !args.no_suggest && cli_config.suggest.enabled {
         let cache_enabled = cli_config.cache.enabled;
         let cache_ttl = if cache_enabled {
@@ -515,7 +776,11 @@ async fn run_serve_inner(args: ServeArgs) -> Result<(), tower_mcp::BoxError> {
             cache_ttl,
             &seed_urls,
             cli_config.source.clone(),
-        );
+        )
+        .with_concurrency(concurrency)
+        .with_merge_policy(merge_policy.clone())
+        .with_repo_settings(repo_settings)
+        .with_file_limits(limits);
         let seed_paths = repo_paths.clone();
         walker.walk(
             &seed_paths,
@@ -524,21 +789,23 @@ async fn run_serve_inner(args: ServeArgs) -> Result<(), tower_mcp::BoxError> {
             cli_config.suggest.max_depth,
             vec![],
         );
+        suggested = walker.discovered().to_vec();
     }
 
-    // Auto-detect skillet.toml in current directory for embedded skills
-    if let Some(project_root) = skillet_mcp::project::find_skillet_toml(std::path::Path::new(".")) {
+    // Auto-detect skillet.toml in the project for embedded skills
+    if let Some(project_root) = skillet_mcp::project::find_skillet_toml(&project_root) {
         match skillet_mcp::project::load_skillet_toml(&project_root) {
             Ok(Some(manifest)) if manifest.skill.is_some() || manifest.skills.is_some() => {
-                let embedded = skillet_mcp::project::load_embedded_skills(&project_root, &manifest);
+                let embedded =
+                    skillet_mcp::project::load_embedded_skills(&project_root, &manifest, &limits);
                 if !embedded.skills.is_empty() {
                     tracing::info!(
                         count = embedded.skills.len(),
                         project = %project_root.display(),
                         "Loaded embedded skills from skillet.toml"
                     );
-                    merged_index.merge(embedded);
                 }
+                merged_index.merge_with(embedded, &merge_policy);
             }
             Ok(_) => {} // No skill sections or no manifest
             Err(e) => {
@@ -546,19 +813,30 @@ async fn run_serve_inner(args: ServeArgs) -> Result<(), tower_mcp::BoxError> {
                     error = %e,
                     "Failed to load skillet.toml for embedded skills"
                 );
+                merged_index.diagnostics.push(state::LoadDiagnostic::error(
+                    state::DiagnosticKind::RepoUnreadable,
+                    project_root.join("skillet.toml"),
+                    e.to_string(),
+                ));
             }
         }
     }
 
-    let skill_search = search::SkillSearch::build(&merged_index);
-    let mut remote_urls = args.remote.clone();
-    remote_urls.extend(default_remote_urls);
-    let state = AppState::new(
-        repo_paths,
-        remote_urls.clone(),
-        merged_index,
-        skill_search,
-        config,
+    taxonomy::normalize_index(&mut merged_index, &cli_config.taxonomy);
+
+    let skill_search =
+        search::SkillSearch::build_with_config(&merged_index, &cli_config.search, &repo_paths);
+    let state = Arc::new(
+        AppState::new(
+            repo_paths,
+            remote_urls.clone(),
+            suggested,
+            merged_index,
+            skill_search,
+            config,
+            cli_config.clone(),
+        )
+        .with_project_root(project_root),
     );
 
     // Resolve which tools to expose and build the router
@@ -568,15 +846,26 @@ async fn run_serve_inner(args: ServeArgs) -> Result<(), tower_mcp::BoxError> {
         "Exposing MCP capabilities"
     );
 
-    let (router, prompt_registry) = build_router(Arc::clone(&state), &caps);
+    let project_context = load_project_context(&state.project_root);
+    let (router, registries) = build_router(
+        Arc::clone(&state),
+        &caps,
+        project_context.as_ref(),
+        args.watch,
+    );
 
     // Register all skills as MCP prompts
     {
         let index = state.index.read().await;
-        prompts::register_all(&prompt_registry, &index);
+        prompts::register_all(&registries.prompts, &index);
         let count = index.skills.len();
         tracing::info!(count, "Registered skills as MCP prompts");
     }
+    prompts::sync_project_context(
+        &registries.prompts,
+        &registries.resources,
+        project_context.as_ref(),
+    );
 
     // Determine refresh interval: CLI flag wins, then server config, then "5m"
     let effective_interval = if args.refresh_interval == "5m" {
@@ -592,47 +881,65 @@ async fn run_serve_inner(args: ServeArgs) -> Result<(), tower_mcp::BoxError> {
     let interval = parse_duration(&effective_interval)?;
     if interval > Duration::ZERO {
         for url in remote_urls {
-            spawn_refresh_task(Arc::clone(&state), prompt_registry.clone(), url, interval);
+            spawn_refresh_task(Arc::clone(&state), registries.clone(), url, interval);
         }
     }
 
     // Spawn filesystem watch task if requested
     if args.watch {
-        spawn_watch_task(Arc::clone(&state), prompt_registry.clone());
+        spawn_watch_task(Arc::clone(&state), registries.clone());
     }
 
     if let Some(addr) = args.http {
         tracing::info!(addr = %addr, "Serving over HTTP");
         HttpTransport::new(router)
+            .layer(middleware::ClientProfileLayer::new(Arc::clone(&state)))
             .disable_origin_validation()
             .serve(&addr)
             .await?;
     } else {
         tracing::info!("Serving over stdio");
-        StdioTransport::new(router).run().await?;
+        StdioTransport::new(router)
+            .layer(middleware::ClientProfileLayer::new(Arc::clone(&state)))
+            .run()
+            .await?;
     }
 
     Ok(())
 }
 
 /// Reload all skill indexes, rebuild search, and sync prompts.
-async fn reload_index(
-    state: &Arc<AppState>,
-    prompt_registry: &DynamicPromptRegistry,
-) -> anyhow::Result<()> {
+///
+/// Also re-reads the project's AGENTS.md so its prompt and resource stay
+/// current.
+async fn reload_index(state: &Arc<AppState>, registries: &Registries) -> anyhow::Result<()> {
     let paths = state.repo_paths.clone();
+    let project_root = state.project_root.clone();
     let remote_urls = state.remote_urls.clone();
+    let suggested = state.suggested.clone();
     let cache_base = default_cache_dir();
+    let taxonomy_config = state.cli_config.taxonomy.clone();
+    let concurrency = state.cli_config.repos.concurrency;
+    let merge_policy = MergePolicy::from_config(&state.cli_config.repos, &cache_base);
+    let repo_settings = RepoSettings::from_config(&state.cli_config.repos);
+    let limits = index::FileLimits::from_config(&state.cli_config.files);
 
-    let new_index = tokio::task::spawn_blocking(move || {
+    let (new_index, project_context) = tokio::task::spawn_blocking(move || {
         let mut merged = state::SkillIndex::default();
-        for path in &paths {
-            match index::load_index(path) {
-                Ok(idx) => {
+        let loaded = repo::parallel_map(&paths, concurrency, |path| {
+            index::load_index(path, &limits).map_err(tower_mcp::BoxError::from)
+        });
+        for (path, result) in paths.iter().zip(loaded) {
+            match result {
+                Ok(mut idx) => {
                     // Write cache for this individual repo
-                    let source = repo_source_for_path(path, &remote_urls, &cache_base);
+                    let source = repo_source_for_path(path, &remote_urls, &suggested, &cache_base);
                     cache::write(&source, &idx);
-                    merged.merge(idx);
+                    repo_settings.apply(&source, path, &mut idx);
+                    if let Some(repo) = suggested.iter().find(|repo| repo.path == *path) {
+                        repo.stamp(&mut idx);
+                    }
+                    merged.merge_with(idx, &merge_policy);
                 }
                 Err(e) => {
                     tracing::warn!(
@@ -640,45 +947,90 @@ async fn reload_index(
                         error = %e,
                         "Failed to reload repo, skipping"
                     );
+                    merged.diagnostics.push(state::LoadDiagnostic::error(
+                        state::DiagnosticKind::IndexFailed,
+                        path,
+                        format!("couldn't reload repo: {e}"),
+                    ));
                 }
             }
         }
         // Re-load embedded skills from skillet.toml
-        if let Some(project_root) =
-            skillet_mcp::project::find_skillet_toml(std::path::Path::new("."))
+        if let Some(project_root) = skillet_mcp::project::find_skillet_toml(&project_root)
             && let Ok(Some(manifest)) = skillet_mcp::project::load_skillet_toml(&project_root)
             && (manifest.skill.is_some() || manifest.skills.is_some())
         {
-            let embedded = skillet_mcp::project::load_embedded_skills(&project_root, &manifest);
+            let embedded =
+                skillet_mcp::project::load_embedded_skills(&project_root, &manifest, &limits);
             if !embedded.skills.is_empty() {
                 tracing::info!(count = embedded.skills.len(), "Re-loaded embedded skills");
-                merged.merge(embedded);
             }
+            merged.merge_with(embedded, &merge_policy);
         }
-        merged
+        taxonomy::normalize_index(&mut merged, &taxonomy_config);
+        (merged, load_project_context(&project_root))
     })
     .await?;
 
-    let new_search = search::SkillSearch::build(&new_index);
+    let synonyms = synonyms::SynonymMap::load(&state.cli_config.search, &state.repo_paths);
 
     // Sync prompts: unregister removed skills, register new/updated ones
     let old_index = state.index.read().await;
-    prompts::sync(prompt_registry, &old_index, &new_index);
+    prompts::sync(&registries.prompts, &old_index, &new_index);
     drop(old_index);
+    prompts::sync_project_context(
+        &registries.prompts,
+        &registries.resources,
+        project_context.as_ref(),
+    );
 
+    // Patch the search index in place: only added, removed, or changed
+    // skills are re-tokenized.
     let mut idx = state.index.write().await;
     let mut srch = state.search.write().await;
+    srch.update(&idx, &new_index);
+    srch.set_synonyms(synonyms, state.cli_config.search.synonym_weight);
     *idx = new_index;
-    *srch = new_search;
     Ok(())
 }
 
+/// Load the project's `[project].agents_md`, if a `skillet.toml` is found
+/// from `project_root`. Read failures are logged, not fatal.
+fn load_project_context(project_root: &std::path::Path) -> Option<ProjectContext> {
+    let project_root = skillet_mcp::project::find_skillet_toml(project_root)?;
+    let manifest = skillet_mcp::project::load_skillet_toml(&project_root)
+        .ok()
+        .flatten()?;
+    match skillet_mcp::project::load_project_context(&project_root, &manifest) {
+        Ok(Some(context)) => {
+            tracing::info!(
+                project = %context.project,
+                path = %context.path.display(),
+                "Loaded project context from AGENTS.md"
+            );
+            Some(context)
+        }
+        Ok(None) => None,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to load project AGENTS.md");
+            None
+        }
+    }
+}
+
 /// Determine the cache `RepoSource` for a given repo path.
+///
+/// Suggested repos resolve to the remote they were discovered at, so
+/// URL-keyed `[repos]` settings still apply to them on reload.
 fn repo_source_for_path(
     path: &std::path::Path,
     remote_urls: &[String],
+    suggested: &[DiscoveredRepo],
     cache_base: &std::path::Path,
 ) -> RepoSource {
+    if let Some(repo) = suggested.iter().find(|repo| repo.path == path) {
+        return repo.source.clone();
+    }
     for url in remote_urls {
         let checkout = cache_dir_for_url(cache_base, url);
         if path.starts_with(&checkout) {
@@ -695,7 +1047,7 @@ fn repo_source_for_path(
 /// reloads all indexes if the HEAD commit changes.
 fn spawn_refresh_task(
     state: Arc<AppState>,
-    prompt_registry: DynamicPromptRegistry,
+    registries: Registries,
     url: String,
     interval: Duration,
 ) {
@@ -735,7 +1087,7 @@ fn spawn_refresh_task(
             .await;
 
             match pull_result {
-                Ok(Ok(true)) => match reload_index(&state, &prompt_registry).await {
+                Ok(Ok(true)) => match reload_index(&state, &registries).await {
                     Ok(()) => {
                         tracing::info!(url = %url, "Index refreshed from remote");
                     }
@@ -769,9 +1121,16 @@ fn spawn_refresh_task(
     });
 }
 
+/// `path` with its parent directory canonicalized, so watcher events match
+/// configured paths even for files that were just created or removed.
+fn canonical_file_path(path: &Path) -> Option<PathBuf> {
+    let parent = std::fs::canonicalize(path.parent()?).ok()?;
+    Some(parent.join(path.file_name()?))
+}
+
 /// Spawn a background task that watches all local repo directories for
 /// changes and reloads the index when relevant files are modified.
-fn spawn_watch_task(state: Arc<AppState>, prompt_registry: DynamicPromptRegistry) {
+fn spawn_watch_task(state: Arc<AppState>, registries: Registries) {
     use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode};
 
     for path in &state.repo_paths {
@@ -783,6 +1142,28 @@ fn spawn_watch_task(state: Arc<AppState>, prompt_registry: DynamicPromptRegistry
 
     let watch_paths: Vec<PathBuf> = state.repo_paths.clone();
 
+    // The project's AGENTS.md, whatever it is named
+    let agents_md = skillet_mcp::project::find_skillet_toml(&state.project_root)
+        .and_then(|root| {
+            let manifest = skillet_mcp::project::load_skillet_toml(&root).ok()??;
+            skillet_mcp::project::agents_md_path(&root, &manifest)
+        })
+        .and_then(|path| canonical_file_path(&path));
+
+    // Also watch the directory holding it, unless a watched repo already
+    // covers it
+    let agents_md_dir = agents_md
+        .as_deref()
+        .and_then(|path| path.parent().map(|p| p.to_path_buf()))
+        .filter(|dir| {
+            !watch_paths
+                .iter()
+                .any(|repo| std::fs::canonicalize(repo).is_ok_and(|repo| dir.starts_with(repo)))
+        });
+    if let Some(ref dir) = agents_md_dir {
+        tracing::info!(dir = %dir.display(), "Watching project AGENTS.md for changes");
+    }
+
     tokio::spawn(async move {
         let (tx, mut rx) = tokio::sync::mpsc::channel(16);
 
@@ -805,6 +1186,11 @@ fn spawn_watch_task(state: Arc<AppState>, prompt_registry: DynamicPromptRegistry
                     .watch(path, RecursiveMode::Recursive)
                     .expect("failed to watch repo directory");
             }
+            if let Some(ref dir) = agents_md_dir
+                && let Err(e) = debouncer.watcher().watch(dir, RecursiveMode::NonRecursive)
+            {
+                tracing::warn!(error = %e, "Failed to watch project AGENTS.md");
+            }
 
             debouncer
         };
@@ -818,6 +1204,18 @@ fn spawn_watch_task(state: Arc<AppState>, prompt_registry: DynamicPromptRegistry
                     return false;
                 }
 
+                // In the AGENTS.md directory, only AGENTS.md itself and the
+                // skillet.toml naming it matter
+                let canonical = canonical_file_path(path);
+                if agents_md.is_some() && canonical == agents_md {
+                    return true;
+                }
+                if agents_md_dir.is_some()
+                    && canonical.as_deref().and_then(Path::parent) == agents_md_dir.as_deref()
+                {
+                    return path.file_name().is_some_and(|n| n == "skillet.toml");
+                }
+
                 // Only react to files that matter for the index
                 match path.extension().and_then(|e| e.to_str()) {
                     Some("toml" | "md") => true,
@@ -825,7 +1223,7 @@ fn spawn_watch_task(state: Arc<AppState>, prompt_registry: DynamicPromptRegistry
                         // Also react to changes in extra-file directories
                         path.components().any(|c| {
                             let s = c.as_os_str().to_string_lossy();
-                            s == "scripts" || s == "references" || s == "assets"
+                            index::EXTRA_DIRS.contains(&s.as_ref())
                         })
                     }
                 }
@@ -833,7 +1231,7 @@ fn spawn_watch_task(state: Arc<AppState>, prompt_registry: DynamicPromptRegistry
 
             if dominated_by_relevant {
                 tracing::info!("File change detected, reloading index");
-                if let Err(e) = reload_index(&state, &prompt_registry).await {
+                if let Err(e) = reload_index(&state, &registries).await {
                     tracing::warn!(error = %e, "Failed to reload after file change");
                 }
             }
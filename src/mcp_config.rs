@@ -0,0 +1,271 @@
+//! Local MCP client configuration discovery.
+//!
+//! Skills can declare `required_mcp_servers`. This module reads the config
+//! files common MCP clients keep on disk, both project-level (`.mcp.json`,
+//! `.cursor/mcp.json`, `.vscode/mcp.json`) and user-level (`~/.claude.json`,
+//! `~/.cursor/mcp.json`, Claude Desktop, Windsurf), to find out which
+//! servers are actually configured and which requirements are unmet.
+//!
+//! Unreadable or malformed files don't fail discovery; they are collected in
+//! [`McpInventory::errors`] so a report can show them.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use crate::state::SkillEntry;
+
+/// Where a client keeps its config, relative to the project or home dir.
+struct Location {
+    client: &'static str,
+    path: &'static str,
+    /// JSON key holding the server map.
+    key: &'static str,
+}
+
+const PROJECT_CONFIGS: &[Location] = &[
+    Location {
+        client: "Claude Code (project)",
+        path: ".mcp.json",
+        key: "mcpServers",
+    },
+    Location {
+        client: "Cursor (project)",
+        path: ".cursor/mcp.json",
+        key: "mcpServers",
+    },
+    Location {
+        client: "VS Code (project)",
+        path: ".vscode/mcp.json",
+        key: "servers",
+    },
+];
+
+const USER_CONFIGS: &[Location] = &[
+    Location {
+        client: "Claude Code (user)",
+        path: ".claude.json",
+        key: "mcpServers",
+    },
+    Location {
+        client: "Cursor (user)",
+        path: ".cursor/mcp.json",
+        key: "mcpServers",
+    },
+    Location {
+        client: "Claude Desktop",
+        path: "Library/Application Support/Claude/claude_desktop_config.json",
+        key: "mcpServers",
+    },
+    Location {
+        client: "Claude Desktop",
+        path: ".config/Claude/claude_desktop_config.json",
+        key: "mcpServers",
+    },
+    Location {
+        client: "Windsurf",
+        path: ".codeium/windsurf/mcp_config.json",
+        key: "mcpServers",
+    },
+];
+
+/// One client config file and the servers it defines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientConfig {
+    /// Client label (e.g. "Claude Code (project)").
+    pub client: &'static str,
+    pub path: PathBuf,
+    pub servers: BTreeSet<String>,
+}
+
+/// All MCP servers configured for a project, by source file.
+#[derive(Debug, Clone, Default)]
+pub struct McpInventory {
+    pub configs: Vec<ClientConfig>,
+    /// Config files that exist but couldn't be read or parsed.
+    pub errors: Vec<(PathBuf, String)>,
+}
+
+impl McpInventory {
+    /// Discover configs for `project_dir`, with user-level configs under `$HOME`.
+    pub fn discover(project_dir: &Path) -> Self {
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+        Self::discover_in(project_dir, home.as_deref())
+    }
+
+    /// Discover configs for `project_dir`, with user-level configs under `home`.
+    pub fn discover_in(project_dir: &Path, home: Option<&Path>) -> Self {
+        let mut inventory = Self::default();
+        for loc in PROJECT_CONFIGS {
+            inventory.read(loc, &project_dir.join(loc.path), None);
+        }
+        if let Some(home) = home {
+            // ~/.claude.json also keeps per-project servers under projects.<abs path>
+            let project = project_dir
+                .canonicalize()
+                .unwrap_or_else(|_| project_dir.to_path_buf());
+            for loc in USER_CONFIGS {
+                inventory.read(loc, &home.join(loc.path), Some(&project));
+            }
+        }
+        inventory
+    }
+
+    fn read(&mut self, loc: &Location, path: &Path, project: Option<&Path>) {
+        let raw = match std::fs::read_to_string(path) {
+            Ok(raw) => raw,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return,
+            Err(e) => {
+                self.errors.push((path.to_path_buf(), e.to_string()));
+                return;
+            }
+        };
+        match parse_servers(&raw, loc.key, project) {
+            Ok(servers) => self.configs.push(ClientConfig {
+                client: loc.client,
+                path: path.to_path_buf(),
+                servers,
+            }),
+            Err(e) => self.errors.push((path.to_path_buf(), e)),
+        }
+    }
+
+    /// Every configured server name, across all config files.
+    pub fn servers(&self) -> BTreeSet<&str> {
+        self.configs
+            .iter()
+            .flat_map(|c| c.servers.iter().map(String::as_str))
+            .collect()
+    }
+
+    /// Whether a server with this name (case-insensitive) is configured.
+    pub fn has_server(&self, name: &str) -> bool {
+        self.configs
+            .iter()
+            .flat_map(|c| &c.servers)
+            .any(|s| s.eq_ignore_ascii_case(name))
+    }
+
+    /// Each server the latest version of `entry` requires, with whether
+    /// it is configured.
+    pub fn check(&self, entry: &SkillEntry) -> Vec<(String, bool)> {
+        required_servers(entry)
+            .iter()
+            .map(|s| (s.clone(), self.has_server(s)))
+            .collect()
+    }
+
+    /// Servers the latest version of `entry` requires that aren't configured.
+    pub fn missing_for(&self, entry: &SkillEntry) -> Vec<String> {
+        self.check(entry)
+            .into_iter()
+            .filter(|(_, configured)| !configured)
+            .map(|(s, _)| s)
+            .collect()
+    }
+}
+
+/// Format `check` results as "github (configured), sentry (not configured)".
+pub fn format_check(check: &[(String, bool)]) -> String {
+    check
+        .iter()
+        .map(|(s, ok)| {
+            if *ok {
+                format!("{s} (configured)")
+            } else {
+                format!("{s} (not configured)")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The MCP servers the latest version of `entry` requires.
+pub fn required_servers(entry: &SkillEntry) -> &[String] {
+    entry
+        .latest()
+        .and_then(|v| v.metadata.skill.compatibility.as_ref())
+        .map(|c| c.required_mcp_servers.as_slice())
+        .unwrap_or_default()
+}
+
+/// Server names under `key`, plus `projects.<project>.<key>` when given.
+fn parse_servers(raw: &str, key: &str, project: Option<&Path>) -> Result<BTreeSet<String>, String> {
+    let json: serde_json::Value = serde_json::from_str(raw).map_err(|e| e.to_string())?;
+    let names = |v: Option<&serde_json::Value>| {
+        v.and_then(|v| v.as_object())
+            .map(|m| m.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default()
+    };
+
+    let mut servers: BTreeSet<String> = names(json.get(key)).into_iter().collect();
+    if let Some(project) = project {
+        let scoped = json
+            .get("projects")
+            .and_then(|p| p.get(project.to_string_lossy().as_ref()))
+            .and_then(|p| p.get(key));
+        servers.extend(names(scoped));
+    }
+    Ok(servers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, content: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_discovers_project_and_user_configs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let project = tmp.path().join("project");
+        let home = tmp.path().join("home");
+        write(
+            &project.join(".mcp.json"),
+            r#"{"mcpServers": {"github": {"command": "gh-mcp"}}}"#,
+        );
+        write(
+            &project.join(".vscode/mcp.json"),
+            r#"{"servers": {"postgres": {}}}"#,
+        );
+        let abs = project.canonicalize().unwrap();
+        write(
+            &home.join(".claude.json"),
+            &serde_json::json!({
+                "mcpServers": { "memory": {} },
+                "projects": { abs.to_string_lossy(): { "mcpServers": { "sentry": {} } } }
+            })
+            .to_string(),
+        );
+
+        let inventory = McpInventory::discover_in(&project, Some(&home));
+        assert_eq!(inventory.configs.len(), 3);
+        assert!(inventory.errors.is_empty());
+        assert_eq!(
+            inventory.servers(),
+            BTreeSet::from(["github", "memory", "postgres", "sentry"])
+        );
+        assert!(inventory.has_server("GitHub"));
+    }
+
+    #[test]
+    fn test_malformed_config_is_reported() {
+        let tmp = tempfile::tempdir().unwrap();
+        write(&tmp.path().join(".mcp.json"), "{not json");
+
+        let inventory = McpInventory::discover_in(tmp.path(), None);
+        assert!(inventory.configs.is_empty());
+        assert_eq!(inventory.errors.len(), 1);
+        assert!(inventory.errors[0].0.ends_with(".mcp.json"));
+    }
+
+    #[test]
+    fn test_no_configs() {
+        let tmp = tempfile::tempdir().unwrap();
+        let inventory = McpInventory::discover_in(tmp.path(), Some(tmp.path()));
+        assert!(inventory.configs.is_empty());
+        assert!(!inventory.has_server("github"));
+    }
+}
@@ -0,0 +1,120 @@
+//! Tower middleware around the MCP router.
+//!
+//! [`ClientProfileLayer`] records the client name and capabilities sent in
+//! `initialize` as the session's [`AgentProfile`], runs every request with
+//! that profile as [`compat::current`] so tools can check skills against it,
+//! and drops incompatible skills from `prompts/list` in `hide` mode.
+
+use std::collections::{BTreeSet, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use tower::{Layer, Service};
+use tower_mcp::protocol::{ClientCapabilities, McpRequest, McpResponse};
+use tower_mcp::router::{RouterRequest, RouterResponse};
+use tower_mcp::{McpRouter, SessionState};
+
+use skillet_mcp::compat::{self, AgentProfile};
+use skillet_mcp::config::CompatibilityMode;
+use skillet_mcp::state::AppState;
+
+/// Layer that applies [`ClientProfileService`] to each session's router.
+#[derive(Clone)]
+pub struct ClientProfileLayer {
+    state: Arc<AppState>,
+}
+
+impl ClientProfileLayer {
+    pub fn new(state: Arc<AppState>) -> Self {
+        Self { state }
+    }
+}
+
+impl Layer<McpRouter> for ClientProfileLayer {
+    type Service = ClientProfileService;
+
+    fn layer(&self, router: McpRouter) -> Self::Service {
+        ClientProfileService {
+            session: router.session().clone(),
+            inner: router,
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+/// See [`ClientProfileLayer`].
+#[derive(Clone)]
+pub struct ClientProfileService {
+    inner: McpRouter,
+    session: SessionState,
+    state: Arc<AppState>,
+}
+
+impl Service<RouterRequest> for ClientProfileService {
+    type Response = RouterResponse;
+    type Error = std::convert::Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<RouterResponse, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: RouterRequest) -> Self::Future {
+        let agent_config = &self.state.cli_config.agent;
+
+        if let McpRequest::Initialize(ref params) = req.inner {
+            let mut profile =
+                AgentProfile::from_config(agent_config, Some(&params.client_info.name));
+            profile.client_capabilities = Some(capability_names(&params.capabilities));
+            tracing::debug!(?profile, "Recorded client profile");
+            self.session.insert(profile);
+        }
+
+        let profile = self
+            .session
+            .get::<AgentProfile>()
+            .unwrap_or_else(|| AgentProfile::from_config(agent_config, None));
+        let filter_prompts = matches!(req.inner, McpRequest::ListPrompts(_))
+            && agent_config.compatibility == CompatibilityMode::Hide;
+        let state = Arc::clone(&self.state);
+        let fut = self.inner.call(req);
+
+        Box::pin(async move {
+            let mut resp = compat::scope(profile.clone(), fut).await?;
+            if filter_prompts && let Ok(McpResponse::ListPrompts(ref mut result)) = resp.inner {
+                let index = state.index.read().await;
+                let hidden: HashSet<String> = index
+                    .skills
+                    .iter()
+                    .filter(|(_, entry)| !profile.check_entry(entry).is_empty())
+                    .map(|((owner, name), _)| skillet_mcp::state::prompt_name(owner, name))
+                    .collect();
+                result.prompts.retain(|p| !hidden.contains(&p.name));
+            }
+            Ok(resp)
+        })
+    }
+}
+
+/// Names of the capabilities a client declared, including experimental and
+/// extension keys.
+fn capability_names(caps: &ClientCapabilities) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    let standard = [
+        ("roots", caps.roots.is_some()),
+        ("sampling", caps.sampling.is_some()),
+        ("elicitation", caps.elicitation.is_some()),
+        ("tasks", caps.tasks.is_some()),
+    ];
+    for (name, present) in standard {
+        if present {
+            names.insert(name.to_string());
+        }
+    }
+    for map in [&caps.experimental, &caps.extensions].into_iter().flatten() {
+        names.extend(map.keys().cloned());
+    }
+    names
+}
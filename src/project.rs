@@ -10,6 +10,8 @@ use std::path::{Path, PathBuf};
 use serde::Deserialize;
 
 use crate::error::Error;
+use crate::index::FileLimits;
+use crate::state::{DiagnosticKind, LoadDiagnostic};
 
 /// Top-level manifest parsed from `skillet.toml`.
 ///
@@ -151,6 +153,11 @@ pub struct SkillSection {
     /// Path to SKILL.md relative to project root (defaults to ".")
     #[serde(default)]
     pub path: Option<String>,
+
+    /// Which files ship with the skill (defaults to SKILL.md frontmatter,
+    /// then the standard skillpack folders)
+    #[serde(default)]
+    pub files: Option<crate::state::FilePatterns>,
 }
 
 /// Multiple skills directory configuration.
@@ -240,6 +247,76 @@ pub fn find_skillet_toml(start: &Path) -> Option<PathBuf> {
     }
 }
 
+/// The project a command runs for: the directory holding the nearest
+/// `skillet.toml` above the working directory, or the working directory
+/// if there is none.
+///
+/// The server and CLI both use this, so MCP client configs (`.mcp.json`
+/// and similar) are found the same way wherever they're started.
+pub fn resolve_project_root() -> PathBuf {
+    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    find_skillet_toml(&cwd).unwrap_or(cwd)
+}
+
+/// A project's agent instructions, loaded from `[project].agents_md`.
+///
+/// Served as a dedicated MCP prompt and resource so an agent connecting
+/// from inside the project sees its conventions without searching.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectContext {
+    /// Project name (from `[project].name`, else the directory name)
+    pub project: String,
+    /// Resolved path to the AGENTS.md file
+    pub path: PathBuf,
+    /// File contents
+    pub content: String,
+}
+
+/// Resolve `[project].agents_md` against the project root.
+///
+/// Returns `None` if the manifest doesn't set it.
+pub fn agents_md_path(project_root: &Path, manifest: &SkilletToml) -> Option<PathBuf> {
+    let rel = manifest.project.as_ref()?.agents_md.as_deref()?;
+    Some(project_root.join(rel))
+}
+
+/// Load the AGENTS.md file named by `[project].agents_md`.
+///
+/// Returns `Ok(None)` if the manifest doesn't set it, `Err` if it is set
+/// but the file can't be read.
+pub fn load_project_context(
+    project_root: &Path,
+    manifest: &SkilletToml,
+) -> crate::error::Result<Option<ProjectContext>> {
+    let Some(path) = agents_md_path(project_root, manifest) else {
+        return Ok(None);
+    };
+    let content = std::fs::read_to_string(&path).map_err(|e| Error::FileRead {
+        path: path.clone(),
+        source: e,
+    })?;
+    Ok(Some(ProjectContext {
+        project: project_name(project_root, manifest),
+        path,
+        content,
+    }))
+}
+
+/// Project name from `[project].name`, falling back to the directory name.
+fn project_name(project_root: &Path, manifest: &SkilletToml) -> String {
+    manifest
+        .project
+        .as_ref()
+        .and_then(|p| p.name.clone())
+        .or_else(|| {
+            project_root
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|s| s.to_string())
+        })
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
 /// Parsed YAML frontmatter fields from a SKILL.md file.
 ///
 /// npm-style skill repos (redis/agent-skills, anthropics/skills, etc.)
@@ -255,6 +332,13 @@ pub struct Frontmatter {
     pub trigger: Option<String>,
     pub tags: Vec<String>,
     pub categories: Vec<String>,
+    pub language: Option<String>,
+    pub files: Option<crate::state::FilePatterns>,
+    /// `allowed-tools`: a space-separated string or a list
+    pub allowed_tools: Option<Vec<String>>,
+    pub model: Option<String>,
+    /// Every field as written, including unmodeled and `metadata:` keys
+    pub fields: std::collections::BTreeMap<String, serde_json::Value>,
 }
 
 /// Parse YAML frontmatter from SKILL.md content.
@@ -277,20 +361,8 @@ pub struct Frontmatter {
 ///
 /// Returns `None` if the content doesn't start with `---` frontmatter.
 pub fn parse_frontmatter(skill_md: &str) -> Option<Frontmatter> {
-    let trimmed = skill_md.trim_start();
-    if !trimmed.starts_with("---") {
-        return None;
-    }
-
-    // Find the closing ---
-    let after_open = &trimmed[3..];
-    let rest = after_open.trim_start_matches(['\r', '\n']);
-    let end = rest.find("\n---")?;
-    let yaml_str = &rest[..end];
-
-    // Parse with serde_yaml into a generic Value for flexible extraction
-    let yaml: serde_yaml::Value = serde_yaml::from_str(yaml_str).ok()?;
-    let map = yaml.as_mapping()?;
+    let yaml = frontmatter_yaml(skill_md)?;
+    let map = &yaml;
 
     let mut fm = Frontmatter::default();
 
@@ -338,6 +410,49 @@ pub fn parse_frontmatter(skill_md: &str) -> Option<Frontmatter> {
         fm.trigger = Some(v.trim().to_string());
     }
 
+    // Language: top-level, or nested in metadata
+    fm.language = map
+        .get("language")
+        .or_else(|| map.get("metadata").and_then(|m| m.get("language")))
+        .and_then(|v| v.as_str())
+        .map(|v| v.trim().to_string());
+
+    // Files: a list of include globs, or {include, exclude}
+    fm.files = map
+        .get("files")
+        .or_else(|| map.get("metadata").and_then(|m| m.get("files")))
+        .and_then(|v| match v {
+            serde_yaml::Value::Mapping(m) => Some(crate::state::FilePatterns {
+                include: extract_string_list(m.get("include")).unwrap_or_default(),
+                exclude: extract_string_list(m.get("exclude")).unwrap_or_default(),
+            }),
+            other => extract_string_list(Some(other)).map(|include| crate::state::FilePatterns {
+                include,
+                exclude: Vec::new(),
+            }),
+        });
+
+    // allowed-tools: "Read Grep Bash(git:*)" per the spec, or a YAML list
+    fm.allowed_tools = match map.get("allowed-tools") {
+        Some(serde_yaml::Value::String(s)) => {
+            Some(s.split_whitespace().map(str::to_string).collect())
+        }
+        other => extract_string_list(other),
+    }
+    .filter(|tools: &Vec<String>| !tools.is_empty());
+
+    fm.model = map
+        .get("model")
+        .and_then(|v| v.as_str())
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
+
+    // Keep everything, for custom metadata and client-specific fields
+    fm.fields = map
+        .iter()
+        .filter_map(|(k, v)| Some((k.as_str()?.to_string(), serde_json::to_value(v).ok()?)))
+        .collect();
+
     // Tags: top-level, or nested in metadata
     fm.tags = extract_string_list(map.get("tags"))
         .or_else(|| {
@@ -359,6 +474,26 @@ pub fn parse_frontmatter(skill_md: &str) -> Option<Frontmatter> {
     Some(fm)
 }
 
+/// Parse the YAML frontmatter block of a SKILL.md into a mapping.
+fn frontmatter_yaml(skill_md: &str) -> Option<serde_yaml::Mapping> {
+    let trimmed = skill_md.trim_start();
+    if !trimmed.starts_with("---") {
+        return None;
+    }
+
+    // Find the closing ---
+    let after_open = &trimmed[3..];
+    let rest = after_open.trim_start_matches(['\r', '\n']);
+    let end = rest.find("\n---")?;
+    let yaml_str = &rest[..end];
+
+    // Parse with serde_yaml into a generic Value for flexible extraction
+    match serde_yaml::from_str(yaml_str).ok()? {
+        serde_yaml::Value::Mapping(map) => Some(map),
+        _ => None,
+    }
+}
+
 /// Extract tags from a YAML value (handles both inline arrays and list-style).
 fn extract_string_list(value: Option<&serde_yaml::Value>) -> Option<Vec<String>> {
     let v = value?;
@@ -485,7 +620,13 @@ pub fn infer_metadata(
             author,
             classification,
             compatibility: None,
+            language: frontmatter.as_ref().and_then(|fm| fm.language.clone()),
+            files: frontmatter.as_ref().and_then(|fm| fm.files.clone()),
+            activation: None,
+            allowed_tools: frontmatter.as_ref().and_then(|fm| fm.allowed_tools.clone()),
+            model: frontmatter.as_ref().and_then(|fm| fm.model.clone()),
         },
+        frontmatter: frontmatter.map(|fm| fm.fields).unwrap_or_default(),
     }
 }
 
@@ -553,6 +694,12 @@ pub(crate) fn owner_from_git_remote(dir: &Path) -> Option<String> {
 /// Takes the first non-empty line that doesn't start with `#` or `---`.
 /// Truncates to 200 characters. Falls back to `"Embedded skill"`.
 fn extract_description(skill_md: &str) -> String {
+    first_paragraph(skill_md).unwrap_or_else(|| "Embedded skill".to_string())
+}
+
+/// First line of body text (skipping frontmatter and headings), capped at
+/// 200 characters.
+pub(crate) fn first_paragraph(skill_md: &str) -> Option<String> {
     let mut in_frontmatter = false;
     for line in skill_md.lines() {
         let trimmed = line.trim();
@@ -567,12 +714,12 @@ fn extract_description(skill_md: &str) -> String {
             continue;
         }
         let max = 200;
-        return match trimmed.char_indices().nth(max) {
+        return Some(match trimmed.char_indices().nth(max) {
             Some((idx, _)) => trimmed[..idx].to_string(),
             None => trimmed.to_string(),
-        };
+        });
     }
-    "Embedded skill".to_string()
+    None
 }
 
 /// Load embedded skills from a project with a `skillet.toml` manifest.
@@ -587,20 +734,11 @@ fn extract_description(skill_md: &str) -> String {
 pub fn load_embedded_skills(
     project_root: &Path,
     manifest: &SkilletToml,
+    limits: &FileLimits,
 ) -> crate::state::SkillIndex {
     let mut index = crate::state::SkillIndex::default();
 
-    let project_name = manifest
-        .project
-        .as_ref()
-        .and_then(|p| p.name.clone())
-        .or_else(|| {
-            project_root
-                .file_name()
-                .and_then(|n| n.to_str())
-                .map(|s| s.to_string())
-        })
-        .unwrap_or_else(|| "unknown".to_string());
+    let project_name = project_name(project_root, manifest);
 
     // Handle [skill] section: single inline skill
     if let Some(ref skill_section) = manifest.skill {
@@ -609,7 +747,7 @@ pub fn load_embedded_skills(
             None => project_root.to_path_buf(),
         };
 
-        match build_embedded_entry(&skill_path, skill_section, manifest, &project_name) {
+        match build_embedded_entry(&skill_path, skill_section, manifest, &project_name, limits) {
             Ok(entry) => {
                 let key = (entry.owner.clone(), entry.name.clone());
                 tracing::debug!(
@@ -625,6 +763,11 @@ pub fn load_embedded_skills(
                     error = %e,
                     "Failed to load embedded inline skill"
                 );
+                index.diagnostics.push(LoadDiagnostic::error(
+                    DiagnosticKind::InvalidSkill,
+                    &skill_path,
+                    e.to_string(),
+                ));
             }
         }
     }
@@ -638,6 +781,7 @@ pub fn load_embedded_skills(
                 skills_section,
                 manifest,
                 &project_name,
+                limits,
                 &mut index,
             );
         } else {
@@ -657,6 +801,7 @@ fn build_embedded_entry(
     skill_section: &SkillSection,
     manifest: &SkilletToml,
     project_name: &str,
+    limits: &FileLimits,
 ) -> anyhow::Result<crate::state::SkillEntry> {
     let md_path = skill_path.join("SKILL.md");
     let skill_md = std::fs::read_to_string(&md_path)?;
@@ -712,7 +857,13 @@ fn build_embedded_entry(
         None
     };
 
-    let files = crate::index::load_extra_files(skill_path).unwrap_or_default();
+    let frontmatter = parse_frontmatter(&skill_md);
+    let file_patterns = skill_section
+        .files
+        .clone()
+        .or_else(|| frontmatter.as_ref().and_then(|fm| fm.files.clone()));
+    let files = crate::index::load_extra_files(skill_path, file_patterns.as_ref(), limits)
+        .unwrap_or_default();
 
     let skill_toml_raw = String::new();
     let metadata = crate::state::SkillMetadata {
@@ -726,7 +877,13 @@ fn build_embedded_entry(
             author: None,
             classification,
             compatibility: None,
+            language: None,
+            files: file_patterns,
+            activation: None,
+            allowed_tools: frontmatter.as_ref().and_then(|fm| fm.allowed_tools.clone()),
+            model: frontmatter.as_ref().and_then(|fm| fm.model.clone()),
         },
+        frontmatter: frontmatter.map(|fm| fm.fields).unwrap_or_default(),
     };
 
     Ok(crate::state::SkillEntry {
@@ -749,6 +906,7 @@ fn build_embedded_entry(
         }],
         trust_tier: Default::default(),
         discovered_via: Vec::new(),
+        origin: None,
     })
 }
 
@@ -757,6 +915,7 @@ fn build_embedded_entry_from_dir(
     skill_dir: &Path,
     manifest: &SkilletToml,
     project_name: &str,
+    limits: &FileLimits,
 ) -> anyhow::Result<crate::state::SkillEntry> {
     let md_path = skill_dir.join("SKILL.md");
     let skill_md = std::fs::read_to_string(&md_path)?;
@@ -825,7 +984,9 @@ fn build_embedded_entry_from_dir(
     // Read skill.toml if present for richer metadata
     let skill_toml_raw = std::fs::read_to_string(skill_dir.join("skill.toml")).unwrap_or_default();
 
-    let files = crate::index::load_extra_files(skill_dir).unwrap_or_default();
+    let file_patterns = frontmatter.as_ref().and_then(|fm| fm.files.clone());
+    let files = crate::index::load_extra_files(skill_dir, file_patterns.as_ref(), limits)
+        .unwrap_or_default();
 
     let metadata = crate::state::SkillMetadata {
         skill: crate::state::SkillInfo {
@@ -838,7 +999,13 @@ fn build_embedded_entry_from_dir(
             author,
             classification,
             compatibility: None,
+            language: None,
+            files: file_patterns,
+            activation: None,
+            allowed_tools: frontmatter.as_ref().and_then(|fm| fm.allowed_tools.clone()),
+            model: frontmatter.as_ref().and_then(|fm| fm.model.clone()),
         },
+        frontmatter: frontmatter.map(|fm| fm.fields).unwrap_or_default(),
     };
 
     Ok(crate::state::SkillEntry {
@@ -861,6 +1028,7 @@ fn build_embedded_entry_from_dir(
         }],
         trust_tier: Default::default(),
         discovered_via: Vec::new(),
+        origin: None,
     })
 }
 
@@ -870,6 +1038,7 @@ fn load_skills_dir(
     skills_section: &SkillsSection,
     manifest: &SkilletToml,
     project_name: &str,
+    limits: &FileLimits,
     index: &mut crate::state::SkillIndex,
 ) {
     let entries = match std::fs::read_dir(skills_dir) {
@@ -880,6 +1049,11 @@ fn load_skills_dir(
                 error = %e,
                 "Cannot read skills directory"
             );
+            index.diagnostics.push(LoadDiagnostic::error(
+                DiagnosticKind::RepoUnreadable,
+                skills_dir,
+                format!("can't read skills directory: {e}"),
+            ));
             return;
         }
     };
@@ -908,9 +1082,17 @@ fn load_skills_dir(
             continue;
         }
 
-        match build_embedded_entry_from_dir(&path, manifest, project_name) {
+        match build_embedded_entry_from_dir(&path, manifest, project_name, limits) {
             Ok(entry) => {
                 let key = (entry.owner.clone(), entry.name.clone());
+                if index.skills.contains_key(&key) {
+                    index.diagnostics.push(LoadDiagnostic::warning(
+                        DiagnosticKind::DuplicateSkill,
+                        &path,
+                        format!("{}/{} is already loaded; keeping the first", key.0, key.1),
+                    ));
+                    continue;
+                }
                 tracing::debug!(
                     skill = %entry.name,
                     project = %project_name,
@@ -924,6 +1106,11 @@ fn load_skills_dir(
                     error = %e,
                     "Failed to load embedded skill"
                 );
+                index.diagnostics.push(LoadDiagnostic::error(
+                    DiagnosticKind::InvalidSkill,
+                    &path,
+                    e.to_string(),
+                ));
             }
         }
     }
@@ -1030,6 +1217,41 @@ members = ["api-usage", "debugging"]
         assert!(found.is_none());
     }
 
+    #[test]
+    fn test_load_project_context() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("skillet.toml"),
+            "[project]\nname = \"acme\"\nagents_md = \"docs/AGENTS.md\"\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(tmp.path().join("docs")).unwrap();
+        std::fs::write(tmp.path().join("docs/AGENTS.md"), "# Conventions\n").unwrap();
+
+        let manifest = load_skillet_toml(tmp.path()).unwrap().unwrap();
+        let ctx = load_project_context(tmp.path(), &manifest)
+            .unwrap()
+            .unwrap();
+        assert_eq!(ctx.project, "acme");
+        assert_eq!(ctx.path, tmp.path().join("docs/AGENTS.md"));
+        assert_eq!(ctx.content, "# Conventions\n");
+    }
+
+    #[test]
+    fn test_load_project_context_unset_or_missing() {
+        let tmp = tempfile::tempdir().unwrap();
+        let manifest: SkilletToml = toml::from_str("[project]\nname = \"acme\"\n").unwrap();
+        assert!(
+            load_project_context(tmp.path(), &manifest)
+                .unwrap()
+                .is_none()
+        );
+
+        let manifest: SkilletToml =
+            toml::from_str("[project]\nagents_md = \"AGENTS.md\"\n").unwrap();
+        assert!(load_project_context(tmp.path(), &manifest).is_err());
+    }
+
     #[test]
     fn test_skills_section_default_path() {
         let section = SkillsSection::default();
@@ -1168,7 +1390,7 @@ description = "How to use my-cli"
         .unwrap();
 
         let manifest = load_skillet_toml(root).unwrap().unwrap();
-        let index = load_embedded_skills(root, &manifest);
+        let index = load_embedded_skills(root, &manifest, &FileLimits::default());
 
         assert_eq!(index.skills.len(), 1);
         let entry = index
@@ -1220,7 +1442,7 @@ path = ".skillet"
         std::fs::write(skill2.join("SKILL.md"), "# Debugging\n\nHow to debug.\n").unwrap();
 
         let manifest = load_skillet_toml(root).unwrap().unwrap();
-        let index = load_embedded_skills(root, &manifest);
+        let index = load_embedded_skills(root, &manifest, &FileLimits::default());
 
         assert_eq!(index.skills.len(), 2);
         assert!(
@@ -1235,6 +1457,70 @@ path = ".skillet"
         );
     }
 
+    #[test]
+    fn test_load_embedded_skills_honor_file_patterns() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+
+        std::fs::write(
+            root.join("skillet.toml"),
+            r#"
+[project]
+name = "patterns"
+
+[[project.authors]]
+github = "dev"
+
+[skill]
+name = "inline"
+
+[skill.files]
+include = ["docs"]
+
+[skills]
+path = "skills"
+"#,
+        )
+        .unwrap();
+        std::fs::write(root.join("SKILL.md"), "# Inline\n\nInline skill.\n").unwrap();
+        std::fs::create_dir_all(root.join("docs")).unwrap();
+        std::fs::write(root.join("docs/usage.md"), "# Usage\n").unwrap();
+
+        let member = root.join("skills/member");
+        std::fs::create_dir_all(member.join("examples")).unwrap();
+        std::fs::create_dir_all(member.join("scripts")).unwrap();
+        std::fs::write(
+            member.join("SKILL.md"),
+            "---\nname: member\nfiles:\n  - examples\n---\n\n# Member\n",
+        )
+        .unwrap();
+        std::fs::write(member.join("examples/demo.md"), "demo\n").unwrap();
+        std::fs::write(member.join("scripts/run.sh"), "echo run\n").unwrap();
+
+        let manifest = load_skillet_toml(root).unwrap().unwrap();
+        let index = load_embedded_skills(root, &manifest, &FileLimits::default());
+        let files = |name: &str| {
+            let entry = &index.skills[&("dev".to_string(), name.to_string())];
+            let mut files: Vec<String> = entry.latest().unwrap().files.keys().cloned().collect();
+            files.sort();
+            files
+        };
+
+        assert_eq!(files("inline"), vec!["docs/usage.md"]);
+        assert_eq!(files("member"), vec!["examples/demo.md"]);
+    }
+
+    #[test]
+    fn test_parse_frontmatter_files() {
+        let fm = parse_frontmatter(
+            "---\nname: x\nfiles:\n  include: [examples, \"*.md\"]\n  exclude: [\"*.tmp\"]\n---\n",
+        )
+        .unwrap();
+        let files = fm.files.unwrap();
+        assert_eq!(files.include, vec!["examples", "*.md"]);
+        assert_eq!(files.exclude, vec!["*.tmp"]);
+    }
+
     #[test]
     fn test_load_embedded_multi_skill_with_members_filter() {
         let tmp = tempfile::tempdir().unwrap();
@@ -1265,7 +1551,7 @@ members = ["included"]
         std::fs::write(excluded.join("SKILL.md"), "# Excluded\n\nNo.\n").unwrap();
 
         let manifest = load_skillet_toml(root).unwrap().unwrap();
-        let index = load_embedded_skills(root, &manifest);
+        let index = load_embedded_skills(root, &manifest, &FileLimits::default());
 
         assert_eq!(index.skills.len(), 1);
         assert!(
@@ -1308,7 +1594,7 @@ path = ".skillet"
         std::fs::write(extra.join("SKILL.md"), "# Extra\n\nExtra skill.\n").unwrap();
 
         let manifest = load_skillet_toml(root).unwrap().unwrap();
-        let index = load_embedded_skills(root, &manifest);
+        let index = load_embedded_skills(root, &manifest, &FileLimits::default());
 
         assert_eq!(index.skills.len(), 2);
         assert!(
@@ -1416,6 +1702,67 @@ url = "https://github.com/owner/repo-c.git"
         assert_eq!(fm.tags, vec!["caching", "redis", "performance"]);
     }
 
+    #[test]
+    fn test_parse_frontmatter_language() {
+        let md = "---\nname: nihongo\nlanguage: ja\n---\n";
+        let fm = parse_frontmatter(md).expect("should parse frontmatter");
+        assert_eq!(fm.language.as_deref(), Some("ja"));
+
+        let md = "---\nname: nested\nmetadata:\n  language: de\n---\n";
+        let fm = parse_frontmatter(md).expect("should parse frontmatter");
+        assert_eq!(fm.language.as_deref(), Some("de"));
+    }
+
+    #[test]
+    fn test_infer_metadata_keeps_full_frontmatter() {
+        let md = "---\nname: x\nmodel: sonnet\nallowed-tools: Read Grep Bash(git:*)\n\
+                  audience: backend\nmetadata:\n  keywords:\n    - queue\n    - broker\n  \
+                  priority: 2\n  tags: [a]\n---\n";
+        let meta = infer_metadata(Path::new("/tmp/acme/x"), md, None);
+        assert_eq!(meta.skill.model.as_deref(), Some("sonnet"));
+        assert_eq!(
+            meta.skill.allowed_tools,
+            Some(vec![
+                "Read".to_string(),
+                "Grep".to_string(),
+                "Bash(git:*)".to_string()
+            ])
+        );
+        assert_eq!(meta.frontmatter_text("model").as_deref(), Some("sonnet"));
+        assert_eq!(
+            meta.frontmatter_text("keywords").as_deref(),
+            Some("queue broker")
+        );
+        assert_eq!(meta.frontmatter_text("priority").as_deref(), Some("2"));
+        assert_eq!(meta.frontmatter_text("missing"), None);
+        assert_eq!(
+            meta.custom_metadata(),
+            vec![
+                ("audience".to_string(), "backend".to_string()),
+                ("keywords".to_string(), "queue broker".to_string()),
+                ("priority".to_string(), "2".to_string()),
+            ]
+        );
+        assert!(meta.frontmatter_matches("keywords", "Broker"));
+        assert!(meta.frontmatter_matches("priority", "2"));
+        assert!(!meta.frontmatter_matches("audience", "frontend"));
+
+        let meta = infer_metadata(Path::new("/tmp/acme/x"), "# no frontmatter", None);
+        assert!(meta.frontmatter.is_empty());
+        assert_eq!(meta.frontmatter_text("model"), None);
+    }
+
+    #[test]
+    fn test_parse_frontmatter_allowed_tools_list() {
+        let md = "---\nallowed-tools:\n  - Read\n  - Write\n---\n";
+        let fm = parse_frontmatter(md).unwrap();
+        assert_eq!(
+            fm.allowed_tools,
+            Some(vec!["Read".to_string(), "Write".to_string()])
+        );
+        assert!(fm.model.is_none());
+    }
+
     #[test]
     fn test_parse_frontmatter_quoted_values() {
         let md = "---\nname: \"quoted-skill\"\ndescription: 'single quoted'\n---\n";
@@ -2,33 +2,42 @@
 //!
 //! Each skill in the index becomes an MCP prompt, namespaced as `owner_skill-name`.
 //! Prompts support an optional `section` argument for filtering by heading.
+//! SKILL.md include directives (see [`crate::include`]) are expanded here.
 //! On index refresh, stale prompts are unregistered and new ones registered,
 //! with a `prompts/list_changed` notification emitted automatically.
+//!
+//! A project's `[project].agents_md` file is served alongside the skills as
+//! the `project-context` prompt and `skillet://project/AGENTS.md` resource.
 
 use std::collections::HashMap;
 
-use tower_mcp::PromptBuilder;
 use tower_mcp::protocol::{Content, GetPromptResult, PromptMessage, PromptRole};
-use tower_mcp::registry::DynamicPromptRegistry;
+use tower_mcp::registry::{DynamicPromptRegistry, DynamicResourceRegistry};
+use tower_mcp::{PromptBuilder, ResourceBuilder};
 
-use crate::state::SkillIndex;
+use crate::include;
+use crate::project::ProjectContext;
+use crate::state::{SkillIndex, prompt_name};
 
 /// Register all skills from the index as MCP prompts.
 ///
-/// Prompt names are namespaced as `owner_skill-name` to avoid collisions
+/// Prompt names are namespaced as `owner_skill-name` (or
+/// `alias__owner_skill-name` for aliased repos) to avoid collisions
 /// across repos. Each prompt accepts an optional `section` argument
 /// to return only a specific section (by heading) of the SKILL.md.
+/// Include directives are expanded against the index; skills that use
+/// them also take a `raw` argument to get the unexpanded text.
 pub fn register_all(registry: &DynamicPromptRegistry, index: &SkillIndex) {
     for ((owner, name), entry) in &index.skills {
         let Some(latest) = entry.latest() else {
             continue;
         };
 
-        let prompt_name = format!("{owner}_{name}");
+        let prompt_name = prompt_name(owner, name);
         let description = latest.metadata.skill.description.clone();
-        let content = latest.skill_md.clone();
+        let raw = latest.skill_md.clone();
 
-        if content.is_empty() {
+        if raw.is_empty() {
             tracing::debug!(
                 prompt = %prompt_name,
                 "Skipping prompt with empty SKILL.md"
@@ -36,24 +45,36 @@ pub fn register_all(registry: &DynamicPromptRegistry, index: &SkillIndex) {
             continue;
         }
 
-        let prompt = PromptBuilder::new(&prompt_name)
+        let has_includes = include::has_includes(&raw);
+        let content = if has_includes {
+            let expansion = include::expand(entry, index);
+            for problem in &expansion.problems {
+                tracing::warn!(prompt = %prompt_name, %problem, "Unresolved include");
+            }
+            expansion.text
+        } else {
+            raw.clone()
+        };
+
+        let mut builder = PromptBuilder::new(&prompt_name)
             .description(&description)
-            .optional_arg("section", "Return only a specific section (by heading)")
+            .optional_arg("section", "Return only a specific section (by heading)");
+        if has_includes {
+            builder = builder.optional_arg(
+                "raw",
+                "Set to \"true\" to return SKILL.md without expanding includes",
+            );
+        }
+        let prompt = builder
             .handler(move |args: HashMap<String, String>| {
-                let content = content.clone();
+                let content = if args.get("raw").is_some_and(|v| v == "true") {
+                    raw.clone()
+                } else {
+                    content.clone()
+                };
                 let description = description.clone();
                 async move {
-                    let text = if let Some(section) = args.get("section") {
-                        extract_section(&content, section).unwrap_or_else(|| {
-                            format!(
-                                "Section '{section}' not found. Available sections:\n{}",
-                                list_sections(&content)
-                            )
-                        })
-                    } else {
-                        content
-                    };
-
+                    let text = select_section(content, &args);
                     Ok(GetPromptResult {
                         description: Some(description),
                         messages: vec![PromptMessage {
@@ -73,11 +94,88 @@ pub fn register_all(registry: &DynamicPromptRegistry, index: &SkillIndex) {
     }
 }
 
+/// Name of the prompt serving the project's AGENTS.md.
+///
+/// Skill prompt names always contain an underscore, so this can't collide.
+pub const PROJECT_CONTEXT_PROMPT: &str = "project-context";
+
+/// URI of the resource serving the project's AGENTS.md.
+pub const PROJECT_CONTEXT_URI: &str = "skillet://project/AGENTS.md";
+
+/// Register (or replace) the project context prompt and resource.
+///
+/// With `None`, any previously registered project context is removed, so
+/// calling this after a reload keeps both registries in step with disk.
+pub fn sync_project_context(
+    prompts: &DynamicPromptRegistry,
+    resources: &DynamicResourceRegistry,
+    context: Option<&ProjectContext>,
+) {
+    let Some(context) = context else {
+        prompts.unregister(PROJECT_CONTEXT_PROMPT);
+        resources.unregister(PROJECT_CONTEXT_URI);
+        return;
+    };
+
+    let description = format!(
+        "Conventions and agent instructions for the {} project (AGENTS.md)",
+        context.project
+    );
+    let content = context.content.clone();
+    let prompt_description = description.clone();
+    let prompt = PromptBuilder::new(PROJECT_CONTEXT_PROMPT)
+        .description(&description)
+        .optional_arg("section", "Return only a specific section (by heading)")
+        .handler(move |args: HashMap<String, String>| {
+            let content = content.clone();
+            let description = prompt_description.clone();
+            async move {
+                Ok(GetPromptResult {
+                    description: Some(description),
+                    messages: vec![PromptMessage {
+                        role: PromptRole::User,
+                        content: Content::text(select_section(content, &args)),
+                        meta: None,
+                    }],
+                    meta: None,
+                })
+            }
+        })
+        .build();
+    prompts.register(prompt);
+
+    let resource = ResourceBuilder::new(PROJECT_CONTEXT_URI)
+        .name("AGENTS.md")
+        .description(description)
+        .mime_type("text/markdown")
+        .text(context.content.clone());
+    resources.register(resource);
+
+    tracing::debug!(
+        project = %context.project,
+        path = %context.path.display(),
+        "Registered project context prompt and resource"
+    );
+}
+
+/// Apply the optional `section` prompt argument to markdown content.
+fn select_section(content: String, args: &HashMap<String, String>) -> String {
+    match args.get("section") {
+        Some(section) => extract_section(&content, section).unwrap_or_else(|| {
+            format!(
+                "Section '{section}' not found. Available sections:\n{}",
+                list_sections(&content)
+            )
+        }),
+        None => content,
+    }
+}
+
 /// Extract a section from markdown by heading.
 ///
 /// Matches headings case-insensitively. Returns the heading and everything
 /// until the next heading at the same or higher level, or end of document.
-fn extract_section(content: &str, section_name: &str) -> Option<String> {
+pub(crate) fn extract_section(content: &str, section_name: &str) -> Option<String> {
     let section_lower = section_name.to_lowercase();
     let lines: Vec<&str> = content.lines().collect();
 
@@ -152,7 +250,7 @@ pub fn sync(registry: &DynamicPromptRegistry, old_index: &SkillIndex, new_index:
             .skills
             .contains_key(&(owner.clone(), name.clone()))
         {
-            let prompt_name = format!("{owner}_{name}");
+            let prompt_name = prompt_name(owner, name);
             if registry.unregister(&prompt_name) {
                 tracing::debug!(prompt = %prompt_name, "Unregistered removed skill prompt");
             }
@@ -176,6 +274,7 @@ mod tests {
             source: SkillSource::Repo,
             trust_tier: Default::default(),
             discovered_via: Vec::new(),
+            origin: None,
             versions: vec![SkillVersion {
                 version: "1.0.0".to_string(),
                 metadata: SkillMetadata {
@@ -189,7 +288,13 @@ mod tests {
                         author: None,
                         classification: None,
                         compatibility: None,
+                        language: None,
+                        files: None,
+                        activation: None,
+                        allowed_tools: None,
+                        model: None,
                     },
+                    frontmatter: Default::default(),
                 },
                 skill_md: content.to_string(),
                 skill_toml_raw: String::new(),
@@ -271,6 +376,27 @@ mod tests {
         sync(&registry, &old_index, &new_index);
     }
 
+    #[test]
+    fn test_sync_project_context_registers_and_removes() {
+        let (router, prompts) = tower_mcp::McpRouter::new()
+            .server_info("test", "0.1.0")
+            .with_dynamic_prompts();
+        let (_router, resources) = router.with_dynamic_resources();
+
+        let context = ProjectContext {
+            project: "acme".to_string(),
+            path: "AGENTS.md".into(),
+            content: "# Conventions\n\nUse tabs.".to_string(),
+        };
+        sync_project_context(&prompts, &resources, Some(&context));
+        assert!(prompts.contains(PROJECT_CONTEXT_PROMPT));
+        assert!(resources.contains(PROJECT_CONTEXT_URI));
+
+        sync_project_context(&prompts, &resources, None);
+        assert!(!prompts.contains(PROJECT_CONTEXT_PROMPT));
+        assert!(!resources.contains(PROJECT_CONTEXT_URI));
+    }
+
     // -- Section extraction --
 
     const SAMPLE_MD: &str = "\
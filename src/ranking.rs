@@ -0,0 +1,289 @@
+//! Re-ranking signals blended into BM25 relevance.
+//!
+//! Text relevance alone lets a transitive skill with thin metadata outrank a
+//! curated one from a directly configured repo. Each skill gets a static
+//! boost from its trust tier, publish recency, `verified_with` entries,
+//! metadata completeness, and repo order; search scores are multiplied by
+//! that boost. Weights come from `[search.ranking]` (see [`RankingConfig`]).
+
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::RankingConfig;
+use crate::state::{SkillEntry, TrustTier};
+
+/// Per-skill ranking signals, each normalized to `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Signals {
+    /// Direct 1.0, suggested 0.5, transitive 0.0.
+    pub trust_tier: f64,
+    /// Halves every `recency_half_life_days`; 0.0 when never published.
+    pub recency: f64,
+    /// 1.0 when the latest version lists any `verified_with` models.
+    pub verified: f64,
+    /// Fraction of trigger, categories, and tags that are present.
+    pub metadata: f64,
+    /// 1.0 for the first configured repo, decreasing with load order.
+    pub repo_priority: f64,
+}
+
+impl Signals {
+    /// Compute signals for a skill.
+    ///
+    /// `repo_paths` is the load order of repos (earlier wins merges, so
+    /// earlier ranks higher). Skills without an origin repo, i.e. embedded
+    /// project skills, get full repo priority. `today` is days since the
+    /// Unix epoch, see [`today`].
+    pub fn for_entry(
+        entry: &SkillEntry,
+        repo_paths: &[PathBuf],
+        half_life_days: f64,
+        today: i64,
+    ) -> Self {
+        let latest = entry.latest();
+        let info = latest.map(|v| &v.metadata.skill);
+
+        let trust_tier = match entry.trust_tier {
+            TrustTier::Direct => 1.0,
+            TrustTier::Suggested => 0.5,
+            TrustTier::Transitive => 0.0,
+        };
+
+        let recency = latest
+            .and_then(|v| v.published.as_deref())
+            .and_then(parse_days)
+            .map(|published| {
+                let age = (today - published).max(0) as f64;
+                if half_life_days > 0.0 {
+                    0.5_f64.powf(age / half_life_days)
+                } else {
+                    0.0
+                }
+            })
+            .unwrap_or(0.0);
+
+        let verified = info
+            .and_then(|i| i.compatibility.as_ref())
+            .is_some_and(|c| !c.verified_with.is_empty());
+
+        let metadata = info
+            .map(|i| {
+                let classification = i.classification.as_ref();
+                let present = [
+                    i.trigger.as_deref().is_some_and(|t| !t.is_empty()),
+                    classification.is_some_and(|c| !c.categories.is_empty()),
+                    classification.is_some_and(|c| !c.tags.is_empty()),
+                ];
+                present.iter().filter(|p| **p).count() as f64 / present.len() as f64
+            })
+            .unwrap_or(0.0);
+
+        let repo_priority = match entry.origin {
+            Some(ref origin) if !repo_paths.is_empty() => repo_paths
+                .iter()
+                .position(|p| p == origin)
+                .map(|pos| 1.0 - pos as f64 / repo_paths.len() as f64)
+                .unwrap_or(0.0),
+            _ => 1.0,
+        };
+
+        Self {
+            trust_tier,
+            recency,
+            verified: if verified { 1.0 } else { 0.0 },
+            metadata,
+            repo_priority,
+        }
+    }
+
+    /// Score multiplier: `1 + sum(weight * signal)`.
+    pub fn boost(&self, config: &RankingConfig) -> f64 {
+        1.0 + config.trust_tier * self.trust_tier
+            + config.recency * self.recency
+            + config.verified * self.verified
+            + config.metadata * self.metadata
+            + config.repo_priority * self.repo_priority
+    }
+}
+
+/// Current date as days since the Unix epoch.
+pub fn today() -> i64 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    (secs / 86_400) as i64
+}
+
+/// Parse the date part of an ISO 8601 timestamp (`YYYY-MM-DD...`) into
+/// days since the Unix epoch.
+fn parse_days(published: &str) -> Option<i64> {
+    let date = published.get(..10)?;
+    let mut parts = date.split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day))
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's
+/// `days_from_civil`).
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = (if y >= 0 { y } else { y - 399 }) / 400;
+    let yoe = y - era * 400;
+    let m = month as i64;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{Classification, Compatibility, SkillInfo, SkillMetadata, SkillVersion};
+    use std::collections::HashMap;
+
+    fn make_entry() -> SkillEntry {
+        SkillEntry {
+            owner: "acme".to_string(),
+            name: "tool".to_string(),
+            repo_path: None,
+            source: Default::default(),
+            trust_tier: Default::default(),
+            discovered_via: Vec::new(),
+            origin: None,
+            versions: vec![SkillVersion {
+                version: "1.0.0".to_string(),
+                metadata: SkillMetadata {
+                    skill: SkillInfo {
+                        name: "tool".to_string(),
+                        owner: "acme".to_string(),
+                        version: "1.0.0".to_string(),
+                        description: "A tool".to_string(),
+                        trigger: None,
+                        license: None,
+                        author: None,
+                        classification: None,
+                        compatibility: None,
+                        language: None,
+                        files: None,
+                        activation: None,
+                        allowed_tools: None,
+                        model: None,
+                    },
+                    frontmatter: Default::default(),
+                },
+                skill_md: String::new(),
+                skill_toml_raw: String::new(),
+                yanked: false,
+                files: HashMap::new(),
+                published: None,
+                has_content: true,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("1970-01-01"), Some(0));
+        assert_eq!(parse_days("2000-03-01T12:00:00Z"), Some(11_017));
+        assert_eq!(parse_days("2024-02-29"), Some(19_782));
+        assert_eq!(parse_days("not a date"), None);
+        assert_eq!(parse_days("2024-13-01"), None);
+    }
+
+    #[test]
+    fn test_trust_tier_signal() {
+        let mut entry = make_entry();
+        let direct = Signals::for_entry(&entry, &[], 180.0, 0);
+        entry.trust_tier = TrustTier::Transitive;
+        let transitive = Signals::for_entry(&entry, &[], 180.0, 0);
+        assert_eq!(direct.trust_tier, 1.0);
+        assert_eq!(transitive.trust_tier, 0.0);
+    }
+
+    #[test]
+    fn test_recency_halves() {
+        let mut entry = make_entry();
+        entry.versions[0].published = Some("2024-01-01T00:00:00Z".to_string());
+        let published = parse_days("2024-01-01").unwrap();
+
+        let fresh = Signals::for_entry(&entry, &[], 30.0, published);
+        let month_old = Signals::for_entry(&entry, &[], 30.0, published + 30);
+        assert_eq!(fresh.recency, 1.0);
+        assert!((month_old.recency - 0.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_metadata_and_verified_signals() {
+        let mut entry = make_entry();
+        let bare = Signals::for_entry(&entry, &[], 180.0, 0);
+        assert_eq!(bare.metadata, 0.0);
+        assert_eq!(bare.verified, 0.0);
+
+        let info = &mut entry.versions[0].metadata.skill;
+        info.trigger = Some("when testing".to_string());
+        info.classification = Some(Classification {
+            categories: vec!["testing".to_string()],
+            tags: Vec::new(),
+        });
+        info.compatibility = Some(Compatibility {
+            requires_tool_use: None,
+            requires_vision: None,
+            min_context_tokens: None,
+            required_capabilities: Vec::new(),
+            required_mcp_servers: Vec::new(),
+            verified_with: vec!["some-model".to_string()],
+        });
+        let rich = Signals::for_entry(&entry, &[], 180.0, 0);
+        assert!((rich.metadata - 2.0 / 3.0).abs() < 1e-10);
+        assert_eq!(rich.verified, 1.0);
+    }
+
+    #[test]
+    fn test_repo_priority_follows_load_order() {
+        let repos = vec![PathBuf::from("/a"), PathBuf::from("/b")];
+        let mut entry = make_entry();
+
+        entry.origin = Some(PathBuf::from("/a"));
+        assert_eq!(
+            Signals::for_entry(&entry, &repos, 180.0, 0).repo_priority,
+            1.0
+        );
+        entry.origin = Some(PathBuf::from("/b"));
+        assert_eq!(
+            Signals::for_entry(&entry, &repos, 180.0, 0).repo_priority,
+            0.5
+        );
+        entry.origin = None;
+        assert_eq!(
+            Signals::for_entry(&entry, &repos, 180.0, 0).repo_priority,
+            1.0
+        );
+    }
+
+    #[test]
+    fn test_boost_uses_weights() {
+        let signals = Signals {
+            trust_tier: 1.0,
+            recency: 0.0,
+            verified: 1.0,
+            metadata: 0.5,
+            repo_priority: 1.0,
+        };
+        let config = RankingConfig {
+            enabled: true,
+            trust_tier: 0.3,
+            recency: 0.1,
+            recency_half_life_days: 180.0,
+            verified: 0.2,
+            metadata: 0.2,
+            repo_priority: 0.0,
+        };
+        assert!((signals.boost(&config) - 1.6).abs() < 1e-10);
+    }
+}
@@ -1,10 +1,13 @@
 //! Repo management: initialization, loading, and utility functions.
 
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
 use std::time::Duration;
 
 use crate::cache::{self, RepoSource};
-use crate::config::SkilletConfig;
+use crate::config::{ImportFormat, ReposConfig, SkilletConfig};
+use crate::conflict::MergePolicy;
 use crate::error::Error;
 use crate::state::SkillIndex;
 use crate::{git, index};
@@ -41,6 +44,130 @@ pub fn parse_duration(s: &str) -> crate::error::Result<Duration> {
     Ok(Duration::from_secs(secs))
 }
 
+/// Parse a human-friendly size string like "512", "64KB", "1MB", or "1GB".
+///
+/// Units are binary multiples (1KB = 1024 bytes) and case-insensitive.
+pub fn parse_size(s: &str) -> crate::error::Result<u64> {
+    let s = s.trim();
+    let (num, suffix) = s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()));
+    let num: u64 = num
+        .parse()
+        .map_err(|_| Error::InvalidSize(format!("invalid number: {s}")))?;
+
+    let multiplier = match suffix.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "KB" | "K" => 1024,
+        "MB" | "M" => 1024 * 1024,
+        "GB" | "G" => 1024 * 1024 * 1024,
+        _ => {
+            return Err(Error::InvalidSize(format!(
+                "unknown suffix: {suffix} (use B, KB, MB, or GB)"
+            )));
+        }
+    };
+
+    num.checked_mul(multiplier)
+        .ok_or_else(|| Error::InvalidSize(format!("too large: {s}")))
+}
+
+/// Per-repo settings from `[repos.aliases]` and `[repos.import]`,
+/// matched against the repos being loaded.
+#[derive(Debug, Clone, Default)]
+pub struct RepoSettings {
+    aliases: RepoMap<String>,
+    imports: RepoMap<Vec<ImportFormat>>,
+}
+
+impl RepoSettings {
+    pub fn from_config(config: &ReposConfig) -> Self {
+        Self {
+            aliases: RepoMap::new(
+                config
+                    .aliases
+                    .iter()
+                    .map(|(alias, repo)| (repo.as_str(), alias.clone())),
+            ),
+            imports: RepoMap::new(
+                config
+                    .import
+                    .iter()
+                    .map(|(repo, formats)| (repo.as_str(), formats.clone())),
+            ),
+        }
+    }
+
+    /// Alias for a repo source.
+    pub fn alias_for(&self, source: &RepoSource) -> Option<&str> {
+        self.aliases.get(source).map(String::as_str)
+    }
+
+    /// Import the configured instruction formats from the repo at
+    /// `repo_path`, then qualify `index` with the repo's alias.
+    pub fn apply(&self, source: &RepoSource, repo_path: &Path, index: &mut SkillIndex) {
+        if let Some(formats) = self.imports.get(source) {
+            crate::import::import_into(repo_path, formats, index);
+        }
+        if let Some(alias) = self.alias_for(source) {
+            index.qualify(alias);
+        }
+    }
+}
+
+/// Values keyed by repo. Keys containing `://` or starting with `git@` are
+/// remote URLs; anything else is a local path, which also matches its
+/// subdirectories.
+#[derive(Debug, Clone)]
+struct RepoMap<T> {
+    /// (canonical URL, value)
+    urls: Vec<(String, T)>,
+    /// (canonical path, value)
+    paths: Vec<(PathBuf, T)>,
+}
+
+impl<T> Default for RepoMap<T> {
+    fn default() -> Self {
+        Self {
+            urls: Vec::new(),
+            paths: Vec::new(),
+        }
+    }
+}
+
+impl<T> RepoMap<T> {
+    fn new<'a>(entries: impl IntoIterator<Item = (&'a str, T)>) -> Self {
+        let mut map = Self::default();
+        for (repo, value) in entries {
+            if repo.contains("://") || repo.starts_with("git@") {
+                map.urls
+                    .push((crate::suggest::canonicalize_url(repo), value));
+            } else {
+                map.paths.push((canonical_path(Path::new(repo)), value));
+            }
+        }
+        map
+    }
+
+    fn get(&self, source: &RepoSource) -> Option<&T> {
+        match source {
+            RepoSource::Local(path) => {
+                let path = canonical_path(path);
+                self.paths
+                    .iter()
+                    .find(|(p, _)| path.starts_with(p))
+                    .map(|(_, v)| v)
+            }
+            RepoSource::Remote { url, .. } => {
+                let url = crate::suggest::canonicalize_url(url);
+                self.urls.iter().find(|(u, _)| *u == url).map(|(_, v)| v)
+            }
+        }
+    }
+}
+
+fn canonical_path(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
 /// Derive a cache directory from the remote URL.
 ///
 /// Turns `https://github.com/owner/repo.git` into `<base>/owner_repo`.
@@ -126,6 +253,8 @@ pub fn load_repos(
         remote_urls
     };
 
+    let limits = index::FileLimits::from_config(&config.files);
+
     let cache_base = default_cache_dir();
     let mut repo_paths = Vec::new();
 
@@ -136,64 +265,88 @@ pub fn load_repos(
         Duration::ZERO
     };
 
+    let concurrency = config.repos.concurrency;
+    let merge_policy = MergePolicy::from_config(&config.repos, &cache_base);
+    let settings = RepoSettings::from_config(&config.repos);
     let mut merged = SkillIndex::default();
 
     // Load local repos
-    for path in &local_paths {
+    let locals = parallel_map(
+        &local_paths,
+        concurrency,
+        |path| -> crate::error::Result<_> {
+            let source = RepoSource::Local(path.clone());
+
+            if cache_enabled && let Some(idx) = cache::load(&source, cache_ttl) {
+                return Ok(idx);
+            }
+
+            let idx = index::load_index(path, &limits)?;
+            if cache_enabled {
+                cache::write(&source, &idx);
+            }
+            Ok(idx)
+        },
+    );
+    for (path, idx) in local_paths.iter().zip(locals) {
+        let mut idx = idx?;
+        settings.apply(&RepoSource::Local(path.clone()), path, &mut idx);
         repo_paths.push(path.clone());
-        let source = RepoSource::Local(path.clone());
-
-        if cache_enabled && let Some(idx) = cache::load(&source, cache_ttl) {
-            merged.merge(idx);
-            continue;
-        }
-
-        let idx = index::load_index(path)?;
-        if cache_enabled {
-            cache::write(&source, &idx);
-        }
-        merged.merge(idx);
+        merged.merge_with(idx, &merge_policy);
     }
 
     // Clone/pull remote repos
-    for url in &remote_urls {
-        let target = cache_dir_for_url(&cache_base, url);
-        if let Some(parent) = target.parent() {
-            std::fs::create_dir_all(parent)?;
-        }
-        git::clone_or_pull(url, &target)?;
-
-        // Resolve release model: checkout appropriate tag/ref
-        if let Err(e) = crate::resolve::resolve_and_checkout(&target, url, &config.source) {
-            tracing::warn!(
-                url,
-                error = %e,
-                "Failed to resolve release ref, using default branch"
-            );
-        }
-
-        let path = match subdir {
-            Some(sub) => target.join(sub),
-            None if *url == DEFAULT_REPO_URL => target.join(DEFAULT_REPO_SUBDIR),
-            None => target.clone(),
-        };
-        repo_paths.push(path.clone());
-
+    let remotes = parallel_map(
+        &remote_urls,
+        concurrency,
+        |url| -> crate::error::Result<_> {
+            let target = cache_dir_for_url(&cache_base, url);
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            git::clone_or_pull(url, &target)?;
+
+            // Resolve release model: checkout appropriate tag/ref
+            if let Err(e) = crate::resolve::resolve_and_checkout(&target, url, &config.source) {
+                tracing::warn!(
+                    url,
+                    error = %e,
+                    "Failed to resolve release ref, using default branch"
+                );
+            }
+
+            let path = match subdir {
+                Some(sub) => target.join(sub),
+                None if *url == DEFAULT_REPO_URL => target.join(DEFAULT_REPO_SUBDIR),
+                None => target.clone(),
+            };
+
+            let source = RepoSource::Remote {
+                url: url.to_string(),
+                checkout: target,
+            };
+
+            if cache_enabled && let Some(idx) = cache::load(&source, cache_ttl) {
+                return Ok((path, idx));
+            }
+
+            let idx = index::load_index(&path, &limits)?;
+            if cache_enabled {
+                cache::write(&source, &idx);
+            }
+            Ok((path, idx))
+        },
+    );
+    // Merge in configured order so earlier repos win on conflicts
+    for (url, result) in remote_urls.iter().zip(remotes) {
+        let (path, mut idx) = result?;
         let source = RepoSource::Remote {
             url: url.to_string(),
-            checkout: target,
+            checkout: path.clone(),
         };
-
-        if cache_enabled && let Some(idx) = cache::load(&source, cache_ttl) {
-            merged.merge(idx);
-            continue;
-        }
-
-        let idx = index::load_index(&path)?;
-        if cache_enabled {
-            cache::write(&source, &idx);
-        }
-        merged.merge(idx);
+        settings.apply(&source, &path, &mut idx);
+        repo_paths.push(path);
+        merged.merge_with(idx, &merge_policy);
     }
 
     // Follow [[suggest]] entries from loaded repos
@@ -206,7 +359,11 @@ pub fn load_repos(
             cache_ttl,
             &seed_urls,
             config.source.clone(),
-        );
+        )
+        .with_concurrency(concurrency)
+        .with_merge_policy(merge_policy)
+        .with_repo_settings(settings)
+        .with_file_limits(limits);
         let seed_paths = repo_paths.clone();
         walker.walk(
             &seed_paths,
@@ -217,9 +374,49 @@ pub fn load_repos(
         );
     }
 
+    crate::taxonomy::normalize_index(&mut merged, &config.taxonomy);
+
     Ok((merged, repo_paths))
 }
 
+/// Run `f` over `items` on up to `concurrency` threads.
+///
+/// Results come back in input order regardless of which finished first,
+/// so callers can merge them deterministically.
+pub fn parallel_map<T, R, F>(items: &[T], concurrency: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let workers = concurrency.clamp(1, items.len().max(1));
+    if workers == 1 {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<R>>> = Mutex::new(items.iter().map(|_| None).collect());
+    std::thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(i) else { break };
+                    let result = f(item);
+                    results.lock().unwrap_or_else(PoisonError::into_inner)[i] = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap_or_else(PoisonError::into_inner)
+        .into_iter()
+        .map(|r| r.expect("every item is processed"))
+        .collect()
+}
+
 /// Identify a repo for manifest entries.
 ///
 /// Returns the git URL as-is for remotes, `local:<abs_path>` for local repos.
@@ -320,6 +517,21 @@ mod tests {
         assert_eq!(parse_duration("  30s  ").unwrap(), Duration::from_secs(30));
     }
 
+    #[test]
+    fn test_parse_size_units() {
+        assert_eq!(parse_size("512").unwrap(), 512);
+        assert_eq!(parse_size("64KB").unwrap(), 64 * 1024);
+        assert_eq!(parse_size(" 1mb ").unwrap(), 1024 * 1024);
+        assert_eq!(parse_size("2G").unwrap(), 2 * 1024 * 1024 * 1024);
+    }
+
+    #[test]
+    fn test_parse_size_invalid() {
+        let err = parse_size("5TB").unwrap_err().to_string();
+        assert!(err.contains("unknown suffix"));
+        assert!(parse_size("MB").is_err());
+    }
+
     #[test]
     fn test_repo_id_local() {
         let path = PathBuf::from("/home/user/my-repo");
@@ -342,4 +554,22 @@ mod tests {
         assert!(!DEFAULT_REPO_URL.is_empty());
         assert!(DEFAULT_REPO_URL.ends_with(".git"));
     }
+
+    #[test]
+    fn test_parallel_map_preserves_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let out = parallel_map(&items, 4, |n| {
+            // Later items finish first
+            std::thread::sleep(Duration::from_millis(20 - n));
+            n * 2
+        });
+        assert_eq!(out, items.iter().map(|n| n * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_parallel_map_zero_concurrency_runs_sequentially() {
+        let out = parallel_map(&["a", "b"], 0, |s| s.to_uppercase());
+        assert_eq!(out, vec!["A", "B"]);
+        assert!(parallel_map(&[] as &[u8], 4, |b| *b).is_empty());
+    }
 }
@@ -1,24 +1,19 @@
 //! Skill search wrapper over the BM25 index.
 //!
 //! Builds a BM25 index from the skill index and provides relevance-ranked
-//! search over skill metadata fields.
+//! search over skill metadata fields. Query terms are expanded through a
+//! [`SynonymMap`] before scoring.
 
 use std::collections::HashMap;
+use std::path::PathBuf;
 
-use crate::bm25::{Bm25Index, IndexOptions};
-use crate::state::SkillIndex;
-
-/// Common English stop words excluded from indexing.
-const STOP_WORDS: &[&str] = &[
-    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
-    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
-    "they", "this", "to", "was", "will", "with",
-    // Question words and pronouns -- improve natural language query handling
-    "how", "do", "does", "did", "can", "should", "would", "could", "what", "which", "where", "when",
-    "why", "who", "me", "my", "i", "you", "your", "we", "us", // Common filler words
-    "about", "from", "have", "has", "had", "been", "being", "some", "any", "all", "just", "want",
-    "need",
-];
+use crate::annotations::{self, AnnotationStore};
+use crate::bm25::{Bm25Index, IndexOptions, Language};
+use crate::cache;
+use crate::config::{RankingConfig, SearchConfig, SkilletConfig};
+use crate::ranking::{self, Signals};
+use crate::state::{SkillEntry, SkillIndex, split_alias};
+use crate::synonyms::{DEFAULT_SYNONYM_WEIGHT, SynonymMap};
 
 /// Truncate text to at most `max_chars` characters for indexing.
 fn truncate_for_index(text: &str, max_chars: usize) -> &str {
@@ -28,81 +23,575 @@ fn truncate_for_index(text: &str, max_chars: usize) -> &str {
     }
 }
 
+/// Fields every skill is indexed with, and their default BM25F weights.
+/// Name and owner matches count most, body content least.
+pub const DEFAULT_FIELD_WEIGHTS: &[(&str, f64)] = &[
+    ("owner", 2.0),
+    ("name", 3.0),
+    ("description", 1.5),
+    ("trigger", 1.5),
+    ("categories", 1.0),
+    ("tags", 1.0),
+    ("content", 0.5),
+];
+
+/// Optional fields that can be indexed via `[search] extra_fields`, in
+/// addition to `metadata.<key>` for arbitrary SKILL.md frontmatter keys.
+pub const EXTRA_FIELDS: &[&str] = &[
+    "annotations",
+    "author",
+    "files",
+    "language",
+    "license",
+    "verified_with",
+];
+
+/// Prefix for extra fields read from SKILL.md frontmatter.
+const METADATA_PREFIX: &str = "metadata.";
+
+/// Whether `field` is a valid `[search] extra_fields` entry.
+pub fn is_extra_field(field: &str) -> bool {
+    EXTRA_FIELDS.contains(&field)
+        || field
+            .strip_prefix(METADATA_PREFIX)
+            .is_some_and(|key| !key.is_empty())
+}
+
+/// Effective field weights: defaults, then extra fields at 1.0, then
+/// `[search.field_weights]` overrides.
+pub fn effective_field_weights(config: &SearchConfig) -> Vec<(String, f64)> {
+    let mut weights: Vec<(String, f64)> = DEFAULT_FIELD_WEIGHTS
+        .iter()
+        .map(|(f, w)| (f.to_string(), *w))
+        .collect();
+    for field in &config.extra_fields {
+        if !weights.iter().any(|(f, _)| f == field) {
+            weights.push((field.clone(), 1.0));
+        }
+    }
+    for (field, weight) in &mut weights {
+        if let Some(w) = config.field_weights.get(field.as_str()) {
+            *weight = *w;
+        }
+    }
+    weights
+}
+
+/// BM25 options for the skill index, from `[search]`: analysis language,
+/// stop words, k1/b, and field weights.
+fn index_options(config: &SearchConfig) -> IndexOptions {
+    let weights = effective_field_weights(config);
+
+    IndexOptions {
+        fields: weights.iter().map(|(f, _)| f.clone()).collect(),
+        id_field: Some("id".to_string()),
+        stopwords: config.stop_words.clone().unwrap_or_else(|| {
+            config
+                .language
+                .default_stopwords()
+                .iter()
+                .map(|s| s.to_string())
+                .collect()
+        }),
+        lowercase: true,
+        language: config.language,
+        k1: config.k1,
+        b: config.b,
+        field_weights: weights.into_iter().collect(),
+    }
+}
+
+/// Document ID for a skill in the BM25 index.
+fn doc_id(entry: &SkillEntry) -> String {
+    format!("{}/{}", entry.owner, entry.name)
+}
+
+/// A skill rendered as the text that gets indexed.
+///
+/// Comparing two of these tells whether a skill needs re-indexing, so
+/// unrelated changes (provenance, historical versions) don't force a
+/// re-tokenize.
+#[derive(Debug, PartialEq)]
+struct SkillDoc {
+    language: Option<Language>,
+    fields: Vec<(String, String)>,
+}
+
+/// Render a skill's latest non-yanked version. Fully yanked skills have
+/// no document.
+fn skill_doc(
+    entry: &SkillEntry,
+    extra_fields: &[String],
+    annotations: &AnnotationStore,
+) -> Option<SkillDoc> {
+    let v = entry.latest()?;
+    let info = &v.metadata.skill;
+    let classification = info.classification.as_ref();
+
+    let mut fields = vec![
+        // The repo alias only disambiguates; it isn't skill text
+        ("owner".to_string(), split_alias(&entry.owner).1.to_string()),
+        ("name".to_string(), entry.name.clone()),
+        ("description".to_string(), info.description.clone()),
+        (
+            "trigger".to_string(),
+            info.trigger.clone().unwrap_or_default(),
+        ),
+        (
+            "categories".to_string(),
+            classification
+                .map(|c| c.categories.join(" "))
+                .unwrap_or_default(),
+        ),
+        (
+            "tags".to_string(),
+            classification.map(|c| c.tags.join(" ")).unwrap_or_default(),
+        ),
+        (
+            "content".to_string(),
+            truncate_for_index(&v.skill_md, 500).to_string(),
+        ),
+    ];
+
+    for field in extra_fields {
+        let text = match field.as_str() {
+            "annotations" => annotations
+                .skills
+                .get(&doc_id(entry))
+                .map(|notes| {
+                    notes
+                        .iter()
+                        .map(|a| a.note.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .unwrap_or_default(),
+            "author" => info
+                .author
+                .as_ref()
+                .map(|a| {
+                    [a.name.as_deref(), a.github.as_deref()]
+                        .into_iter()
+                        .flatten()
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .unwrap_or_default(),
+            "files" => {
+                let mut paths: Vec<&str> = v.files.keys().map(String::as_str).collect();
+                paths.sort();
+                paths.join(" ")
+            }
+            "language" => info.language.clone().unwrap_or_default(),
+            "license" => info.license.clone().unwrap_or_default(),
+            "verified_with" => info
+                .compatibility
+                .as_ref()
+                .map(|c| c.verified_with.join(" "))
+                .unwrap_or_default(),
+            other => match other.strip_prefix(METADATA_PREFIX) {
+                Some(key) => v.metadata.frontmatter_text(key).unwrap_or_default(),
+                None => String::new(),
+            },
+        };
+        fields.push((field.clone(), text));
+    }
+
+    Some(SkillDoc {
+        language: skill_language(entry),
+        fields,
+    })
+}
+
+/// Hash every document `skill_index` would contribute to a search index.
+///
+/// Rendering is far cheaper than tokenizing, and covering the merged
+/// documents rather than the repo sources catches everything applied after
+/// the per-repo caches load.
+fn doc_set_hash(skill_index: &SkillIndex, extra_fields: &[String]) -> String {
+    use sha2::{Digest, Sha256};
+
+    let annotations = AnnotationStore::default();
+    let mut entries: Vec<&SkillEntry> = skill_index.skills.values().collect();
+    entries.sort_by(|a, b| (&a.owner, &a.name).cmp(&(&b.owner, &b.name)));
+
+    let mut hasher = Sha256::new();
+    for entry in entries {
+        let Some(doc) = skill_doc(entry, extra_fields, &annotations) else {
+            continue;
+        };
+        hasher.update(doc_id(entry).as_bytes());
+        hasher.update([0]);
+        if let Some(language) = doc.language {
+            hasher.update(language.to_string().as_bytes());
+        }
+        hasher.update([0]);
+        for (field, text) in &doc.fields {
+            hasher.update(field.as_bytes());
+            hasher.update([0]);
+            hasher.update(text.as_bytes());
+            hasher.update([0]);
+        }
+        hasher.update([1]);
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Whether `[search] extra_fields` includes local annotations.
+fn indexes_annotations(config: &SearchConfig) -> bool {
+    indexes_annotations_in(&config.extra_fields)
+}
+
+fn indexes_annotations_in(extra_fields: &[String]) -> bool {
+    extra_fields.iter().any(|f| f == "annotations")
+}
+
+/// Index a rendered skill document.
+fn add_doc(index: &mut Bm25Index, id: &str, doc: &SkillDoc) {
+    let fields: Vec<(&str, &str)> = doc
+        .fields
+        .iter()
+        .map(|(f, t)| (f.as_str(), t.as_str()))
+        .collect();
+    let language = doc.language.unwrap_or(index.options.language);
+    index.add_fields_in(id, &fields, language);
+}
+
+/// The analysis language declared by a skill's latest version, if any.
+///
+/// Unrecognized values fall back to the index default.
+fn skill_language(entry: &SkillEntry) -> Option<Language> {
+    let declared = entry.latest()?.metadata.skill.language.as_deref()?;
+    match declared.parse() {
+        Ok(language) => Some(language),
+        Err(e) => {
+            tracing::debug!(skill = %doc_id(entry), error = %e, "Ignoring skill language");
+            None
+        }
+    }
+}
+
+/// Number of shared terms reported per similar skill.
+const MAX_SHARED_TERMS: usize = 5;
+
+/// A skill related to another one, see [`SkillSearch::similar`].
+#[derive(Debug, Clone)]
+pub struct SimilarSkill {
+    pub owner: String,
+    pub name: String,
+    /// Cosine similarity of BM25 term weights, `0.0..=1.0`.
+    pub score: f64,
+    /// Indexed (stemmed) terms both skills contain, strongest first.
+    pub shared_terms: Vec<String>,
+}
+
 /// Search index over skills, backed by BM25.
 pub struct SkillSearch {
     index: Bm25Index,
+    synonyms: SynonymMap,
+    synonym_weight: f64,
+    ranking: RankingConfig,
+    /// Repo load order, for the repo priority ranking signal.
+    repo_paths: Vec<PathBuf>,
+    /// Per-document score multipliers from `ranking::Signals`.
+    boosts: HashMap<String, f64>,
+    /// `[search] extra_fields` indexed alongside the default fields.
+    extra_fields: Vec<String>,
+    /// Local annotations, loaded only when `annotations` is an extra field.
+    annotations: AnnotationStore,
 }
 
 impl SkillSearch {
+    /// Build a search index from the skill index using the user's `[search]`
+    /// config and any per-repo `synonyms.toml` files under `repo_paths`.
+    pub fn build_with_config(
+        skill_index: &SkillIndex,
+        config: &SearchConfig,
+        repo_paths: &[PathBuf],
+    ) -> Self {
+        Self::build_index(skill_index, config)
+            .with_synonyms(SynonymMap::load(config, repo_paths), config.synonym_weight)
+            .with_ranking(&config.ranking, repo_paths, skill_index)
+    }
+
+    /// Load the persisted search index for `repo_paths` if it is still
+    /// current, otherwise build one and persist it for the next run.
+    ///
+    /// The persisted index is keyed by [`cache::fingerprint`] plus a hash
+    /// of the final merged document set, so it is only reused when every
+    /// repo was served from an unchanged per-repo cache and nothing applied
+    /// after loading (aliases, imports, taxonomy, merge policy) changed what
+    /// would be indexed. Synonyms are always reloaded since they are cheap
+    /// and come from config. With caching disabled this is
+    /// [`Self::build_with_config`].
+    pub fn load_or_build(
+        skill_index: &SkillIndex,
+        config: &SkilletConfig,
+        repo_paths: &[PathBuf],
+    ) -> Self {
+        let key = if config.cache.enabled {
+            cache::fingerprint(repo_paths, &crate::repo::default_cache_dir())
+        } else {
+            None
+        };
+        // Annotations are edited locally without touching any repo, so an
+        // index that includes them can't be keyed by repo state alone.
+        let key = key.filter(|_| !indexes_annotations(&config.search));
+        let Some(key) = key else {
+            return Self::build_with_config(skill_index, &config.search, repo_paths);
+        };
+        let key = format!(
+            "{key}-{}",
+            doc_set_hash(skill_index, &config.search.extra_fields)
+        );
+
+        let synonyms = SynonymMap::load(&config.search, repo_paths);
+        if let Some(index) = cache::load_search(&key)
+            && index.options == index_options(&config.search)
+        {
+            return Self::from_index(index, &config.search)
+                .with_synonyms(synonyms, config.search.synonym_weight)
+                .with_ranking(&config.search.ranking, repo_paths, skill_index);
+        }
+
+        let search = Self::build_index(skill_index, &config.search)
+            .with_synonyms(synonyms, config.search.synonym_weight)
+            .with_ranking(&config.search.ranking, repo_paths, skill_index);
+        cache::write_search(&key, &search.index);
+        search
+    }
+
     /// Build a search index from the skill index.
     ///
-    /// Each skill's latest non-yanked version is indexed as a JSON document
-    /// with fields: id, owner, name, description, trigger, categories, tags.
+    /// Each skill's latest non-yanked version is indexed as a document
+    /// with fields: owner, name, description, trigger, categories, tags,
+    /// content. Queries are expanded with the built-in synonym dictionary.
+    /// Results are re-ranked with the default `[search.ranking]` weights.
     pub fn build(skill_index: &SkillIndex) -> Self {
-        let docs: Vec<serde_json::Value> = skill_index
+        Self::build_index(skill_index, &SearchConfig::default()).with_ranking(
+            &RankingConfig::default(),
+            &[],
+            skill_index,
+        )
+    }
+
+    /// Index every skill using the analysis settings from `config`.
+    fn build_index(skill_index: &SkillIndex, config: &SearchConfig) -> Self {
+        let mut search = Self::from_index(Bm25Index::new(index_options(config)), config);
+        for entry in skill_index.skills.values() {
+            if let Some(doc) = search.render(entry) {
+                add_doc(&mut search.index, &doc_id(entry), &doc);
+            }
+        }
+        search
+    }
+
+    /// Wrap an already-built BM25 index, using the built-in synonyms and
+    /// no ranking boosts.
+    fn from_index(index: Bm25Index, config: &SearchConfig) -> Self {
+        Self {
+            index,
+            synonyms: SynonymMap::builtin(),
+            synonym_weight: DEFAULT_SYNONYM_WEIGHT,
+            ranking: RankingConfig::default(),
+            repo_paths: Vec::new(),
+            boosts: HashMap::new(),
+            extra_fields: config.extra_fields.clone(),
+            annotations: if indexes_annotations(config) {
+                annotations::load()
+            } else {
+                AnnotationStore::default()
+            },
+        }
+    }
+
+    /// Render a skill with this index's extra fields.
+    fn render(&self, entry: &SkillEntry) -> Option<SkillDoc> {
+        skill_doc(entry, &self.extra_fields, &self.annotations)
+    }
+
+    /// Set the ranking weights and repo order, and compute per-skill boosts.
+    pub fn with_ranking(
+        mut self,
+        ranking: &RankingConfig,
+        repo_paths: &[PathBuf],
+        skill_index: &SkillIndex,
+    ) -> Self {
+        self.ranking = ranking.clone();
+        self.repo_paths = repo_paths.to_vec();
+        self.compute_boosts(skill_index);
+        self
+    }
+
+    /// Recompute ranking boosts for every indexed skill.
+    ///
+    /// Signals are cheap to derive and several (trust tier, repo order)
+    /// can change without touching indexed text, so this always runs over
+    /// the whole index.
+    fn compute_boosts(&mut self, skill_index: &SkillIndex) {
+        let today = ranking::today();
+        self.boosts = skill_index
             .skills
             .values()
-            .filter_map(|entry| {
-                let v = entry.latest()?;
-                let info = &v.metadata.skill;
-                let classification = info.classification.as_ref();
-
-                let categories = classification
-                    .map(|c| c.categories.join(" "))
-                    .unwrap_or_default();
-                let tags = classification.map(|c| c.tags.join(" ")).unwrap_or_default();
-
-                Some(serde_json::json!({
-                    "id": format!("{}/{}", entry.owner, entry.name),
-                    "owner": entry.owner,
-                    "name": entry.name,
-                    "description": info.description,
-                    "trigger": info.trigger.as_deref().unwrap_or(""),
-                    "categories": categories,
-                    "tags": tags,
-                    "content": truncate_for_index(&v.skill_md, 500),
-                }))
+            .filter(|entry| entry.latest().is_some())
+            .map(|entry| {
+                let signals = Signals::for_entry(
+                    entry,
+                    &self.repo_paths,
+                    self.ranking.recency_half_life_days,
+                    today,
+                );
+                (doc_id(entry), signals.boost(&self.ranking))
             })
             .collect();
+    }
+
+    /// Replace the synonym dictionary and the weight given to expanded terms.
+    pub fn with_synonyms(mut self, synonyms: SynonymMap, weight: f64) -> Self {
+        self.set_synonyms(synonyms, weight);
+        self
+    }
+
+    /// Replace the synonym dictionary in place (e.g. after a repo refresh).
+    pub fn set_synonyms(&mut self, synonyms: SynonymMap, weight: f64) {
+        self.synonyms = synonyms;
+        self.synonym_weight = weight;
+    }
+
+    /// Bring the search index in line with `new` given that it currently
+    /// reflects `old`.
+    ///
+    /// Only skills that were added, removed, or whose indexed fields changed
+    /// are re-tokenized; everything else keeps its postings. Returns the
+    /// number of documents touched.
+    pub fn update(&mut self, old: &SkillIndex, new: &SkillIndex) -> usize {
+        let mut touched = 0;
 
-        let field_weights = HashMap::from([
-            ("name".to_string(), 3.0),
-            ("owner".to_string(), 2.0),
-            ("description".to_string(), 1.5),
-            ("trigger".to_string(), 1.5),
-            ("categories".to_string(), 1.0),
-            ("tags".to_string(), 1.0),
-            ("content".to_string(), 0.5),
-        ]);
-
-        let options = IndexOptions {
-            fields: vec![
-                "owner".to_string(),
-                "name".to_string(),
-                "description".to_string(),
-                "trigger".to_string(),
-                "categories".to_string(),
-                "tags".to_string(),
-                "content".to_string(),
-            ],
-            id_field: Some("id".to_string()),
-            stopwords: STOP_WORDS.iter().map(|s| s.to_string()).collect(),
-            lowercase: true,
-            k1: 1.2,
-            b: 0.75,
-            field_weights,
+        // Render old documents against the annotations they were indexed
+        // with, new ones against the current store.
+        let old_annotations = if indexes_annotations_in(&self.extra_fields) {
+            std::mem::replace(&mut self.annotations, annotations::load())
+        } else {
+            AnnotationStore::default()
         };
 
-        Self {
-            index: Bm25Index::build(&docs, options),
+        for (key, old_entry) in &old.skills {
+            if !new.skills.contains_key(key) && old_entry.latest().is_some() {
+                self.index.remove_document(&doc_id(old_entry));
+                touched += 1;
+            }
+        }
+
+        for (key, entry) in &new.skills {
+            let doc = self.render(entry);
+            match old.skills.get(key) {
+                Some(old_entry)
+                    if skill_doc(old_entry, &self.extra_fields, &old_annotations) == doc => {}
+                Some(old_entry) => {
+                    // Changed, or newly all-yanked: drop and re-add
+                    self.index.remove_document(&doc_id(old_entry));
+                    if let Some(doc) = doc {
+                        add_doc(&mut self.index, &doc_id(entry), &doc);
+                    }
+                    touched += 1;
+                }
+                None => {
+                    if let Some(doc) = doc {
+                        add_doc(&mut self.index, &doc_id(entry), &doc);
+                    }
+                    touched += 1;
+                }
+            }
         }
+
+        self.compute_boosts(new);
+
+        tracing::debug!(
+            touched,
+            total = self.index.doc_count,
+            "Incrementally updated search index"
+        );
+        touched
+    }
+
+    /// Tokenize a query and append synonym expansions.
+    ///
+    /// Terms the user typed get weight 1.0; terms only reached through the
+    /// synonym dictionary get `synonym_weight`.
+    fn expand_query(&self, query: &str) -> Vec<(String, f64)> {
+        let mut terms: Vec<(String, f64)> = self
+            .index
+            .tokenize_query(query)
+            .into_iter()
+            .map(|t| (t, 1.0))
+            .collect();
+
+        let words: Vec<String> = query
+            .to_lowercase()
+            .split(|c: char| !c.is_alphanumeric() && c != '_')
+            .filter(|w| !w.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        for expansion in self.synonyms.expand_words(&words) {
+            for term in self.index.tokenize_query(expansion) {
+                if !terms.iter().any(|(t, _)| *t == term) {
+                    terms.push((term, self.synonym_weight));
+                }
+            }
+        }
+
+        terms
+    }
+
+    /// Skills most similar to `owner/name` ("more like this").
+    ///
+    /// Compares the BM25 term-weight vectors of the indexed documents, so
+    /// it uses the same fields, field weights, and analysis as search.
+    /// Returns an empty list if the skill isn't indexed.
+    pub fn similar(&self, owner: &str, name: &str, limit: usize) -> Vec<SimilarSkill> {
+        self.index
+            .similar(&format!("{owner}/{name}"), limit)
+            .into_iter()
+            .filter_map(|result| {
+                let (owner, name) = result.id.split_once('/')?;
+                let mut shared_terms = result.shared_terms;
+                shared_terms.truncate(MAX_SHARED_TERMS);
+                Some(SimilarSkill {
+                    owner: owner.to_string(),
+                    name: name.to_string(),
+                    score: result.score,
+                    shared_terms,
+                })
+            })
+            .collect()
     }
 
     /// Search skills by query. Returns `(owner, name, score)` tuples sorted
     /// by relevance (highest score first).
+    ///
+    /// When ranking is enabled, every BM25 match is scaled by its skill's
+    /// boost before the top `limit` are taken, so a boosted skill just
+    /// outside the text-only top `limit` can still make the cut.
     pub fn search(&self, query: &str, limit: usize) -> Vec<(String, String, f64)> {
-        self.index
-            .search(query, limit)
+        let terms = self.expand_query(query);
+        let results = if self.ranking.enabled {
+            let mut results = self.index.search_weighted(&terms, usize::MAX);
+            for result in &mut results {
+                result.score *= self.boosts.get(&result.id).copied().unwrap_or(1.0);
+            }
+            results.sort_by(|a, b| {
+                b.score
+                    .partial_cmp(&a.score)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+            results.truncate(limit);
+            results
+        } else {
+            self.index.search_weighted(&terms, limit)
+        };
+
+        results
             .into_iter()
             .filter_map(|result| {
                 let (owner, name) = result.id.split_once('/')?;
@@ -117,6 +606,7 @@ mod tests {
     use super::*;
     use crate::state::{
         Classification, Compatibility, SkillEntry, SkillInfo, SkillMetadata, SkillVersion,
+        TrustTier,
     };
     use std::collections::HashMap;
 
@@ -128,6 +618,7 @@ mod tests {
             source: Default::default(),
             trust_tier: Default::default(),
             discovered_via: Vec::new(),
+            origin: None,
             versions: vec![SkillVersion {
                 version: "1.0.0".to_string(),
                 metadata: SkillMetadata {
@@ -151,7 +642,13 @@ mod tests {
                             required_mcp_servers: Vec::new(),
                             verified_with: vec!["claude-opus-4-6".to_string()],
                         }),
+                        language: None,
+                        files: None,
+                        activation: None,
+                        allowed_tools: None,
+                        model: None,
                     },
+                    frontmatter: Default::default(),
                 },
                 skill_md: String::new(),
                 skill_toml_raw: String::new(),
@@ -275,6 +772,347 @@ mod tests {
         );
     }
 
+    fn k8s_index() -> SkillIndex {
+        let mut skills = HashMap::new();
+        skills.insert(
+            ("acme".to_string(), "kube-deploy".to_string()),
+            make_entry(
+                "acme",
+                "kube-deploy",
+                "Deploy services to Kubernetes",
+                &["kubernetes"],
+            ),
+        );
+        skills.insert(
+            ("acme".to_string(), "k8s-debug".to_string()),
+            make_entry("acme", "k8s-debug", "Debug k8s pods", &["k8s"]),
+        );
+        SkillIndex {
+            skills,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_search_expands_builtin_synonyms() {
+        let search = SkillSearch::build(&k8s_index());
+        let names: Vec<String> = search.search("k8s", 10).into_iter().map(|r| r.1).collect();
+        assert!(names.contains(&"kube-deploy".to_string()), "{names:?}");
+        assert!(names.contains(&"k8s-debug".to_string()), "{names:?}");
+    }
+
+    #[test]
+    fn test_search_expanded_terms_rank_below_literal() {
+        let search = SkillSearch::build(&k8s_index());
+        let results = search.search("k8s", 10);
+        // The skill that literally says "k8s" outranks the synonym match
+        assert_eq!(results[0].1, "k8s-debug");
+
+        let results = search.search("kubernetes", 10);
+        assert_eq!(results[0].1, "kube-deploy");
+    }
+
+    #[test]
+    fn test_search_without_synonyms() {
+        let search = SkillSearch::build(&k8s_index()).with_synonyms(SynonymMap::new(), 0.5);
+        let names: Vec<String> = search.search("k8s", 10).into_iter().map(|r| r.1).collect();
+        assert_eq!(names, vec!["k8s-debug"]);
+    }
+
+    #[test]
+    fn test_build_with_config_custom_synonyms() {
+        let mut config = SearchConfig {
+            builtin_synonyms: false,
+            ..Default::default()
+        };
+        config
+            .synonyms
+            .insert("python".to_string(), vec!["snake".to_string()]);
+
+        let search = SkillSearch::build_with_config(&test_index(), &config, &[]);
+        let results = search.search("snake", 10);
+        assert_eq!(results[0].1, "python-dev");
+    }
+
+    #[test]
+    fn test_search_japanese_skill() {
+        let mut index = test_index();
+        index.skills.insert(
+            ("acme".to_string(), "nihongo".to_string()),
+            make_entry("acme", "nihongo", "データベースの移行を支援する", &[]),
+        );
+        let search = SkillSearch::build(&index);
+
+        let results = search.search("移行", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].1, "nihongo");
+    }
+
+    #[test]
+    fn test_search_skill_language_overrides_default() {
+        let mut entry = make_entry("acme", "datenbank", "Datenbanken verwalten", &[]);
+        entry.versions[0].metadata.skill.language = Some("de".to_string());
+        let mut index = test_index();
+        index
+            .skills
+            .insert(("acme".to_string(), "datenbank".to_string()), entry);
+        let search = SkillSearch::build(&index);
+
+        // German stemming maps both forms to the same term
+        let results = search.search("Datenbank", 10);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].1, "datenbank");
+    }
+
+    /// Two skills with identical text; `rival` is transitive with thin metadata.
+    fn ranking_index() -> SkillIndex {
+        let mut index = SkillIndex::default();
+        let curated = make_entry("acme", "curated", "Deploy services with helm", &["helm"]);
+        let mut rival = make_entry("zeta", "rival", "Deploy services with helm", &["helm"]);
+        rival.trust_tier = TrustTier::Transitive;
+        let info = &mut rival.versions[0].metadata.skill;
+        info.classification = None;
+        info.compatibility = None;
+        index
+            .skills
+            .insert(("acme".to_string(), "curated".to_string()), curated);
+        index
+            .skills
+            .insert(("zeta".to_string(), "rival".to_string()), rival);
+        index
+    }
+
+    #[test]
+    fn test_ranking_prefers_trusted_complete_skills() {
+        let index = ranking_index();
+        let search = SkillSearch::build(&index);
+
+        let results = search.search("deploy helm", 10);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].1, "curated");
+        assert!(results[0].2 > results[1].2);
+    }
+
+    #[test]
+    fn test_ranking_disabled_is_pure_bm25() {
+        let index = ranking_index();
+        let config = SearchConfig {
+            ranking: RankingConfig {
+                enabled: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let ranked = SkillSearch::build(&index);
+        let plain = SkillSearch::build_with_config(&index, &config, &[]);
+
+        let bm25 = plain.index.search("deploy helm", 10);
+        let results = plain.search("deploy helm", 10);
+        assert_eq!(results.len(), bm25.len());
+        for (r, b) in results.iter().zip(&bm25) {
+            assert_eq!(r.2, b.score);
+        }
+        assert!(ranked.search("deploy helm", 10)[0].2 > results[0].2);
+    }
+
+    #[test]
+    fn test_ranking_repo_priority() {
+        let mut index = SkillIndex::default();
+        let mut first = make_entry("acme", "first", "Lint shell scripts", &["shell"]);
+        first.origin = Some(PathBuf::from("/repos/a"));
+        let mut second = make_entry("acme", "second", "Lint shell scripts", &["shell"]);
+        second.origin = Some(PathBuf::from("/repos/b"));
+        index
+            .skills
+            .insert(("acme".to_string(), "first".to_string()), first);
+        index
+            .skills
+            .insert(("acme".to_string(), "second".to_string()), second);
+
+        let repos = [PathBuf::from("/repos/b"), PathBuf::from("/repos/a")];
+        let search = SkillSearch::build_with_config(&index, &SearchConfig::default(), &repos);
+        assert_eq!(search.search("lint shell", 10)[0].1, "second");
+    }
+
+    #[test]
+    fn test_field_weights_change_ranking() {
+        let mut index = SkillIndex::default();
+        let mut by_trigger = make_entry("acme", "alpha", "General helper", &[]);
+        by_trigger.versions[0].metadata.skill.trigger = Some("when migrating databases".into());
+        let by_tag = make_entry(
+            "acme",
+            "beta",
+            "General helper",
+            &["migrating", "databases"],
+        );
+        index
+            .skills
+            .insert(("acme".to_string(), "alpha".to_string()), by_trigger);
+        index
+            .skills
+            .insert(("acme".to_string(), "beta".to_string()), by_tag);
+
+        let mut config = SearchConfig {
+            ranking: RankingConfig {
+                enabled: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        config.field_weights.insert("tags".to_string(), 5.0);
+        let tag_heavy = SkillSearch::build_with_config(&index, &config, &[]);
+        assert_eq!(tag_heavy.search("migrating databases", 10)[0].1, "beta");
+
+        config.field_weights.insert("tags".to_string(), 0.5);
+        config.field_weights.insert("trigger".to_string(), 5.0);
+        let trigger_heavy = SkillSearch::build_with_config(&index, &config, &[]);
+        assert_eq!(
+            trigger_heavy.search("migrating databases", 10)[0].1,
+            "alpha"
+        );
+    }
+
+    #[test]
+    fn test_extra_fields_are_indexed() {
+        let mut entry = make_entry("acme", "broker", "Message helper", &[]);
+        entry.versions[0].skill_md =
+            "---\nname: broker\nmetadata:\n  keywords: [rabbitmq, amqp]\n---\n# Broker\n"
+                .to_string();
+        entry.versions[0].metadata.frontmatter =
+            crate::project::parse_frontmatter(&entry.versions[0].skill_md)
+                .unwrap()
+                .fields;
+        entry.versions[0].metadata.skill.license = Some("Apache-2.0".to_string());
+        let mut index = SkillIndex::default();
+        index
+            .skills
+            .insert(("acme".to_string(), "broker".to_string()), entry);
+
+        let plain = SkillSearch::build(&index);
+        assert!(plain.search("apache", 10).is_empty());
+
+        let config = SearchConfig {
+            extra_fields: vec!["metadata.keywords".to_string(), "license".to_string()],
+            ..Default::default()
+        };
+        let extended = SkillSearch::build_with_config(&index, &config, &[]);
+        assert_eq!(extended.search("apache", 10)[0].1, "broker");
+        // Frontmatter is also in the content field; the keywords field adds to it
+        assert!(extended.search("rabbitmq", 10)[0].2 > plain.search("rabbitmq", 10)[0].2);
+    }
+
+    #[test]
+    fn test_effective_field_weights() {
+        let mut config = SearchConfig {
+            extra_fields: vec!["license".to_string()],
+            ..Default::default()
+        };
+        config.field_weights.insert("trigger".to_string(), 4.0);
+        let weights: HashMap<String, f64> = effective_field_weights(&config).into_iter().collect();
+        assert_eq!(weights["trigger"], 4.0);
+        assert_eq!(weights["name"], 3.0);
+        assert_eq!(weights["license"], 1.0);
+        assert_eq!(weights.len(), DEFAULT_FIELD_WEIGHTS.len() + 1);
+    }
+
+    #[test]
+    fn test_similar_skills() {
+        let search = SkillSearch::build(&test_index());
+
+        let similar = search.similar("acme", "rust-dev", 10);
+        assert_eq!(similar[0].name, "python-dev");
+        assert!(similar.iter().all(|s| s.name != "rust-dev"));
+        assert!(similar[0].shared_terms.len() <= MAX_SHARED_TERMS);
+        assert!(!similar[0].shared_terms.is_empty());
+
+        assert_eq!(search.similar("acme", "rust-dev", 1).len(), 1);
+        assert!(search.similar("acme", "missing", 10).is_empty());
+    }
+
+    /// Compare incremental and full-rebuild search results for a query.
+    /// Results are keyed by skill since tied scores have no stable order.
+    fn assert_same_results(a: &SkillSearch, b: &SkillSearch, query: &str) {
+        let collect = |s: &SkillSearch| -> HashMap<(String, String), f64> {
+            s.search(query, 10)
+                .into_iter()
+                .map(|(owner, name, score)| ((owner, name), score))
+                .collect()
+        };
+        let (ra, rb) = (collect(a), collect(b));
+        assert_eq!(ra.len(), rb.len(), "result count for {query:?}");
+        for (key, score) in &ra {
+            assert!(
+                (score - rb[key]).abs() < 1e-10,
+                "score for {key:?} on {query:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_update_matches_full_rebuild() {
+        let old = test_index();
+        let mut search = SkillSearch::build(&old);
+
+        let mut new = test_index();
+        // Remove one skill, change another, add a third
+        new.skills
+            .remove(&("acme".to_string(), "docker-workflow".to_string()));
+        new.skills
+            .get_mut(&("acme".to_string(), "python-dev".to_string()))
+            .unwrap()
+            .versions[0]
+            .metadata
+            .skill
+            .description = "Python packaging with poetry".to_string();
+        new.skills.insert(
+            ("acme".to_string(), "go-dev".to_string()),
+            make_entry("acme", "go-dev", "Go development", &["go"]),
+        );
+
+        let touched = search.update(&old, &new);
+        assert_eq!(touched, 3);
+
+        let rebuilt = SkillSearch::build(&new);
+        assert_eq!(search.index.doc_count, rebuilt.index.doc_count);
+        for query in [
+            "docker",
+            "poetry",
+            "python testing",
+            "go",
+            "rust development",
+        ] {
+            assert_same_results(&rebuilt, &search, query);
+        }
+    }
+
+    #[test]
+    fn test_update_skips_unchanged() {
+        let old = test_index();
+        let mut search = SkillSearch::build(&old);
+        let mut new = test_index();
+        // Provenance changes don't affect the indexed document
+        new.skills
+            .get_mut(&("acme".to_string(), "rust-dev".to_string()))
+            .unwrap()
+            .discovered_via = vec!["https://example.com/repo".to_string()];
+        assert_eq!(search.update(&old, &new), 0);
+    }
+
+    #[test]
+    fn test_update_drops_fully_yanked() {
+        let old = test_index();
+        let mut search = SkillSearch::build(&old);
+        let mut new = test_index();
+        new.skills
+            .get_mut(&("acme".to_string(), "rust-dev".to_string()))
+            .unwrap()
+            .versions[0]
+            .yanked = true;
+
+        search.update(&old, &new);
+        assert!(search.search("rust", 10).iter().all(|r| r.1 != "rust-dev"));
+    }
+
     #[test]
     fn test_search_finds_skill_via_content() {
         // Create a skill with sparse metadata but rich SKILL.md content
@@ -293,4 +1131,55 @@ mod tests {
         assert!(!results.is_empty(), "should find skill via content match");
         assert_eq!(results[0].1, "minimal-skill");
     }
+
+    #[test]
+    fn test_repo_alias_is_not_indexed() {
+        let plain = SkillSearch::build(&test_index());
+        let mut aliased = test_index();
+        aliased.qualify("fork");
+        let aliased = SkillSearch::build(&aliased);
+
+        assert!(aliased.search("fork", 10).is_empty());
+        let results = aliased.search("rust", 10);
+        assert_eq!(results[0].0, "fork:acme");
+        assert_eq!(results[0].1, "rust-dev");
+
+        // Scores match the unaliased index
+        let plain_scores: Vec<f64> = plain.search("rust", 10).iter().map(|r| r.2).collect();
+        let aliased_scores: Vec<f64> = results.iter().map(|r| r.2).collect();
+        assert_eq!(plain_scores, aliased_scores);
+    }
+
+    #[test]
+    fn test_doc_set_hash_tracks_merged_documents() {
+        let base = doc_set_hash(&test_index(), &[]);
+        assert_eq!(base, doc_set_hash(&test_index(), &[]));
+
+        // Re-qualifying a skill (as a repo alias does) changes its document
+        let mut renamed = test_index();
+        let mut entry = renamed
+            .skills
+            .remove(&("acme".to_string(), "rust-dev".to_string()))
+            .unwrap();
+        entry.owner = "fork:acme".to_string();
+        renamed
+            .skills
+            .insert(("fork:acme".to_string(), "rust-dev".to_string()), entry);
+        assert_ne!(base, doc_set_hash(&renamed, &[]));
+
+        // So does any change to indexed text, e.g. normalized tags
+        let mut retagged = test_index();
+        retagged
+            .skills
+            .get_mut(&("acme".to_string(), "rust-dev".to_string()))
+            .unwrap()
+            .versions[0]
+            .metadata
+            .skill
+            .classification
+            .as_mut()
+            .unwrap()
+            .tags = vec!["rustlang".to_string()];
+        assert_ne!(base, doc_set_hash(&retagged, &[]));
+    }
 }
@@ -2,12 +2,16 @@
 
 use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
 
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
+use crate::config::{ConflictPolicy, ImportFormat, SkilletConfig};
+use crate::conflict::MergePolicy;
 use crate::search::SkillSearch;
+use crate::suggest::DiscoveredRepo;
 
 /// Shared state for the MCP server
 pub struct AppState {
@@ -19,25 +23,45 @@ pub struct AppState {
     pub repo_paths: Vec<PathBuf>,
     /// Remote URLs (for cache key generation)
     pub remote_urls: Vec<String>,
+    /// Repos reached through the suggest graph, with the remote each was
+    /// cloned from
+    pub suggested: Vec<DiscoveredRepo>,
     /// Server configuration (name and refresh interval)
     pub config: ServerConfig,
+    /// User configuration from `config.toml` (search tuning, etc.)
+    pub cli_config: SkilletConfig,
+    /// Project the server runs for: the nearest `skillet.toml` root, or the
+    /// startup directory. Resolved once so discovery doesn't depend on the
+    /// process's working directory later.
+    pub project_root: PathBuf,
 }
 
 impl AppState {
     pub fn new(
         repo_paths: Vec<PathBuf>,
         remote_urls: Vec<String>,
+        suggested: Vec<DiscoveredRepo>,
         index: SkillIndex,
         search: SkillSearch,
         config: ServerConfig,
-    ) -> Arc<Self> {
-        Arc::new(Self {
+        cli_config: SkilletConfig,
+    ) -> Self {
+        Self {
             index: RwLock::new(index),
             search: RwLock::new(search),
             repo_paths,
             remote_urls,
+            suggested,
             config,
-        })
+            cli_config,
+            project_root: PathBuf::from("."),
+        }
+    }
+
+    /// Set the project root (default: the working directory).
+    pub fn with_project_root(mut self, project_root: PathBuf) -> Self {
+        self.project_root = project_root;
+        self
     }
 }
 
@@ -68,32 +92,381 @@ pub struct SkillIndex {
     pub skills: HashMap<(String, String), SkillEntry>,
     /// All known categories with skill counts
     pub categories: BTreeMap<String, usize>,
+    /// All known tags with skill counts
+    pub tags: BTreeMap<String, usize>,
+    /// Skills and repos that were skipped while loading
+    pub diagnostics: Vec<LoadDiagnostic>,
+    /// Skills dropped during merging because another repo provided the
+    /// same owner/name
+    pub shadowed: Vec<Shadowing>,
 }
 
 impl SkillIndex {
     /// Merge another index into this one. Skills already present are skipped
-    /// (first repo wins).
+    /// (first repo wins). Diagnostics are appended.
     pub fn merge(&mut self, other: SkillIndex) {
-        for (key, entry) in other.skills {
-            if self.skills.contains_key(&key) {
-                tracing::debug!(
-                    owner = %key.0,
-                    name = %key.1,
-                    "Skipping duplicate skill from secondary repo"
-                );
+        self.merge_with(other, &MergePolicy::default());
+    }
+
+    /// Merge another index into this one, letting `policy` decide between
+    /// skills with the same owner/name. Each loser is recorded in
+    /// [`shadowed`](Self::shadowed).
+    pub fn merge_with(&mut self, other: SkillIndex, policy: &MergePolicy) {
+        self.diagnostics.extend(other.diagnostics);
+        self.shadowed.extend(other.shadowed);
+
+        // Iterate in key order so shadowing reports are stable
+        let mut incoming: Vec<_> = other.skills.into_iter().collect();
+        incoming.sort_by(|a, b| a.0.cmp(&b.0));
+
+        for (key, entry) in incoming {
+            let Some(existing) = self.skills.get(&key) else {
+                self.count(&entry);
+                self.skills.insert(key, entry);
                 continue;
+            };
+
+            let (kept, dropped) = if policy.prefers(existing, &entry) {
+                let existing = self.skills.remove(&key).expect("checked above");
+                self.uncount(&existing);
+                self.count(&entry);
+                self.skills.insert(key.clone(), entry);
+                let kept = &self.skills[&key];
+
+                // Earlier records for this skill named the entry just
+                // replaced as the one kept
+                let winner = ShadowedSkill::from_entry(kept);
+                for record in self
+                    .shadowed
+                    .iter_mut()
+                    .filter(|s| s.owner == key.0 && s.name == key.1)
+                {
+                    record.kept = winner.clone();
+                }
+                (kept, existing)
+            } else {
+                (existing, entry)
+            };
+            tracing::debug!(
+                owner = %key.0,
+                name = %key.1,
+                kept = ?kept.origin,
+                dropped = ?dropped.origin,
+                policy = %policy.conflict,
+                "Shadowed duplicate skill"
+            );
+            self.shadowed.push(Shadowing {
+                owner: key.0,
+                name: key.1,
+                kept: ShadowedSkill::from_entry(kept),
+                dropped: ShadowedSkill::from_entry(&dropped),
+                policy: policy.conflict,
+            });
+        }
+    }
+
+    /// Qualify every skill's owner with a repo alias (`alias:owner`), so
+    /// these skills can sit alongside same-named skills from other repos.
+    pub fn qualify(&mut self, alias: &str) {
+        let skills = std::mem::take(&mut self.skills);
+        self.skills = skills
+            .into_values()
+            .map(|mut entry| {
+                entry.owner = qualify_owner(alias, &entry.owner);
+                ((entry.owner.clone(), entry.name.clone()), entry)
+            })
+            .collect();
+    }
+
+    /// Find a skill by owner and name.
+    ///
+    /// `owner` may be repo-qualified (`alias:owner`). A bare owner matches
+    /// an unaliased skill first, then an aliased one if exactly one repo
+    /// provides it.
+    pub fn lookup(&self, owner: &str, name: &str) -> Lookup<'_> {
+        if let Some(entry) = self.skills.get(&(owner.to_string(), name.to_string())) {
+            return Lookup::Found(entry);
+        }
+        if split_alias(owner).0.is_some() {
+            return Lookup::NotFound;
+        }
+
+        let mut matches: Vec<&SkillEntry> = self
+            .skills
+            .values()
+            .filter(|e| e.name == name && split_alias(&e.owner).1 == owner)
+            .collect();
+        match matches.len() {
+            0 => Lookup::NotFound,
+            1 => Lookup::Found(matches[0]),
+            _ => {
+                matches.sort_by(|a, b| a.owner.cmp(&b.owner));
+                Lookup::Ambiguous(
+                    matches
+                        .iter()
+                        .map(|e| format!("{}/{}", e.owner, e.name))
+                        .collect(),
+                )
             }
-            // Update category counts for the new entry
-            if let Some(v) = entry.latest()
-                && let Some(ref c) = v.metadata.skill.classification
-            {
-                for cat in &c.categories {
-                    *self.categories.entry(cat.clone()).or_insert(0) += 1;
+        }
+    }
+
+    /// Shadowing records involving `owner/name`.
+    pub fn shadowing_for(&self, owner: &str, name: &str) -> Vec<&Shadowing> {
+        self.shadowed
+            .iter()
+            .filter(|s| s.owner == owner && s.name == name)
+            .collect()
+    }
+
+    /// Recompute category and tag counts from the skills in the index.
+    pub fn recount(&mut self) {
+        self.categories.clear();
+        self.tags.clear();
+        let skills = std::mem::take(&mut self.skills);
+        for entry in skills.values() {
+            self.count(entry);
+        }
+        self.skills = skills;
+    }
+
+    /// Remove an entry's latest categories and tags from the counts.
+    fn uncount(&mut self, entry: &SkillEntry) {
+        if let Some(v) = entry.latest()
+            && let Some(ref c) = v.metadata.skill.classification
+        {
+            for (counts, labels) in [
+                (&mut self.categories, &c.categories),
+                (&mut self.tags, &c.tags),
+            ] {
+                for label in labels {
+                    if let Some(n) = counts.get_mut(label) {
+                        *n -= 1;
+                        if *n == 0 {
+                            counts.remove(label);
+                        }
+                    }
                 }
             }
-            self.skills.insert(key, entry);
         }
     }
+
+    /// Add an entry's latest categories and tags to the counts.
+    fn count(&mut self, entry: &SkillEntry) {
+        if let Some(v) = entry.latest()
+            && let Some(ref c) = v.metadata.skill.classification
+        {
+            for cat in &c.categories {
+                *self.categories.entry(cat.clone()).or_insert(0) += 1;
+            }
+            for tag in &c.tags {
+                *self.tags.entry(tag.clone()).or_insert(0) += 1;
+            }
+        }
+    }
+}
+
+/// Outcome of [`SkillIndex::lookup`].
+#[derive(Debug)]
+pub enum Lookup<'a> {
+    Found(&'a SkillEntry),
+    /// A bare `owner/name` matched skills from several aliased repos;
+    /// holds their qualified references.
+    Ambiguous(Vec<String>),
+    NotFound,
+}
+
+impl<'a> Lookup<'a> {
+    /// The entry, or a message explaining why there isn't one.
+    pub fn found(self, owner: &str, name: &str) -> Result<&'a SkillEntry, String> {
+        match self {
+            Lookup::Found(entry) => Ok(entry),
+            Lookup::Ambiguous(candidates) => Err(format!(
+                "'{owner}/{name}' is ambiguous; use one of: {}",
+                candidates.join(", ")
+            )),
+            Lookup::NotFound => Err(format!("skill '{owner}/{name}' not found in any repo")),
+        }
+    }
+}
+
+/// Whether `alias` can qualify skill owners: lowercase letters, digits and
+/// hyphens, so it never clashes with the `:` and `__` separators.
+pub fn is_valid_alias(alias: &str) -> bool {
+    !alias.is_empty()
+        && alias
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// `alias:owner`.
+pub fn qualify_owner(alias: &str, owner: &str) -> String {
+    format!("{alias}:{owner}")
+}
+
+/// Split a possibly repo-qualified owner into `(alias, owner)`.
+pub fn split_alias(owner: &str) -> (Option<&str>, &str) {
+    match owner.split_once(':') {
+        Some((alias, owner)) => (Some(alias), owner),
+        None => (None, owner),
+    }
+}
+
+/// MCP prompt name for a skill: `owner_name`, or `alias__owner_name` for
+/// repo-qualified owners (prompt names can't contain `:`).
+pub fn prompt_name(owner: &str, name: &str) -> String {
+    match split_alias(owner) {
+        (Some(alias), owner) => format!("{alias}__{owner}_{name}"),
+        (None, owner) => format!("{owner}_{name}"),
+    }
+}
+
+/// A skill dropped while merging because another repo provided the same
+/// owner/name.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Shadowing {
+    pub owner: String,
+    pub name: String,
+    /// The entry that stayed in the index
+    pub kept: ShadowedSkill,
+    /// The entry that was dropped
+    pub dropped: ShadowedSkill,
+    /// The policy that chose between them
+    pub policy: ConflictPolicy,
+}
+
+/// Where one side of a [`Shadowing`] came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShadowedSkill {
+    /// Repo directory, or None for skills embedded in a project
+    pub origin: Option<PathBuf>,
+    /// Latest version
+    pub version: String,
+    pub trust_tier: TrustTier,
+    /// Provenance chain, for skills found via the suggest graph
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub discovered_via: Vec<String>,
+}
+
+impl ShadowedSkill {
+    fn from_entry(entry: &SkillEntry) -> Self {
+        Self {
+            origin: entry.origin.clone(),
+            version: entry
+                .latest()
+                .map(|v| v.version.clone())
+                .unwrap_or_default(),
+            trust_tier: entry.trust_tier.clone(),
+            discovered_via: entry.discovered_via.clone(),
+        }
+    }
+
+    /// Where the skill came from, for display.
+    pub fn source_label(&self) -> String {
+        match (&self.origin, self.discovered_via.last()) {
+            (_, Some(url)) => url.clone(),
+            (Some(origin), None) => origin.display().to_string(),
+            (None, None) => "embedded project".to_string(),
+        }
+    }
+}
+
+/// How serious a diagnostic is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+/// What went wrong while loading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiagnosticKind {
+    /// A skill directory couldn't be loaded and was skipped
+    InvalidSkill,
+    /// A skill with the same owner and name was already loaded
+    DuplicateSkill,
+    /// A repo (or a skills directory in it) couldn't be read
+    RepoUnreadable,
+    /// A suggested repo couldn't be cloned or pulled
+    CloneFailed,
+    /// A repo was cloned but its skills couldn't be indexed
+    IndexFailed,
+}
+
+impl std::fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            DiagnosticKind::InvalidSkill => "invalid_skill",
+            DiagnosticKind::DuplicateSkill => "duplicate_skill",
+            DiagnosticKind::RepoUnreadable => "repo_unreadable",
+            DiagnosticKind::CloneFailed => "clone_failed",
+            DiagnosticKind::IndexFailed => "index_failed",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// A problem encountered while loading skills, kept so callers can report
+/// what was skipped instead of it only reaching the log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LoadDiagnostic {
+    /// Skill directory or repo path (or URL, for remote repos)
+    pub path: PathBuf,
+    pub severity: Severity,
+    pub kind: DiagnosticKind,
+    pub message: String,
+}
+
+impl LoadDiagnostic {
+    pub fn error(
+        kind: DiagnosticKind,
+        path: impl Into<PathBuf>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            severity: Severity::Error,
+            kind,
+            message: message.into(),
+        }
+    }
+
+    pub fn warning(
+        kind: DiagnosticKind,
+        path: impl Into<PathBuf>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            severity: Severity::Warning,
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for LoadDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: {}: {} [{}]",
+            self.path.display(),
+            self.severity,
+            self.message,
+            self.kind
+        )
+    }
 }
 
 /// Where a skill was discovered from.
@@ -110,6 +483,13 @@ pub enum SkillSource {
         /// Absolute path to the skill directory on disk
         path: PathBuf,
     },
+    /// Imported from another agent's instruction file (see
+    /// [`crate::import`])
+    Imported {
+        format: ImportFormat,
+        /// Absolute path to the source file
+        path: PathBuf,
+    },
 }
 
 impl SkillSource {
@@ -118,6 +498,7 @@ impl SkillSource {
         match self {
             Self::Repo => None,
             Self::Embedded { project, .. } => Some(format!("embedded ({project})")),
+            Self::Imported { format, .. } => Some(format!("imported ({format})")),
         }
     }
 
@@ -125,7 +506,7 @@ impl SkillSource {
     pub fn path(&self) -> Option<&Path> {
         match self {
             Self::Repo => None,
-            Self::Embedded { path, .. } => Some(path),
+            Self::Embedded { path, .. } | Self::Imported { path, .. } => Some(path),
         }
     }
 }
@@ -175,6 +556,10 @@ pub struct SkillEntry {
     /// Empty for directly configured repos.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub discovered_via: Vec<String>,
+    /// Repo directory this skill was loaded from (the path given to
+    /// `index::load_index`). None for skills embedded in a project.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub origin: Option<PathBuf>,
 }
 
 impl SkillEntry {
@@ -220,16 +605,145 @@ pub struct VersionRecord {
 /// An extra file in a skillpack
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillFile {
+    /// File content; base64-encoded when `binary` is set
     pub content: String,
     pub mime_type: String,
+    /// Whether `content` holds base64-encoded bytes (non-UTF-8 files)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub binary: bool,
+}
+
+impl SkillFile {
+    /// A file from raw bytes: UTF-8 text is kept as is, anything else is
+    /// stored base64-encoded.
+    pub fn from_bytes(bytes: Vec<u8>, mime_type: String) -> Self {
+        match String::from_utf8(bytes) {
+            Ok(content) => Self {
+                content,
+                mime_type,
+                binary: false,
+            },
+            Err(e) => Self {
+                content: BASE64.encode(e.as_bytes()),
+                mime_type,
+                binary: true,
+            },
+        }
+    }
+
+    /// The file's raw bytes, decoding base64 content.
+    pub fn bytes(&self) -> Result<Vec<u8>, base64::DecodeError> {
+        if self.binary {
+            BASE64.decode(&self.content)
+        } else {
+            Ok(self.content.as_bytes().to_vec())
+        }
+    }
 }
 
 /// Parsed skill.toml metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillMetadata {
     pub skill: SkillInfo,
+    /// Every SKILL.md frontmatter field as written, including ones skillet
+    /// doesn't model (`metadata:` keys, client-specific fields)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub frontmatter: BTreeMap<String, serde_json::Value>,
+}
+
+impl SkillMetadata {
+    /// Text of a frontmatter key, top-level or nested under `metadata`.
+    ///
+    /// Scalars are returned as-is and lists are joined with spaces;
+    /// nested mappings yield `None`.
+    pub fn frontmatter_text(&self, key: &str) -> Option<String> {
+        let value = self
+            .frontmatter
+            .get(key)
+            .or_else(|| self.frontmatter.get("metadata").and_then(|m| m.get(key)))?;
+        let scalar = |v: &serde_json::Value| match v {
+            serde_json::Value::String(s) => Some(s.trim().to_string()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            serde_json::Value::Bool(b) => Some(b.to_string()),
+            _ => None,
+        };
+        match value {
+            serde_json::Value::Array(items) => Some(
+                items
+                    .iter()
+                    .filter_map(scalar)
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ),
+            other => scalar(other),
+        }
+    }
+
+    /// Frontmatter fields skillet doesn't model itself: unknown top-level
+    /// keys and everything under `metadata:` except the fields it reads
+    /// from there. Values are rendered as by [`Self::frontmatter_text`].
+    pub fn custom_metadata(&self) -> Vec<(String, String)> {
+        let nested = self
+            .frontmatter
+            .get("metadata")
+            .and_then(|m| m.as_object())
+            .into_iter()
+            .flat_map(|m| m.keys());
+        let mut out: Vec<(String, String)> = self
+            .frontmatter
+            .keys()
+            .chain(nested)
+            .filter(|k| !KNOWN_FRONTMATTER_FIELDS.contains(&k.as_str()))
+            .filter_map(|k| Some((k.clone(), self.frontmatter_text(k)?)))
+            .collect();
+        out.sort();
+        out.dedup_by(|a, b| a.0 == b.0);
+        out
+    }
+
+    /// Whether frontmatter key `key` matches `value` (case-insensitive).
+    /// List values match if any item does.
+    pub fn frontmatter_matches(&self, key: &str, value: &str) -> bool {
+        let value = value.to_lowercase();
+        let Some(found) = self
+            .frontmatter
+            .get(key)
+            .or_else(|| self.frontmatter.get("metadata").and_then(|m| m.get(key)))
+        else {
+            return false;
+        };
+        let matches = |v: &serde_json::Value| match v {
+            serde_json::Value::String(s) => s.trim().to_lowercase() == value,
+            serde_json::Value::Number(n) => n.to_string() == value,
+            serde_json::Value::Bool(b) => b.to_string() == value,
+            _ => false,
+        };
+        match found {
+            serde_json::Value::Array(items) => items.iter().any(matches),
+            other => matches(other),
+        }
+    }
 }
 
+/// Frontmatter fields skillet models explicitly, as opposed to custom
+/// metadata: the Agent Skills spec fields first, then skillet's own.
+pub const KNOWN_FRONTMATTER_FIELDS: &[&str] = &[
+    "name",
+    "description",
+    "license",
+    "allowed-tools",
+    "metadata",
+    "compatibility",
+    "version",
+    "trigger",
+    "tags",
+    "categories",
+    "author",
+    "language",
+    "files",
+    "model",
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillInfo {
     pub name: String,
@@ -246,6 +760,79 @@ pub struct SkillInfo {
     pub classification: Option<Classification>,
     #[serde(default)]
     pub compatibility: Option<Compatibility>,
+    /// Natural language the skill is written in (e.g. "en", "ja"), used to
+    /// pick the search stemmer.
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Which files ship with the skill (`[skill.files]`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub files: Option<FilePatterns>,
+    /// When an agent should apply the skill, for imported rule formats
+    /// that say so
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activation: Option<Activation>,
+    /// Tools the skill may use without asking (`allowed-tools`)
+    #[serde(
+        default,
+        alias = "allowed-tools",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub allowed_tools: Option<Vec<String>>,
+    /// Model the skill should run with (`model`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
+/// Activation metadata carried over from Cursor rules and Copilot
+/// instructions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Activation {
+    pub mode: ActivationMode,
+    /// File globs the skill applies to (for `auto-attached`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub globs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ActivationMode {
+    /// Applied to every request
+    Always,
+    /// Applied when working on files matching `globs`
+    AutoAttached,
+    /// Applied when the agent judges the description relevant
+    AgentRequested,
+    /// Applied only when referenced explicitly
+    Manual,
+}
+
+impl std::fmt::Display for Activation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mode = match self.mode {
+            ActivationMode::Always => "always",
+            ActivationMode::AutoAttached => "auto-attached",
+            ActivationMode::AgentRequested => "agent-requested",
+            ActivationMode::Manual => "manual",
+        };
+        if self.globs.is_empty() {
+            f.write_str(mode)
+        } else {
+            write!(f, "{mode} ({})", self.globs.join(", "))
+        }
+    }
+}
+
+/// Include/exclude globs selecting the files that ship with a skill.
+///
+/// Patterns are relative to the skill directory (see [`crate::glob`]). An
+/// empty `include` means the default [`crate::index::EXTRA_DIRS`] folders;
+/// `exclude` applies either way.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FilePatterns {
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -324,6 +911,9 @@ pub struct SkillSummary {
     /// Provenance chain of repo URLs
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub discovered_via: Vec<String>,
+    /// Why the consuming agent can't use this skill (see [`crate::compat`])
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub incompatible: Vec<String>,
 }
 
 impl SkillSummary {
@@ -361,10 +951,79 @@ impl SkillSummary {
                 ref t => Some(t.to_string()),
             },
             discovered_via: entry.discovered_via.clone(),
+            incompatible: Vec::new(),
         })
     }
 }
 
+/// Value counts over a set of search results, for narrowing a search.
+///
+/// Each facet is sorted by count (highest first), then by value.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Facets {
+    pub categories: Vec<(String, usize)>,
+    pub tags: Vec<(String, usize)>,
+    pub owners: Vec<(String, usize)>,
+    pub trust_tiers: Vec<(String, usize)>,
+    pub verified_with: Vec<(String, usize)>,
+}
+
+impl Facets {
+    /// Count facet values across `summaries`.
+    pub fn from_summaries(summaries: &[SkillSummary]) -> Self {
+        Self {
+            categories: count_values(summaries.iter().flat_map(|s| &s.categories)),
+            tags: count_values(summaries.iter().flat_map(|s| &s.tags)),
+            owners: count_values(summaries.iter().map(|s| &s.owner)),
+            trust_tiers: count_values(summaries.iter().map(|s| {
+                s.trust_tier
+                    .clone()
+                    .unwrap_or_else(|| TrustTier::Direct.to_string())
+            })),
+            verified_with: count_values(summaries.iter().flat_map(|s| &s.verified_with)),
+        }
+    }
+
+    /// Named facets in display order, skipping empty ones.
+    pub fn named(&self) -> Vec<(&'static str, &[(String, usize)])> {
+        [
+            ("categories", self.categories.as_slice()),
+            ("tags", &self.tags),
+            ("owners", &self.owners),
+            ("trust", &self.trust_tiers),
+            ("verified with", &self.verified_with),
+        ]
+        .into_iter()
+        .filter(|(_, counts)| !counts.is_empty())
+        .collect()
+    }
+
+    /// Render counts as `value (n), value (n)`, keeping the top `limit`.
+    pub fn format_counts(counts: &[(String, usize)], limit: usize) -> String {
+        let mut out = counts
+            .iter()
+            .take(limit)
+            .map(|(value, n)| format!("{value} ({n})"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if counts.len() > limit {
+            out.push_str(&format!(", +{} more", counts.len() - limit));
+        }
+        out
+    }
+}
+
+/// Count occurrences of each value, highest count first.
+fn count_values<S: AsRef<str>>(values: impl Iterator<Item = S>) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for value in values {
+        *counts.entry(value.as_ref().to_string()).or_default() += 1;
+    }
+    let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -384,7 +1043,13 @@ mod tests {
                     author: None,
                     classification: None,
                     compatibility: None,
+                    language: None,
+                    files: None,
+                    activation: None,
+                    allowed_tools: None,
+                    model: None,
                 },
+                frontmatter: Default::default(),
             },
             skill_md: "# Test".to_string(),
             skill_toml_raw: String::new(),
@@ -405,6 +1070,7 @@ mod tests {
             source: SkillSource::Repo,
             trust_tier: Default::default(),
             discovered_via: Vec::new(),
+            origin: None,
         }
     }
 
@@ -487,6 +1153,88 @@ mod tests {
         assert_eq!(primary.skills.len(), 1);
         let entry = primary.skills.get(&("acme".into(), "tool".into())).unwrap();
         assert_eq!(entry.latest().unwrap().version, "1.0.0");
+
+        assert_eq!(primary.shadowed.len(), 1);
+        let shadow = &primary.shadowed[0];
+        assert_eq!(shadow.kept.version, "1.0.0");
+        assert_eq!(shadow.dropped.version, "2.0.0");
+        assert_eq!(shadow.policy, ConflictPolicy::FirstWins);
+    }
+
+    #[test]
+    fn merge_with_highest_version_replaces_and_recounts() {
+        let tagged = |version: &str, tag: &str| {
+            let mut v = make_version(version, "tool", false);
+            v.metadata.skill.classification = Some(Classification {
+                categories: vec![],
+                tags: vec![tag.into()],
+            });
+            v
+        };
+
+        let mut primary = SkillIndex::default();
+        primary.merge(SkillIndex {
+            skills: [(
+                ("acme".into(), "tool".into()),
+                make_entry("acme", "tool", vec![tagged("1.0.0", "old")]),
+            )]
+            .into(),
+            ..Default::default()
+        });
+
+        let policy = MergePolicy {
+            conflict: ConflictPolicy::HighestVersion,
+            priority: vec![],
+        };
+        primary.merge_with(
+            SkillIndex {
+                skills: [(
+                    ("acme".into(), "tool".into()),
+                    make_entry("acme", "tool", vec![tagged("1.2.0", "new")]),
+                )]
+                .into(),
+                ..Default::default()
+            },
+            &policy,
+        );
+
+        let entry = primary.skills.get(&("acme".into(), "tool".into())).unwrap();
+        assert_eq!(entry.latest().unwrap().version, "1.2.0");
+        assert_eq!(primary.tags.get("new"), Some(&1));
+        assert!(!primary.tags.contains_key("old"));
+
+        let shadows = primary.shadowing_for("acme", "tool");
+        assert_eq!(shadows.len(), 1);
+        assert_eq!(shadows[0].kept.version, "1.2.0");
+        assert_eq!(shadows[0].dropped.version, "1.0.0");
+    }
+
+    #[test]
+    fn merge_with_updates_earlier_shadowing_when_winner_changes() {
+        let repo = |version: &str| SkillIndex {
+            skills: [(
+                ("acme".into(), "tool".into()),
+                make_entry("acme", "tool", vec![make_version(version, "tool", false)]),
+            )]
+            .into(),
+            ..Default::default()
+        };
+        let policy = MergePolicy {
+            conflict: ConflictPolicy::HighestVersion,
+            priority: vec![],
+        };
+
+        let mut merged = SkillIndex::default();
+        merged.merge_with(repo("1.0.0"), &policy);
+        merged.merge_with(repo("1.1.0"), &policy);
+        merged.merge_with(repo("1.2.0"), &policy);
+
+        let shadows = merged.shadowing_for("acme", "tool");
+        let pairs: Vec<(&str, &str)> = shadows
+            .iter()
+            .map(|s| (s.kept.version.as_str(), s.dropped.version.as_str()))
+            .collect();
+        assert_eq!(pairs, vec![("1.2.0", "1.0.0"), ("1.2.0", "1.1.0")]);
     }
 
     #[test]
@@ -543,6 +1291,92 @@ mod tests {
         assert_eq!(primary.categories.get("caching"), Some(&1));
     }
 
+    #[test]
+    fn qualify_keeps_same_named_skills_apart() {
+        let repo = |alias: &str| {
+            let mut idx = SkillIndex::default();
+            idx.skills.insert(
+                ("acme".into(), "tool".into()),
+                make_entry("acme", "tool", vec![make_version("1.0.0", alias, false)]),
+            );
+            idx.qualify(alias);
+            idx
+        };
+
+        let mut merged = SkillIndex::default();
+        merged.merge(repo("main"));
+        merged.merge(repo("fork"));
+
+        assert_eq!(merged.skills.len(), 2);
+        assert!(merged.shadowed.is_empty());
+        assert!(
+            merged
+                .skills
+                .contains_key(&("fork:acme".into(), "tool".into()))
+        );
+        assert!(matches!(
+            merged.lookup("fork:acme", "tool"),
+            Lookup::Found(e) if e.owner == "fork:acme"
+        ));
+        assert!(matches!(
+            merged.lookup("other:acme", "tool"),
+            Lookup::NotFound
+        ));
+
+        let err = merged
+            .lookup("acme", "tool")
+            .found("acme", "tool")
+            .unwrap_err();
+        assert_eq!(
+            err,
+            "'acme/tool' is ambiguous; use one of: fork:acme/tool, main:acme/tool"
+        );
+    }
+
+    #[test]
+    fn lookup_bare_owner_prefers_unaliased() {
+        let mut index = SkillIndex::default();
+        index.skills.insert(
+            ("acme".into(), "tool".into()),
+            make_entry("acme", "tool", vec![make_version("1.0.0", "main", false)]),
+        );
+        index.skills.insert(
+            ("fork:acme".into(), "tool".into()),
+            make_entry(
+                "fork:acme",
+                "tool",
+                vec![make_version("1.0.0", "fork", false)],
+            ),
+        );
+        index.skills.insert(
+            ("fork:acme".into(), "extra".into()),
+            make_entry(
+                "fork:acme",
+                "extra",
+                vec![make_version("1.0.0", "fork", false)],
+            ),
+        );
+
+        assert!(matches!(index.lookup("acme", "tool"), Lookup::Found(e) if e.owner == "acme"));
+        assert!(
+            matches!(index.lookup("acme", "extra"), Lookup::Found(e) if e.owner == "fork:acme")
+        );
+        assert!(matches!(index.lookup("acme", "missing"), Lookup::NotFound));
+    }
+
+    #[test]
+    fn prompt_names_and_aliases() {
+        assert_eq!(prompt_name("acme", "tool"), "acme_tool");
+        assert_eq!(prompt_name("fork:acme", "tool"), "fork__acme_tool");
+        assert_eq!(split_alias("fork:acme"), (Some("fork"), "acme"));
+        assert_eq!(split_alias("acme"), (None, "acme"));
+        assert!(is_valid_alias("my-fork2"));
+        assert!(!is_valid_alias("Fork"));
+        assert!(!is_valid_alias("a:b"));
+        assert!(!is_valid_alias("a_b"));
+        assert!(!is_valid_alias(""));
+    }
+
     #[test]
     fn merge_accumulates_categories_across_skills() {
         let mut primary = SkillIndex::default();
@@ -647,6 +1481,7 @@ mod tests {
             SkillFile {
                 content: "#!/bin/bash".into(),
                 mime_type: "text/x-shellscript".into(),
+                binary: false,
             },
         );
         version.files.insert(
@@ -654,6 +1489,7 @@ mod tests {
             SkillFile {
                 content: "# Guide".into(),
                 mime_type: "text/markdown".into(),
+                binary: false,
             },
         );
         let entry = make_entry("acme", "tool", vec![version]);
@@ -687,6 +1523,74 @@ mod tests {
         assert!(SkillSummary::from_entry(&entry).is_none());
     }
 
+    #[test]
+    fn merge_and_recount_track_tags() {
+        let mut v = make_version("1.0.0", "a", false);
+        v.metadata.skill.classification = Some(Classification {
+            categories: vec!["database".into()],
+            tags: vec!["redis".into(), "cache".into()],
+        });
+        let mut other = SkillIndex::default();
+        other.skills.insert(
+            ("acme".into(), "a".into()),
+            make_entry("acme", "a", vec![v]),
+        );
+
+        let mut index = SkillIndex::default();
+        index.merge(other);
+        assert_eq!(index.tags.get("redis"), Some(&1));
+
+        index.tags.clear();
+        index.categories.insert("stale".into(), 3);
+        index.recount();
+        assert_eq!(index.tags.get("cache"), Some(&1));
+        assert_eq!(index.categories.get("database"), Some(&1));
+        assert!(!index.categories.contains_key("stale"));
+    }
+
+    // -- Facets --
+
+    #[test]
+    fn facets_count_and_sort() {
+        let mut a = make_version("1.0.0", "a", false);
+        a.metadata.skill.classification = Some(Classification {
+            categories: vec!["testing".to_string(), "ci".to_string()],
+            tags: vec!["rust".to_string()],
+        });
+        let mut b = make_version("1.0.0", "b", false);
+        b.metadata.skill.classification = Some(Classification {
+            categories: vec!["testing".to_string()],
+            tags: Vec::new(),
+        });
+        let mut suggested = make_entry("other", "b", vec![b]);
+        suggested.trust_tier = TrustTier::Suggested;
+
+        let summaries: Vec<SkillSummary> = [make_entry("acme", "a", vec![a]), suggested]
+            .iter()
+            .filter_map(SkillSummary::from_entry)
+            .collect();
+        let facets = Facets::from_summaries(&summaries);
+
+        assert_eq!(
+            facets.categories,
+            vec![("testing".to_string(), 2), ("ci".to_string(), 1)]
+        );
+        assert_eq!(facets.tags, vec![("rust".to_string(), 1)]);
+        assert_eq!(
+            facets.trust_tiers,
+            vec![("direct".to_string(), 1), ("suggested".to_string(), 1)]
+        );
+        assert!(facets.verified_with.is_empty());
+        assert_eq!(
+            facets.named().iter().map(|(n, _)| *n).collect::<Vec<_>>(),
+            vec!["categories", "tags", "owners", "trust"]
+        );
+        assert_eq!(
+            Facets::format_counts(&facets.categories, 1),
+            "testing (2), +1 more"
+        );
+    }
+
     // -- ServerConfig default --
 
     #[test]
@@ -5,13 +5,17 @@
 //! configurable safety limits (fan-out, total repos, clone timeout, negative caching)
 //! and stamps each discovered skill with a hop-based trust tier.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
 use crate::cache::{self, RepoSource};
-use crate::config::{SourcePin, SuggestConfig};
-use crate::state::{SkillIndex, TrustTier};
+use crate::config::{ReposConfig, SourcePin, SuggestConfig};
+use crate::conflict::MergePolicy;
+use crate::index::FileLimits;
+use crate::project::SuggestEntry;
+use crate::repo::RepoSettings;
+use crate::state::{DiagnosticKind, LoadDiagnostic, SkillIndex, TrustTier};
 use crate::{git, index, project, resolve};
 
 /// Normalize a git URL for deduplication.
@@ -128,6 +132,33 @@ pub struct SuggestWalker {
     negative_cache: NegativeCache,
     total_cloned: usize,
     consumer_pins: Vec<SourcePin>,
+    concurrency: usize,
+    merge_policy: MergePolicy,
+    repo_settings: RepoSettings,
+    file_limits: FileLimits,
+    discovered: Vec<DiscoveredRepo>,
+}
+
+/// A repo reached through the suggest graph.
+#[derive(Debug, Clone)]
+pub struct DiscoveredRepo {
+    /// Indexed path: the checkout, or its configured subdir.
+    pub path: PathBuf,
+    /// The remote the repo was cloned from, for cache keys and
+    /// URL-keyed `[repos]` settings.
+    pub source: RepoSource,
+    /// Trust tier stamped on the repo's skills.
+    pub trust_tier: TrustTier,
+    /// Suggest chain that led to the repo.
+    pub discovered_via: Vec<String>,
+}
+
+impl DiscoveredRepo {
+    /// Stamp this repo's trust tier and provenance on a freshly loaded
+    /// index of it, as the walk did.
+    pub fn stamp(&self, index: &mut SkillIndex) {
+        stamp_trust(index, &self.trust_tier, &self.discovered_via);
+    }
 }
 
 impl SuggestWalker {
@@ -154,14 +185,54 @@ impl SuggestWalker {
             negative_cache: NegativeCache::new(neg_ttl),
             total_cloned: 0,
             consumer_pins,
+            concurrency: ReposConfig::default().concurrency,
+            merge_policy: MergePolicy::default(),
+            repo_settings: RepoSettings::default(),
+            file_limits: FileLimits::default(),
+            discovered: Vec::new(),
         }
     }
 
+    /// Clone and index up to `concurrency` suggestions at once (default: 4).
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Resolve conflicts with already-loaded skills using `policy`
+    /// (default: first-wins).
+    pub fn with_merge_policy(mut self, policy: MergePolicy) -> Self {
+        self.merge_policy = policy;
+        self
+    }
+
+    /// Apply `[repos.aliases]` and `[repos.import]` to suggested repos.
+    pub fn with_repo_settings(mut self, settings: RepoSettings) -> Self {
+        self.repo_settings = settings;
+        self
+    }
+
+    /// Size caps for suggested repos' skillpack files (default:
+    /// [`FileLimits::default`]).
+    pub fn with_file_limits(mut self, limits: FileLimits) -> Self {
+        self.file_limits = limits;
+        self
+    }
+
+    /// Every repo loaded so far, in merge order.
+    pub fn discovered(&self) -> &[DiscoveredRepo] {
+        &self.discovered
+    }
+
     /// Walk the suggest graph starting from the given repo paths.
     ///
     /// Discovers `[[suggest]]` entries in each repo's `skillet.toml`, clones them,
     /// indexes their skills, and merges into `merged`. Recurses up to `depth` levels.
     ///
+    /// Suggestions within a level are cloned and indexed in parallel, then
+    /// merged in the order a sequential walk would visit them, so conflicts
+    /// resolve the same way regardless of which clone finishes first.
+    ///
     /// Each discovered skill is stamped with the appropriate `TrustTier` and
     /// `discovered_via` provenance chain.
     pub fn walk(
@@ -193,28 +264,106 @@ impl SuggestWalker {
         let clone_timeout = crate::repo::parse_duration(&self.config.clone_timeout)
             .unwrap_or(Duration::from_secs(30));
 
-        let mut new_suggestions = Vec::new();
+        // Each repo's suggestions, numbered in manifest order
+        let mut pending: Vec<VecDeque<(usize, SuggestEntry)>> = repo_paths
+            .iter()
+            .map(|path| suggestions_from(path).into_iter().enumerate().collect())
+            .collect();
+        let mut followed = vec![0; pending.len()];
+        let mut loaded = Vec::new();
+
+        // Failed clones don't count toward the limits, so keep picking
+        // batches until nothing more fits.
+        loop {
+            let batch = self.next_batch(&mut pending, &followed, depth, &provenance);
+            if batch.is_empty() {
+                break;
+            }
 
-        for path in repo_paths {
-            let root = find_repo_root(path).or_else(|| path.parent().and_then(find_repo_root));
+            let results = crate::repo::parallel_map(&batch, self.concurrency, |(_, entry)| {
+                self.fetch(entry, clone_timeout)
+            });
+
+            for ((pos, entry), fetched) in batch.into_iter().zip(results) {
+                match fetched {
+                    Fetched::Skipped => {}
+                    Fetched::CloneFailed(e) => {
+                        self.negative_cache
+                            .record_failure(&canonicalize_url(&entry.url));
+                        merged.diagnostics.push(LoadDiagnostic::error(
+                            DiagnosticKind::CloneFailed,
+                            &entry.url,
+                            format!("couldn't clone suggested repo: {e}"),
+                        ));
+                    }
+                    Fetched::IndexFailed(e) => {
+                        self.total_cloned += 1;
+                        followed[pos.0] += 1;
+                        self.negative_cache
+                            .record_failure(&canonicalize_url(&entry.url));
+                        merged.diagnostics.push(LoadDiagnostic::error(
+                            DiagnosticKind::IndexFailed,
+                            &entry.url,
+                            format!("couldn't index suggested repo: {e}"),
+                        ));
+                    }
+                    Fetched::Loaded { path, index } => {
+                        self.total_cloned += 1;
+                        followed[pos.0] += 1;
+                        loaded.push((pos, entry.url, path, index));
+                    }
+                }
+            }
+        }
 
-            let root = match root {
-                Some(r) => r,
-                None => continue,
+        // Merge in visiting order: source repo first, then manifest position
+        loaded.sort_by_key(|(pos, ..)| *pos);
+        let mut new_suggestions = Vec::new();
+        for (_, url, path, mut idx) in loaded {
+            let mut entry_provenance = provenance.clone();
+            let source = RepoSource::Remote {
+                url: url.clone(),
+                checkout: path.clone(),
             };
+            self.repo_settings.apply(&source, &path, &mut idx);
+            entry_provenance.push(url);
+            stamp_trust(&mut idx, &trust_tier, &entry_provenance);
+            merged.merge_with(idx, &self.merge_policy);
+            all_paths.push(path.clone());
+            self.discovered.push(DiscoveredRepo {
+                path: path.clone(),
+                source,
+                trust_tier: trust_tier.clone(),
+                discovered_via: entry_provenance,
+            });
+            new_suggestions.push(path);
+        }
 
-            let manifest = match project::load_skillet_toml(&root) {
-                Ok(Some(m)) => m,
-                _ => continue,
-            };
+        // Recurse for the newly added repos
+        if !new_suggestions.is_empty() && depth > 1 {
+            self.walk(&new_suggestions, merged, all_paths, depth - 1, provenance);
+        }
+    }
 
-            let mut followed_from_this_repo = 0;
+    /// Take the next suggestions to follow from `pending`, honoring the
+    /// per-repo fan-out and total repo limits given what has already been
+    /// `followed`. Marks each taken URL as visited.
+    fn next_batch(
+        &mut self,
+        pending: &mut [VecDeque<(usize, SuggestEntry)>],
+        followed: &[usize],
+        depth: u32,
+        provenance: &[String],
+    ) -> Vec<((usize, usize), SuggestEntry)> {
+        let mut batch = Vec::new();
+
+        for (i, queue) in pending.iter_mut().enumerate() {
+            let mut taken = followed[i];
 
-            for entry in &manifest.suggest {
+            while !queue.is_empty() {
                 // Per-repo fan-out limit
-                if followed_from_this_repo >= self.config.max_per_repo {
+                if taken >= self.config.max_per_repo {
                     tracing::debug!(
-                        repo = %root.display(),
                         max = self.config.max_per_repo,
                         "Suggest graph: per-repo fan-out limit reached"
                     );
@@ -222,14 +371,18 @@ impl SuggestWalker {
                 }
 
                 // Total repo cap
-                if self.total_cloned >= self.config.max_repos {
+                if self.total_cloned + batch.len() >= self.config.max_repos {
                     tracing::info!(
                         max = self.config.max_repos,
                         "Suggest graph: total repo cap reached"
                     );
-                    return;
+                    return batch;
                 }
 
+                let Some((j, entry)) = queue.pop_front() else {
+                    break;
+                };
+
                 if entry.url.is_empty() {
                     continue;
                 }
@@ -272,83 +425,92 @@ impl SuggestWalker {
                     "Following suggestion"
                 );
 
-                let target = crate::repo::cache_dir_for_url(&self.cache_base, &entry.url);
-                if let Some(parent) = target.parent()
-                    && let Err(e) = std::fs::create_dir_all(parent)
-                {
-                    tracing::warn!(url = %entry.url, error = %e, "Failed to create cache dir");
-                    continue;
-                }
+                taken += 1;
+                batch.push(((i, j), entry));
+            }
+        }
 
-                if let Err(e) = git::clone_or_pull_with_timeout(&entry.url, &target, clone_timeout)
-                {
-                    tracing::warn!(url = %entry.url, error = %e, "Failed to clone suggested repo");
-                    self.negative_cache.record_failure(&canonical);
-                    continue;
-                }
+        batch
+    }
 
-                // Resolve release model: checkout appropriate tag/ref
-                if let Err(e) =
-                    resolve::resolve_and_checkout(&target, &entry.url, &self.consumer_pins)
-                {
-                    tracing::warn!(
-                        url = %entry.url,
-                        error = %e,
-                        "Failed to resolve release ref, using default branch"
-                    );
-                }
+    /// Clone, check out and index one suggested repo.
+    fn fetch(&self, entry: &SuggestEntry, clone_timeout: Duration) -> Fetched {
+        let target = crate::repo::cache_dir_for_url(&self.cache_base, &entry.url);
+        if let Some(parent) = target.parent()
+            && let Err(e) = std::fs::create_dir_all(parent)
+        {
+            tracing::warn!(url = %entry.url, error = %e, "Failed to create cache dir");
+            return Fetched::Skipped;
+        }
 
-                self.total_cloned += 1;
-                followed_from_this_repo += 1;
+        if let Err(e) = git::clone_or_pull_with_timeout(&entry.url, &target, clone_timeout) {
+            tracing::warn!(url = %entry.url, error = %e, "Failed to clone suggested repo");
+            return Fetched::CloneFailed(e.to_string());
+        }
 
-                let skill_path = match &entry.subdir {
-                    Some(sub) => target.join(sub),
-                    None => target.clone(),
-                };
+        // Resolve release model: checkout appropriate tag/ref
+        if let Err(e) = resolve::resolve_and_checkout(&target, &entry.url, &self.consumer_pins) {
+            tracing::warn!(
+                url = %entry.url,
+                error = %e,
+                "Failed to resolve release ref, using default branch"
+            );
+        }
 
-                let source = RepoSource::Remote {
-                    url: entry.url.clone(),
-                    checkout: target,
-                };
+        let path = match &entry.subdir {
+            Some(sub) => target.join(sub),
+            None => target.clone(),
+        };
 
-                let mut entry_provenance = provenance.clone();
-                entry_provenance.push(entry.url.clone());
+        let source = RepoSource::Remote {
+            url: entry.url.clone(),
+            checkout: target,
+        };
 
-                if self.cache_enabled
-                    && let Some(mut idx) = cache::load(&source, self.cache_ttl)
-                {
-                    stamp_trust(&mut idx, &trust_tier, &entry_provenance);
-                    merged.merge(idx);
-                    all_paths.push(skill_path.clone());
-                    new_suggestions.push(skill_path);
-                    continue;
-                }
+        if self.cache_enabled
+            && let Some(index) = cache::load(&source, self.cache_ttl)
+        {
+            return Fetched::Loaded { path, index };
+        }
 
-                match index::load_index(&skill_path) {
-                    Ok(mut idx) => {
-                        if self.cache_enabled {
-                            cache::write(&source, &idx);
-                        }
-                        stamp_trust(&mut idx, &trust_tier, &entry_provenance);
-                        merged.merge(idx);
-                        all_paths.push(skill_path.clone());
-                        new_suggestions.push(skill_path);
-                    }
-                    Err(e) => {
-                        tracing::warn!(url = %entry.url, error = %e, "Failed to index suggested repo");
-                        self.negative_cache.record_failure(&canonical);
-                    }
+        match index::load_index(&path, &self.file_limits) {
+            Ok(index) => {
+                if self.cache_enabled {
+                    cache::write(&source, &index);
                 }
+                Fetched::Loaded { path, index }
+            }
+            Err(e) => {
+                tracing::warn!(url = %entry.url, error = %e, "Failed to index suggested repo");
+                Fetched::IndexFailed(e.to_string())
             }
-        }
-
-        // Recurse for the newly added repos
-        if !new_suggestions.is_empty() && depth > 1 {
-            self.walk(&new_suggestions, merged, all_paths, depth - 1, provenance);
         }
     }
 }
 
+/// Outcome of [`SuggestWalker::fetch`].
+enum Fetched {
+    /// Not attempted; doesn't count toward limits.
+    Skipped,
+    /// Clone or pull failed; doesn't count toward limits.
+    CloneFailed(String),
+    /// Cloned, but the skills couldn't be indexed.
+    IndexFailed(String),
+    Loaded {
+        path: PathBuf,
+        index: SkillIndex,
+    },
+}
+
+/// The `[[suggest]]` entries of the repo containing `path`, if it has a
+/// `skillet.toml`.
+fn suggestions_from(path: &Path) -> Vec<SuggestEntry> {
+    let root = find_repo_root(path).or_else(|| path.parent().and_then(find_repo_root));
+    root.and_then(|root| project::load_skillet_toml(&root).ok().flatten())
+        .map(|manifest| manifest.suggest)
+        .unwrap_or_default()
+}
+
 /// Stamp trust tier and provenance on all entries in an index.
 fn stamp_trust(index: &mut SkillIndex, tier: &TrustTier, provenance: &[String]) {
     for entry in index.skills.values_mut() {
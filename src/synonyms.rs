@@ -0,0 +1,288 @@
+//! Query-time synonym and alias expansion for skill search.
+//!
+//! Teams and skill authors rarely agree on vocabulary ("k8s" vs
+//! "kubernetes", "pg" vs "postgres"). A `SynonymMap` expands query words
+//! through a dictionary of equivalent terms before BM25 scoring, so a
+//! search for one spelling also finds skills written with another.
+//!
+//! The dictionary is assembled from three layers, later layers adding to
+//! earlier ones:
+//!
+//! 1. Built-in defaults ([`BUILTIN_SYNONYMS`])
+//! 2. The `[search.synonyms]` table in `config.toml`
+//! 3. An optional `synonyms.toml` file at the root of each repo
+//!
+//! Entries are symmetric: `k8s = ["kubernetes"]` also expands "kubernetes"
+//! to "k8s". Keys and values may be multi-word phrases ("github actions").
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::config::SearchConfig;
+use crate::error::Error;
+
+/// Built-in synonym groups, applied unless `builtin_synonyms = false`.
+pub const BUILTIN_SYNONYMS: &[(&str, &[&str])] = &[
+    ("k8s", &["kubernetes"]),
+    ("pg", &["postgres", "postgresql"]),
+    ("postgres", &["postgresql"]),
+    ("js", &["javascript"]),
+    ("ts", &["typescript"]),
+    ("py", &["python"]),
+    ("rs", &["rust"]),
+    ("gha", &["github actions"]),
+    ("tf", &["terraform"]),
+    ("db", &["database"]),
+    ("mongo", &["mongodb"]),
+    ("ci", &["continuous integration"]),
+];
+
+/// File name of the optional per-repo synonym dictionary.
+pub const SYNONYMS_FILE: &str = "synonyms.toml";
+
+/// Default weight for query terms added by expansion.
+pub const DEFAULT_SYNONYM_WEIGHT: f64 = 0.5;
+
+/// Longest phrase (in words) considered when matching query n-grams.
+const MAX_PHRASE_WORDS: usize = 3;
+
+/// Deserialized `synonyms.toml`:
+///
+/// ```toml
+/// [synonyms]
+/// k8s = ["kubernetes"]
+/// gha = ["github actions"]
+/// ```
+#[derive(Debug, Default, Deserialize)]
+struct SynonymsFile {
+    #[serde(default)]
+    synonyms: BTreeMap<String, Vec<String>>,
+}
+
+/// Symmetric dictionary of equivalent search terms.
+#[derive(Debug, Clone, Default)]
+pub struct SynonymMap {
+    /// Normalized term or phrase -> equivalent terms/phrases
+    map: HashMap<String, Vec<String>>,
+}
+
+impl SynonymMap {
+    /// An empty dictionary (no expansion).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A dictionary seeded with [`BUILTIN_SYNONYMS`].
+    pub fn builtin() -> Self {
+        let mut map = Self::new();
+        for (term, expansions) in BUILTIN_SYNONYMS {
+            map.add(term, expansions.iter().copied());
+        }
+        map
+    }
+
+    /// Assemble the dictionary from config and per-repo `synonyms.toml` files.
+    ///
+    /// Unreadable or malformed repo files are logged and skipped.
+    pub fn load(config: &SearchConfig, repo_paths: &[PathBuf]) -> Self {
+        let mut map = if config.builtin_synonyms {
+            Self::builtin()
+        } else {
+            Self::new()
+        };
+
+        map.extend(&config.synonyms);
+
+        for path in repo_paths {
+            let file = path.join(SYNONYMS_FILE);
+            if !file.is_file() {
+                continue;
+            }
+            match load_synonyms_file(&file) {
+                Ok(table) => {
+                    tracing::debug!(
+                        path = %file.display(),
+                        entries = table.len(),
+                        "Loaded repo synonyms"
+                    );
+                    map.extend(&table);
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Skipping malformed synonyms file");
+                }
+            }
+        }
+
+        map
+    }
+
+    /// Add a synonym group. Every member expands to every other member.
+    pub fn add<'a>(&mut self, term: &str, expansions: impl IntoIterator<Item = &'a str>) {
+        let mut group = vec![normalize(term)];
+        group.extend(expansions.into_iter().map(normalize));
+        group.retain(|t| !t.is_empty());
+
+        for member in &group {
+            let entry = self.map.entry(member.clone()).or_default();
+            for other in &group {
+                if other != member && !entry.contains(other) {
+                    entry.push(other.clone());
+                }
+            }
+        }
+    }
+
+    /// Add every entry of a `term -> [synonyms]` table.
+    pub fn extend(&mut self, table: &BTreeMap<String, Vec<String>>) {
+        for (term, expansions) in table {
+            self.add(term, expansions.iter().map(String::as_str));
+        }
+    }
+
+    /// Equivalent terms for a single term or phrase.
+    pub fn get(&self, term: &str) -> &[String] {
+        self.map
+            .get(&normalize(term))
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Expansions for all words and word n-grams in a query.
+    ///
+    /// `words` are the lowercased query words in order. Phrases of up to
+    /// `MAX_PHRASE_WORDS` consecutive words are matched, so "github actions"
+    /// in a query expands to "gha".
+    pub fn expand_words(&self, words: &[String]) -> Vec<&str> {
+        let mut out: Vec<&str> = Vec::new();
+        for start in 0..words.len() {
+            for len in 1..=MAX_PHRASE_WORDS.min(words.len() - start) {
+                let phrase = words[start..start + len].join(" ");
+                if let Some(expansions) = self.map.get(&phrase) {
+                    for e in expansions {
+                        if !out.contains(&e.as_str()) {
+                            out.push(e);
+                        }
+                    }
+                }
+            }
+        }
+        out
+    }
+
+    /// Number of distinct terms with at least one synonym.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Whether the dictionary is empty.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+/// Lowercase and collapse internal whitespace.
+fn normalize(term: &str) -> String {
+    term.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Parse a `synonyms.toml` file.
+fn load_synonyms_file(path: &Path) -> crate::error::Result<BTreeMap<String, Vec<String>>> {
+    let raw = std::fs::read_to_string(path).map_err(|e| Error::FileRead {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    let file: SynonymsFile = toml::from_str(&raw).map_err(|e| Error::TomlParse {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    Ok(file.synonyms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(s: &str) -> Vec<String> {
+        s.split_whitespace().map(|w| w.to_lowercase()).collect()
+    }
+
+    #[test]
+    fn builtin_contains_common_aliases() {
+        let map = SynonymMap::builtin();
+        assert!(map.get("k8s").contains(&"kubernetes".to_string()));
+        assert!(map.get("gha").contains(&"github actions".to_string()));
+    }
+
+    #[test]
+    fn groups_are_symmetric() {
+        let mut map = SynonymMap::new();
+        map.add("k8s", ["kubernetes"]);
+        assert_eq!(map.get("kubernetes"), ["k8s".to_string()]);
+    }
+
+    #[test]
+    fn lookup_is_case_insensitive() {
+        let mut map = SynonymMap::new();
+        map.add("PG", ["Postgres"]);
+        assert_eq!(map.get("pg"), ["postgres".to_string()]);
+        assert_eq!(map.get("POSTGRES"), ["pg".to_string()]);
+    }
+
+    #[test]
+    fn expand_words_matches_phrases() {
+        let map = SynonymMap::builtin();
+        let expanded = map.expand_words(&words("set up GitHub Actions"));
+        assert!(expanded.contains(&"gha"));
+    }
+
+    #[test]
+    fn expand_words_deduplicates() {
+        let mut map = SynonymMap::new();
+        map.add("k8s", ["kubernetes"]);
+        let expanded = map.expand_words(&words("k8s k8s"));
+        assert_eq!(expanded, vec!["kubernetes"]);
+    }
+
+    #[test]
+    fn load_merges_config_and_repo_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join(SYNONYMS_FILE),
+            "[synonyms]\nmq = [\"message queue\"]\n",
+        )
+        .unwrap();
+
+        let mut config = SearchConfig::default();
+        config
+            .synonyms
+            .insert("sre".to_string(), vec!["reliability".to_string()]);
+
+        let map = SynonymMap::load(&config, &[tmp.path().to_path_buf()]);
+        assert_eq!(map.get("mq"), ["message queue".to_string()]);
+        assert_eq!(map.get("sre"), ["reliability".to_string()]);
+        assert!(!map.get("k8s").is_empty());
+    }
+
+    #[test]
+    fn load_without_builtins() {
+        let config = SearchConfig {
+            builtin_synonyms: false,
+            ..Default::default()
+        };
+        let map = SynonymMap::load(&config, &[]);
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn load_skips_malformed_repo_file() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join(SYNONYMS_FILE), "not toml {{{").unwrap();
+        let map = SynonymMap::load(&SearchConfig::default(), &[tmp.path().to_path_buf()]);
+        assert!(!map.get("k8s").is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+//! Tag and category normalization.
+//!
+//! Repos spell the same label many ways ("Testing", "testing ",
+//! "GitHub Actions", "github-actions"). Labels are lowercased, trimmed, and
+//! have inner whitespace collapsed to `-`, then mapped through the
+//! `[taxonomy.aliases]` table so synonyms ("k8s" -> "kubernetes") count as
+//! one. The normalized labels replace the originals in the index, so counts,
+//! filters, facets, and search all agree.
+//!
+//! Categories may be hierarchical, with `/` between levels
+//! (`development/rust/testing`). Each level is normalized on its own, and a
+//! category filter matches the category itself and everything beneath it.
+
+use crate::config::TaxonomyConfig;
+use crate::state::SkillIndex;
+
+/// Normalize a label: trim, lowercase, and join words with `-`. Each
+/// `/`-separated level is normalized separately and empty levels dropped.
+pub fn normalize(label: &str) -> String {
+    label
+        .split('/')
+        .map(|level| {
+            level
+                .split_whitespace()
+                .map(str::to_lowercase)
+                .collect::<Vec<_>>()
+                .join("-")
+        })
+        .filter(|level| !level.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Whether `category` is `filter` or nested beneath it. Both are expected
+/// to be canonical.
+pub fn category_matches(category: &str, filter: &str) -> bool {
+    category
+        .strip_prefix(filter)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Normalize a label and resolve it through the configured aliases.
+pub fn canonical(label: &str, config: &TaxonomyConfig) -> String {
+    let normalized = normalize(label);
+    config
+        .aliases
+        .iter()
+        .find(|(alias, _)| normalize(alias) == normalized)
+        .map(|(_, target)| normalize(target))
+        .unwrap_or(normalized)
+}
+
+/// Canonicalize a list of labels, dropping empties and duplicates while
+/// keeping first-seen order.
+fn canonical_list(labels: &[String], config: &TaxonomyConfig) -> Vec<String> {
+    let mut out: Vec<String> = Vec::with_capacity(labels.len());
+    for label in labels {
+        let label = canonical(label, config);
+        if !label.is_empty() && !out.contains(&label) {
+            out.push(label);
+        }
+    }
+    out
+}
+
+/// Rewrite every skill's categories and tags to canonical form and recount
+/// the index's category and tag maps.
+pub fn normalize_index(index: &mut SkillIndex, config: &TaxonomyConfig) {
+    for entry in index.skills.values_mut() {
+        for version in &mut entry.versions {
+            if let Some(ref mut c) = version.metadata.skill.classification {
+                c.categories = canonical_list(&c.categories, config);
+                c.tags = canonical_list(&c.tags, config);
+            }
+        }
+    }
+    index.recount();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    fn aliases(pairs: &[(&str, &str)]) -> TaxonomyConfig {
+        TaxonomyConfig {
+            aliases: pairs
+                .iter()
+                .map(|(a, t)| (a.to_string(), t.to_string()))
+                .collect::<BTreeMap<_, _>>(),
+        }
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("Testing"), "testing");
+        assert_eq!(normalize("  GitHub   Actions "), "github-actions");
+        assert_eq!(normalize(""), "");
+        assert_eq!(
+            normalize("Development / Rust//Unit Testing"),
+            "development/rust/unit-testing"
+        );
+    }
+
+    #[test]
+    fn test_category_matches() {
+        assert!(category_matches("development/rust", "development"));
+        assert!(category_matches("development", "development"));
+        assert!(!category_matches("development-tools", "development"));
+        assert!(!category_matches("development", "development/rust"));
+    }
+
+    #[test]
+    fn test_canonical_applies_aliases() {
+        let config = aliases(&[("K8s", "Kubernetes"), ("ci cd", "ci")]);
+        assert_eq!(canonical("k8s", &config), "kubernetes");
+        assert_eq!(canonical("CI  CD", &config), "ci");
+        assert_eq!(canonical("Rust", &config), "rust");
+    }
+
+    #[test]
+    fn test_normalize_index_merges_labels() {
+        use crate::state::{Classification, SkillEntry, SkillInfo, SkillMetadata, SkillVersion};
+
+        let entry = |name: &str, categories: &[&str], tags: &[&str]| SkillEntry {
+            owner: "acme".to_string(),
+            name: name.to_string(),
+            repo_path: None,
+            source: Default::default(),
+            trust_tier: Default::default(),
+            discovered_via: Vec::new(),
+            origin: None,
+            versions: vec![SkillVersion {
+                version: "1.0.0".to_string(),
+                metadata: SkillMetadata {
+                    skill: SkillInfo {
+                        name: name.to_string(),
+                        owner: "acme".to_string(),
+                        version: "1.0.0".to_string(),
+                        description: String::new(),
+                        trigger: None,
+                        license: None,
+                        author: None,
+                        classification: Some(Classification {
+                            categories: categories.iter().map(|s| s.to_string()).collect(),
+                            tags: tags.iter().map(|s| s.to_string()).collect(),
+                        }),
+                        compatibility: None,
+                        language: None,
+                        files: None,
+                        activation: None,
+                        allowed_tools: None,
+                        model: None,
+                    },
+                    frontmatter: Default::default(),
+                },
+                skill_md: String::new(),
+                skill_toml_raw: String::new(),
+                yanked: false,
+                files: Default::default(),
+                published: None,
+                has_content: true,
+            }],
+        };
+
+        let mut index = SkillIndex::default();
+        index.merge(SkillIndex {
+            skills: [
+                (
+                    ("acme".into(), "a".into()),
+                    entry("a", &["Testing"], &["K8s"]),
+                ),
+                (
+                    ("acme".into(), "b".into()),
+                    entry("b", &["testing "], &["kubernetes"]),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        });
+        assert_eq!(index.categories.len(), 2);
+
+        normalize_index(&mut index, &aliases(&[("k8s", "kubernetes")]));
+        assert_eq!(index.categories.get("testing"), Some(&2));
+        assert_eq!(index.categories.len(), 1);
+        assert_eq!(index.tags.get("kubernetes"), Some(&2));
+        assert_eq!(index.tags.len(), 1);
+    }
+
+    #[test]
+    fn test_canonical_list_dedupes() {
+        let config = aliases(&[("k8s", "kubernetes")]);
+        let labels: Vec<String> = ["Kubernetes", "k8s", " ", "Helm"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(canonical_list(&labels, &config), vec!["kubernetes", "helm"]);
+    }
+}
@@ -1,8 +1,15 @@
 //! annotate_skill tool -- attach persistent notes to skills
 
+use std::sync::Arc;
+
 use schemars::JsonSchema;
 use serde::Deserialize;
-use tower_mcp::{CallToolResult, Tool, ToolBuilder};
+use tower_mcp::{
+    CallToolResult, Tool, ToolBuilder,
+    extract::{Json, State},
+};
+
+use skillet_mcp::state::AppState;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct AnnotateSkillInput {
@@ -14,23 +21,38 @@ pub struct AnnotateSkillInput {
     note: String,
 }
 
-pub fn build() -> Tool {
+pub fn build(state: Arc<AppState>) -> Tool {
     ToolBuilder::new("annotate_skill")
         .description(
             "Attach a persistent note to a skill. Notes survive across sessions \
              and are shown in skill info. Use this to record gaps, tips, or \
              corrections discovered during skill use.",
         )
-        .handler(|input: AnnotateSkillInput| async move {
-            match skillet_mcp::annotations::annotate(&input.owner, &input.name, &input.note) {
-                Ok(count) => Ok(CallToolResult::text(format!(
-                    "Annotated {}/{}. Total annotations: {count}",
-                    input.owner, input.name
-                ))),
-                Err(e) => Ok(CallToolResult::error(format!(
-                    "Failed to save annotation: {e}"
-                ))),
-            }
-        })
+        .extractor_handler(
+            state,
+            |State(state): State<Arc<AppState>>, Json(input): Json<AnnotateSkillInput>| async move {
+                // Store notes under the resolved (possibly repo-qualified)
+                // reference so info and search read them back
+                let (owner, name) = {
+                    let index = state.index.read().await;
+                    match index
+                        .lookup(&input.owner, &input.name)
+                        .found(&input.owner, &input.name)
+                    {
+                        Ok(entry) => (entry.owner.clone(), entry.name.clone()),
+                        Err(msg) => return Ok(CallToolResult::error(format!("{msg}."))),
+                    }
+                };
+
+                match skillet_mcp::annotations::annotate(&owner, &name, &input.note) {
+                    Ok(count) => Ok(CallToolResult::text(format!(
+                        "Annotated {owner}/{name}. Total annotations: {count}"
+                    ))),
+                    Err(e) => Ok(CallToolResult::error(format!(
+                        "Failed to save annotation: {e}"
+                    ))),
+                }
+            },
+        )
         .build()
 }
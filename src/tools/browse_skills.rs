@@ -0,0 +1,83 @@
+//! browse_skills tool -- walk the category tree one level at a time
+
+use std::sync::Arc;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use tower_mcp::{
+    CallToolResult, Tool, ToolBuilder,
+    extract::{Json, State},
+};
+
+use skillet_mcp::{browse, state::AppState, taxonomy};
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct BrowseSkillsInput {
+    /// Category path to list (e.g. "development/rust"). Omit for the top level.
+    #[serde(default)]
+    path: Option<String>,
+}
+
+pub fn build(state: Arc<AppState>) -> Tool {
+    ToolBuilder::new("browse_skills")
+        .description(
+            "Browse skills by category, one level at a time. Returns the groups \
+             beneath a path with skill counts, plus the skills filed directly \
+             at that path. Start with no path and pass a group's path to go \
+             deeper. Nested repo directories show up as groups too.",
+        )
+        .read_only()
+        .idempotent()
+        .extractor_handler(
+            state,
+            |State(state): State<Arc<AppState>>, Json(input): Json<BrowseSkillsInput>| async move {
+                let index = state.index.read().await;
+
+                let path = input
+                    .path
+                    .as_deref()
+                    .map(|p| taxonomy::canonical(p, &state.cli_config.taxonomy))
+                    .unwrap_or_default();
+                let listing = browse::browse(&index, &path);
+
+                if listing.is_empty() {
+                    if path.is_empty() {
+                        return Ok(CallToolResult::text(
+                            "No categories found. The repo may be empty.",
+                        ));
+                    }
+                    return Ok(CallToolResult::error(format!(
+                        "No skills under '{path}'. Browse without a path to see top-level groups."
+                    )));
+                }
+
+                let title = if path.is_empty() { "/" } else { &path };
+                let mut output = format!("## Browse: {title}\n\n");
+
+                if !listing.groups.is_empty() {
+                    output.push_str(&format!("### Groups ({})\n\n", listing.groups.len()));
+                    for group in &listing.groups {
+                        let plural = if group.count == 1 { "skill" } else { "skills" };
+                        output.push_str(&format!(
+                            "- **{}/** ({} {plural}) -- path: `{}`\n",
+                            group.name, group.count, group.path
+                        ));
+                    }
+                    output.push('\n');
+                }
+
+                if !listing.skills.is_empty() {
+                    output.push_str(&format!("### Skills ({})\n\n", listing.skills.len()));
+                    for s in &listing.skills {
+                        output.push_str(&format!(
+                            "- **{}/{}** v{} -- {}\n",
+                            s.owner, s.name, s.version, s.description
+                        ));
+                    }
+                }
+
+                Ok(CallToolResult::text(output))
+            },
+        )
+        .build()
+}
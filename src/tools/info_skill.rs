@@ -9,7 +9,13 @@ use tower_mcp::{
     extract::{Json, State},
 };
 
-use skillet_mcp::state::AppState;
+use skillet_mcp::compat;
+use skillet_mcp::config::CompatibilityMode;
+use skillet_mcp::mcp_config::{self, McpInventory};
+use skillet_mcp::state::{AppState, prompt_name};
+
+/// Number of related skills listed under "Related".
+const RELATED_LIMIT: usize = 3;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct InfoSkillInput {
@@ -23,7 +29,9 @@ pub fn build(state: Arc<AppState>) -> Tool {
     ToolBuilder::new("info_skill")
         .description(
             "Get detailed information about a specific skill including version, \
-             description, author, categories, tags, files, and version history.",
+             description, author, categories, tags, files, version history, \
+             related skills, and copies of the skill from other repos that it \
+             shadows.",
         )
         .read_only()
         .idempotent()
@@ -32,28 +40,27 @@ pub fn build(state: Arc<AppState>) -> Tool {
             |State(state): State<Arc<AppState>>, Json(input): Json<InfoSkillInput>| async move {
                 let index = state.index.read().await;
 
-                let entry = match index.skills.get(&(input.owner.clone(), input.name.clone())) {
-                    Some(e) => e,
-                    None => {
-                        return Ok(CallToolResult::error(format!(
-                            "Skill '{}/{}' not found in any repo.",
-                            input.owner, input.name
-                        )));
-                    }
+                let entry = match index
+                    .lookup(&input.owner, &input.name)
+                    .found(&input.owner, &input.name)
+                {
+                    Ok(e) => e,
+                    Err(msg) => return Ok(CallToolResult::error(format!("{msg}."))),
                 };
+                let (owner, name) = (entry.owner.as_str(), entry.name.as_str());
 
                 let latest = match entry.latest() {
                     Some(v) => v,
                     None => {
                         return Ok(CallToolResult::error(format!(
                             "No available versions for '{}/{}' (all yanked).",
-                            input.owner, input.name
+                            owner, name
                         )));
                     }
                 };
 
                 let info = &latest.metadata.skill;
-                let mut output = format!("## {}/{}\n\n", input.owner, input.name);
+                let mut output = format!("## {}/{}\n\n", owner, name);
 
                 output.push_str(&format!("**Version:** {}\n", info.version));
                 output.push_str(&format!("**Description:** {}\n", info.description));
@@ -61,6 +68,9 @@ pub fn build(state: Arc<AppState>) -> Tool {
                 if let Some(ref trigger) = info.trigger {
                     output.push_str(&format!("**Trigger:** {trigger}\n"));
                 }
+                if let Some(ref activation) = info.activation {
+                    output.push_str(&format!("**Activation:** {activation}\n"));
+                }
                 if let Some(ref license) = info.license {
                     output.push_str(&format!("**License:** {license}\n"));
                 }
@@ -91,6 +101,28 @@ pub fn build(state: Arc<AppState>) -> Tool {
                         compat.verified_with.join(", ")
                     ));
                 }
+                if let Some(ref model) = info.model {
+                    output.push_str(&format!("**Model:** {model}\n"));
+                }
+                if let Some(ref tools) = info.allowed_tools {
+                    output.push_str(&format!("**Allowed tools:** {}\n", tools.join(", ")));
+                }
+                let custom = latest.metadata.custom_metadata();
+                if !custom.is_empty() {
+                    output.push_str("**Metadata:**\n");
+                    for (key, value) in &custom {
+                        output.push_str(&format!("- {key}: {value}\n"));
+                    }
+                }
+
+                // Required MCP servers vs. local client configs
+                let servers = McpInventory::discover(&state.project_root).check(entry);
+                if !servers.is_empty() {
+                    output.push_str(&format!(
+                        "**Required MCP servers:** {}\n",
+                        mcp_config::format_check(&servers)
+                    ));
+                }
 
                 // Extra files
                 if !latest.files.is_empty() {
@@ -127,7 +159,7 @@ pub fn build(state: Arc<AppState>) -> Tool {
                     output.push_str(&format!("**Repo path:** {rpath}\n"));
                 }
 
-                // Source label for embedded skills
+                // Source label for embedded and imported skills
                 if let Some(label) = entry.source.label() {
                     output.push_str(&format!("**Source:** {label}\n"));
                 }
@@ -143,11 +175,54 @@ pub fn build(state: Arc<AppState>) -> Tool {
                     }
                 }
 
+                // Copies from other repos that lost the merge
+                let shadowed = index.shadowing_for(owner, name);
+                if !shadowed.is_empty() {
+                    output.push_str(&format!("\n**Shadowed copies ({}):**\n", shadowed.len()));
+                    for s in &shadowed {
+                        output.push_str(&format!(
+                            "- v{} from {} ({}), dropped by the {} policy\n",
+                            s.dropped.version,
+                            s.dropped.source_label(),
+                            s.dropped.trust_tier,
+                            s.policy
+                        ));
+                    }
+                    output.push('\n');
+                }
+
+                // Compatibility with the connected agent
+                let agent_config = &state.cli_config.agent;
+                if agent_config.compatibility != CompatibilityMode::Off {
+                    let reasons = compat::current_or_config(agent_config).check_entry(entry);
+                    if !reasons.is_empty() {
+                        output.push_str(&format!("**Incompatible:** {}\n", reasons.join("; ")));
+                    }
+                }
+
                 // Prompt name for agent use
-                output.push_str(&format!("\n**Prompt:** `{}_{}`\n", input.owner, input.name));
+                output.push_str(&format!("\n**Prompt:** `{}`\n", prompt_name(owner, name)));
+
+                // Related skills by indexed-text similarity
+                let related = state
+                    .search
+                    .read()
+                    .await
+                    .similar(owner, name, RELATED_LIMIT);
+                if !related.is_empty() {
+                    output.push_str("\n**Related:**\n");
+                    for r in &related {
+                        output.push_str(&format!(
+                            "- {}/{} (shared: {})\n",
+                            r.owner,
+                            r.name,
+                            r.shared_terms.join(", ")
+                        ));
+                    }
+                }
 
                 // Annotations
-                let annotations = skillet_mcp::annotations::get(&input.owner, &input.name);
+                let annotations = skillet_mcp::annotations::get(owner, name);
                 if !annotations.is_empty() {
                     output.push_str(&format!("\n**Annotations ({}):**\n", annotations.len()));
                     for ann in &annotations {
@@ -9,7 +9,7 @@ use tower_mcp::{
     extract::{Json, State},
 };
 
-use skillet_mcp::state::{AppState, SkillSummary};
+use skillet_mcp::state::{AppState, SkillSummary, split_alias};
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ListByOwnerInput {
@@ -34,7 +34,10 @@ pub fn build(state: Arc<AppState>) -> Tool {
                 let mut results: Vec<SkillSummary> = index
                     .skills
                     .values()
-                    .filter(|entry| entry.owner.to_lowercase() == owner_lower)
+                    .filter(|entry| {
+                        let owner = entry.owner.to_lowercase();
+                        owner == owner_lower || split_alias(&owner).1 == owner_lower
+                    })
                     .filter_map(SkillSummary::from_entry)
                     .collect();
 
@@ -55,9 +58,14 @@ pub fn build(state: Arc<AppState>) -> Tool {
                     } else {
                         format!("v{}", s.version)
                     };
+                    // Qualify names from aliased repos so copies stay distinct
+                    let label = match split_alias(&s.owner).0 {
+                        Some(_) => format!("{}/{}", s.owner, s.name),
+                        None => s.name.clone(),
+                    };
                     output.push_str(&format!(
                         "- **{}** ({}) -- {}\n",
-                        s.name, version_info, s.description,
+                        label, version_info, s.description,
                     ));
                 }
 
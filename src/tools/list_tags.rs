@@ -0,0 +1,45 @@
+//! list_tags tool -- browse skill tags
+
+use std::sync::Arc;
+
+use tower_mcp::{
+    CallToolResult, NoParams, Tool, ToolBuilder,
+    extract::{Json, State},
+};
+
+use skillet_mcp::state::AppState;
+
+pub fn build(state: Arc<AppState>) -> Tool {
+    ToolBuilder::new("list_tags")
+        .description(
+            "List all skill tags with the number of skills carrying each, most \
+             used first. Tags are normalized (lowercase, aliases merged), so any \
+             listed tag can be passed to search_skills as a filter.",
+        )
+        .read_only()
+        .idempotent()
+        .extractor_handler(
+            state,
+            |State(state): State<Arc<AppState>>, Json(_): Json<NoParams>| async move {
+                let index = state.index.read().await;
+
+                if index.tags.is_empty() {
+                    return Ok(CallToolResult::text(
+                        "No tags found. The repo may be empty.",
+                    ));
+                }
+
+                let mut tags: Vec<(&String, &usize)> = index.tags.iter().collect();
+                tags.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+                let mut output = format!("## Skill Tags ({} total)\n\n", tags.len());
+                for (name, count) in tags {
+                    let plural = if *count == 1 { "skill" } else { "skills" };
+                    output.push_str(&format!("- **{name}** ({count} {plural})\n"));
+                }
+
+                Ok(CallToolResult::text(output))
+            },
+        )
+        .build()
+}
@@ -0,0 +1,56 @@
+//! load_diagnostics tool -- report skills and repos skipped while loading
+
+use std::sync::Arc;
+
+use tower_mcp::{
+    CallToolResult, NoParams, Tool, ToolBuilder,
+    extract::{Json, State},
+};
+
+use skillet_mcp::state::{AppState, Severity};
+
+pub fn build(state: Arc<AppState>) -> Tool {
+    ToolBuilder::new("load_diagnostics")
+        .description(
+            "List problems hit while loading skills: skill directories that \
+             failed to parse, duplicate skills that were dropped, and repos that \
+             couldn't be cloned or indexed. Use this when a skill you expect is \
+             missing from search results.",
+        )
+        .read_only()
+        .idempotent()
+        .extractor_handler(
+            state,
+            |State(state): State<Arc<AppState>>, Json(_): Json<NoParams>| async move {
+                let index = state.index.read().await;
+
+                if index.diagnostics.is_empty() {
+                    return Ok(CallToolResult::text(
+                        "No load problems. Every discovered skill was indexed.",
+                    ));
+                }
+
+                let errors = index
+                    .diagnostics
+                    .iter()
+                    .filter(|d| d.severity == Severity::Error)
+                    .count();
+                let warnings = index.diagnostics.len() - errors;
+
+                let mut output =
+                    format!("## Load Diagnostics ({errors} errors, {warnings} warnings)\n\n");
+                for d in &index.diagnostics {
+                    output.push_str(&format!(
+                        "- **{}** `{}` ({}): {}\n",
+                        d.severity,
+                        d.path.display(),
+                        d.kind,
+                        d.message
+                    ));
+                }
+
+                Ok(CallToolResult::text(output))
+            },
+        )
+        .build()
+}
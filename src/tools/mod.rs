@@ -1,5 +1,9 @@
 pub mod annotate_skill;
+pub mod browse_skills;
 pub mod info_skill;
 pub mod list_categories;
 pub mod list_skills_by_owner;
+pub mod list_tags;
+pub mod load_diagnostics;
 pub mod search_skills;
+pub mod similar_skills;
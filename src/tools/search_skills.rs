@@ -1,5 +1,6 @@
 //! search_skills tool -- full-text search over the skill index
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use schemars::JsonSchema;
@@ -9,13 +10,22 @@ use tower_mcp::{
     extract::{Json, State},
 };
 
-use skillet_mcp::state::{AppState, SkillSummary};
+use skillet_mcp::mcp_config::McpInventory;
+use skillet_mcp::state::{AppState, Facets, SkillSummary, prompt_name};
+use skillet_mcp::{compat, taxonomy};
+
+/// Maximum values listed per facet.
+const FACET_LIMIT: usize = 10;
+
+/// Maximum ranked matches listed. Facets still count every match.
+const RESULT_LIMIT: usize = 100;
 
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct SearchSkillsInput {
     /// Search query (matches against skill name, description, tags, and categories)
     query: String,
-    /// Filter by category (e.g. "development", "testing")
+    /// Filter by category (e.g. "development", "development/rust"); parent
+    /// categories include everything nested beneath them
     #[serde(default)]
     category: Option<String>,
     /// Filter by tag (e.g. "rust", "python")
@@ -24,13 +34,24 @@ pub struct SearchSkillsInput {
     /// Filter to skills verified with a specific model (e.g. "claude-opus-4-6")
     #[serde(default)]
     verified_with: Option<String>,
+    /// Filter on SKILL.md frontmatter fields, top-level or under `metadata:`
+    /// (e.g. {"audience": "backend"}); every key must match
+    #[serde(default)]
+    metadata: BTreeMap<String, String>,
+    /// Only return skills whose required MCP servers are all configured in
+    /// local MCP client configs (.mcp.json and similar)
+    #[serde(default)]
+    mcp_ready: Option<bool>,
 }
 
 pub fn build(state: Arc<AppState>) -> Tool {
     ToolBuilder::new("search_skills")
         .description(
             "Search for skills. Returns skills matching the query, \
-             with optional filters for category, tag, or model compatibility. \
+             with optional filters for category, tag, model compatibility, \
+             or frontmatter metadata, \
+             and facet counts (categories, tags, owners, trust tiers, models) \
+             over the matches for narrowing further. \
              Use this to discover skills relevant to your current task.",
         )
         .read_only()
@@ -53,7 +74,7 @@ pub fn build(state: Arc<AppState>) -> Tool {
                     // BM25 search, then look up summaries
                     let search = state.search.read().await;
                     search
-                        .search(&input.query, 100)
+                        .search(&input.query, usize::MAX)
                         .into_iter()
                         .filter_map(|(owner, name, _score)| {
                             let entry = index.skills.get(&(owner, name))?;
@@ -62,25 +83,35 @@ pub fn build(state: Arc<AppState>) -> Tool {
                         .collect()
                 };
 
-                // Apply structured filters (category, tag, verified_with)
+                // Apply structured filters (category, tag, verified_with,
+                // mcp_ready, metadata). Indexed labels are canonical, so canonicalize
+                // the filters too.
+                let taxonomy_config = &state.cli_config.taxonomy;
+                let category = input
+                    .category
+                    .as_deref()
+                    .map(|c| taxonomy::canonical(c, taxonomy_config));
+                let tag = input
+                    .tag
+                    .as_deref()
+                    .map(|t| taxonomy::canonical(t, taxonomy_config));
+                let inventory = (input.mcp_ready == Some(true))
+                    .then(|| McpInventory::discover(&state.project_root));
                 let results: Vec<SkillSummary> = results
                     .into_iter()
                     .filter(|summary| {
-                        if let Some(ref cat) = input.category {
-                            let cat_lower = cat.to_lowercase();
-                            if !summary
+                        if let Some(ref cat) = category
+                            && !summary
                                 .categories
                                 .iter()
-                                .any(|c| c.to_lowercase() == cat_lower)
-                            {
-                                return false;
-                            }
+                                .any(|c| taxonomy::category_matches(c, cat))
+                        {
+                            return false;
                         }
-                        if let Some(ref tag) = input.tag {
-                            let tag_lower = tag.to_lowercase();
-                            if !summary.tags.iter().any(|t| t.to_lowercase() == tag_lower) {
-                                return false;
-                            }
+                        if let Some(ref tag) = tag
+                            && !summary.tags.contains(tag)
+                        {
+                            return false;
                         }
                         if let Some(ref model) = input.verified_with {
                             let model_lower = model.to_lowercase();
@@ -92,10 +123,47 @@ pub fn build(state: Arc<AppState>) -> Tool {
                                 return false;
                             }
                         }
+                        if let Some(ref inventory) = inventory
+                            && let Some(entry) = index
+                                .skills
+                                .get(&(summary.owner.clone(), summary.name.clone()))
+                            && !inventory.missing_for(entry).is_empty()
+                        {
+                            return false;
+                        }
+                        if !input.metadata.is_empty()
+                            && !index
+                                .skills
+                                .get(&(summary.owner.clone(), summary.name.clone()))
+                                .and_then(|e| e.latest())
+                                .is_some_and(|v| {
+                                    input.metadata.iter().all(|(key, value)| {
+                                        v.metadata.frontmatter_matches(key, value)
+                                    })
+                                })
+                        {
+                            return false;
+                        }
                         true
                     })
                     .collect();
 
+                // Hide or flag skills the connected agent can't use
+                let agent_config = &state.cli_config.agent;
+                let (mut results, hidden) = compat::screen(
+                    results,
+                    &index,
+                    &compat::current_or_config(agent_config),
+                    agent_config.compatibility,
+                );
+
+                if results.is_empty() && !hidden.is_empty() {
+                    return Ok(CallToolResult::text(format!(
+                        "No compatible skills found matching '{}'.\n\n{}",
+                        input.query,
+                        format_hidden(&hidden)
+                    )));
+                }
                 if results.is_empty() {
                     return Ok(CallToolResult::text(format!(
                         "No skills found matching '{}'.\n\n\
@@ -105,7 +173,15 @@ pub fn build(state: Arc<AppState>) -> Tool {
                     )));
                 }
 
-                let mut output = format!("Found {} skill(s):\n\n", results.len());
+                // Facet counts over every match, for narrowing with filters
+                let facets = Facets::from_summaries(&results);
+
+                let mut output = format!("Found {} skill(s)", results.len());
+                if input.query != "*" && results.len() > RESULT_LIMIT {
+                    results.truncate(RESULT_LIMIT);
+                    output.push_str(&format!(", showing the top {RESULT_LIMIT}"));
+                }
+                output.push_str(":\n\n");
                 for s in &results {
                     let version_info = if s.version_count > 1 {
                         format!("v{}, {} versions", s.version, s.version_count)
@@ -147,7 +223,29 @@ pub fn build(state: Arc<AppState>) -> Tool {
                     if let Some(ref tier) = s.trust_tier {
                         output.push_str(&format!("**Trust:** {tier}\n"));
                     }
-                    output.push_str(&format!("**Prompt:** `{}_{}`\n\n", s.owner, s.name));
+                    if !s.incompatible.is_empty() {
+                        output.push_str(&format!(
+                            "**Incompatible:** {}\n",
+                            s.incompatible.join("; ")
+                        ));
+                    }
+                    output.push_str(&format!(
+                        "**Prompt:** `{}`\n\n",
+                        prompt_name(&s.owner, &s.name)
+                    ));
+                }
+
+                output.push_str("**Facets:**\n");
+                for (name, counts) in facets.named() {
+                    output.push_str(&format!(
+                        "- {name}: {}\n",
+                        Facets::format_counts(counts, FACET_LIMIT)
+                    ));
+                }
+
+                if !hidden.is_empty() {
+                    output.push('\n');
+                    output.push_str(&format_hidden(&hidden));
                 }
 
                 Ok(CallToolResult::text(output))
@@ -155,3 +253,17 @@ pub fn build(state: Arc<AppState>) -> Tool {
         )
         .build()
 }
+
+/// List skills hidden as incompatible, with the reasons for each.
+fn format_hidden(hidden: &[SkillSummary]) -> String {
+    let mut out = format!("**Hidden as incompatible ({}):**\n", hidden.len());
+    for s in hidden {
+        out.push_str(&format!(
+            "- {}/{}: {}\n",
+            s.owner,
+            s.name,
+            s.incompatible.join("; ")
+        ));
+    }
+    out
+}
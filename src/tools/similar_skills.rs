@@ -0,0 +1,89 @@
+//! similar_skills tool -- "more like this" lookup for a skill
+
+use std::sync::Arc;
+
+use schemars::JsonSchema;
+use serde::Deserialize;
+use tower_mcp::{
+    CallToolResult, Tool, ToolBuilder,
+    extract::{Json, State},
+};
+
+use skillet_mcp::state::AppState;
+
+/// Default number of similar skills to return.
+const DEFAULT_LIMIT: usize = 5;
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SimilarSkillsInput {
+    /// Skill owner (e.g. "joshrotenberg")
+    owner: String,
+    /// Skill name (e.g. "rust-dev")
+    name: String,
+    /// Maximum number of similar skills to return (default 5)
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+pub fn build(state: Arc<AppState>) -> Tool {
+    ToolBuilder::new("similar_skills")
+        .description(
+            "Find skills similar to a given skill, ranked by overlap of their \
+             indexed text. Use this when a skill is close to what you need but \
+             not quite right.",
+        )
+        .read_only()
+        .idempotent()
+        .extractor_handler(
+            state,
+            |State(state): State<Arc<AppState>>, Json(input): Json<SimilarSkillsInput>| async move {
+                let index = state.index.read().await;
+
+                let entry = match index
+                    .lookup(&input.owner, &input.name)
+                    .found(&input.owner, &input.name)
+                {
+                    Ok(e) => e,
+                    Err(msg) => return Ok(CallToolResult::error(format!("{msg}."))),
+                };
+                let (owner, name) = (entry.owner.as_str(), entry.name.as_str());
+
+                let search = state.search.read().await;
+                let similar = search.similar(owner, name, input.limit.unwrap_or(DEFAULT_LIMIT));
+
+                if similar.is_empty() {
+                    return Ok(CallToolResult::text(format!(
+                        "No skills similar to '{}/{}'.",
+                        owner, name
+                    )));
+                }
+
+                let mut output = format!(
+                    "Found {} skill(s) similar to {}/{}:\n\n",
+                    similar.len(),
+                    owner,
+                    name
+                );
+                for s in &similar {
+                    output.push_str(&format!(
+                        "## {}/{} (similarity {:.2})\n",
+                        s.owner, s.name, s.score
+                    ));
+                    if let Some(v) = index
+                        .skills
+                        .get(&(s.owner.clone(), s.name.clone()))
+                        .and_then(|e| e.latest())
+                    {
+                        output.push_str(&format!("{}\n", v.metadata.skill.description));
+                    }
+                    output.push_str(&format!(
+                        "**Shared terms:** {}\n\n",
+                        s.shared_terms.join(", ")
+                    ));
+                }
+
+                Ok(CallToolResult::text(output))
+            },
+        )
+        .build()
+}
@@ -50,6 +50,22 @@ fn search_wildcard_lists_all() {
         .stdout(predicate::str::contains("Found"));
 }
 
+#[test]
+fn search_persists_index_for_next_run() {
+    let cache = tempfile::tempdir().unwrap();
+
+    for _ in 0..2 {
+        skillet()
+            .env("SKILLET_CACHE_DIR", cache.path())
+            .args(["search", "rust", "--repo"])
+            .arg(test_repo())
+            .assert()
+            .success()
+            .stdout(predicate::str::contains("rust-dev"));
+        assert!(cache.path().join("search.json").exists());
+    }
+}
+
 #[test]
 fn search_owner_filter() {
     skillet()
@@ -86,6 +102,51 @@ fn search_tag_filter() {
         .stdout(predicate::str::contains("python-dev"));
 }
 
+#[test]
+fn search_shows_facet_counts() {
+    skillet()
+        .args(["search", "*", "--owner", "acme", "--repo"])
+        .arg(test_repo())
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Facets:")
+                .and(predicate::str::contains("owners ................ acme ("))
+                .and(predicate::str::contains("joshrotenberg (").not()),
+        );
+}
+
+#[test]
+fn search_facets_count_every_match() {
+    let tmp = tempfile::tempdir().unwrap();
+    let repo = tmp.path().join("repo");
+    for i in 0..25 {
+        let dir = repo.join(format!("acme/widget-{i}"));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(
+            dir.join("skill.toml"),
+            format!(
+                "[skill]\nname = \"widget-{i}\"\nowner = \"acme\"\nversion = \"1.0.0\"\n\
+                 description = \"Build widgets\"\n\n\
+                 [skill.classification]\ntags = [\"widgets\"]\n"
+            ),
+        )
+        .unwrap();
+        std::fs::write(dir.join("SKILL.md"), "# Widgets\n").unwrap();
+    }
+
+    skillet()
+        .env("HOME", tmp.path())
+        .args(["search", "widgets", "--no-cache", "--repo"])
+        .arg(&repo)
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Found 25 skills, showing the top 20:")
+                .and(predicate::str::contains("widgets (25)")),
+        );
+}
+
 #[test]
 fn search_no_results() {
     skillet()
@@ -106,8 +167,154 @@ fn categories_lists_with_counts() {
         .stdout(predicate::str::contains("development").and(predicate::str::contains("categor")));
 }
 
+#[test]
+fn tags_lists_with_counts() {
+    skillet()
+        .args(["tags", "--by-count", "--repo"])
+        .arg(test_repo())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("tags:").and(predicate::str::contains("rust (")));
+}
+
+#[test]
+fn browse_root_lists_groups() {
+    skillet()
+        .args(["browse", "--repo"])
+        .arg(test_repo())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("development/").and(predicate::str::contains("lang/")));
+}
+
+#[test]
+fn browse_nested_repo_path() {
+    skillet()
+        .args(["browse", "Lang/Java", "--repo"])
+        .arg(test_repo())
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("acme/maven-build")
+                .and(predicate::str::contains("acme/gradle-build")),
+        );
+}
+
+#[test]
+fn browse_unknown_path_fails() {
+    skillet()
+        .args(["browse", "no/such/path", "--repo"])
+        .arg(test_repo())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no skills under"));
+}
+
+/// Write `config` as the skillet config under a fresh HOME and return it.
+fn home_with_config(tmp: &tempfile::TempDir, config: &str) -> PathBuf {
+    let home = tmp.path().join("home");
+    let config_dir = home.join(".config/skillet");
+    std::fs::create_dir_all(&config_dir).expect("create config dir");
+    std::fs::write(config_dir.join("config.toml"), config).expect("write config");
+    home
+}
+
+#[test]
+fn search_tag_filter_uses_aliases() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let home = home_with_config(&tmp, "[taxonomy.aliases]\npy-test = \"pytest\"\n");
+
+    skillet()
+        .args(["search", "*", "--tag", "Py Test", "--repo"])
+        .arg(test_repo())
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("python-dev"));
+}
+
+#[test]
+fn search_cache_tracks_taxonomy_aliases() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let cache = tmp.path().join("cache");
+    let repo = tmp.path().join("repo");
+    let dir = repo.join("acme/cluster");
+    std::fs::create_dir_all(&dir).expect("create skill dir");
+    std::fs::write(
+        dir.join("SKILL.md"),
+        "---\nname: cluster\ndescription: Cluster helper\ntags: [kube-stuff]\n---\n\n# Cluster\n",
+    )
+    .expect("write SKILL.md");
+    let search = |home: &Path| {
+        skillet()
+            .args(["search", "orchestration", "--repo"])
+            .arg(&repo)
+            .env("HOME", home)
+            .env("SKILLET_CACHE_DIR", &cache)
+            .assert()
+            .success()
+    };
+
+    search(&home_with_config(&tmp, "")).stdout(predicate::str::contains("acme/cluster").not());
+
+    // The tag is re-normalized rather than served from the cached index
+    let home = home_with_config(&tmp, "[taxonomy.aliases]\nkube-stuff = \"orchestration\"\n");
+    search(&home).stdout(predicate::str::contains("acme/cluster"));
+}
+
+#[test]
+fn search_hides_incompatible_skills() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let home = home_with_config(&tmp, "[agent]\ncapabilities = [\"file_read\"]\n");
+
+    skillet()
+        .args(["search", "rust", "--repo"])
+        .arg(test_repo())
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Hidden as incompatible"))
+        .stdout(predicate::str::contains(
+            "joshrotenberg/rust-dev: requires capability 'shell_exec'",
+        ));
+}
+
+#[test]
+fn search_flags_incompatible_skills() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let home = home_with_config(
+        &tmp,
+        "[agent]\ncompatibility = \"flag\"\ntool_use = false\n",
+    );
+
+    skillet()
+        .args(["search", "rust", "--repo"])
+        .arg(test_repo())
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("incompatible: requires tool use"))
+        .stdout(predicate::str::contains("Hidden as incompatible").not());
+}
+
 // -- Info --
 
+#[test]
+fn info_shows_incompatibility() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let home = home_with_config(&tmp, "[agent]\ntool_use = false\n");
+
+    skillet()
+        .args(["info", "joshrotenberg/rust-dev", "--repo"])
+        .arg(test_repo())
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "incompatible .......... requires tool use",
+        ));
+}
+
 #[test]
 fn info_shows_skill_details() {
     skillet()
@@ -133,6 +340,229 @@ fn info_not_found() {
         .stderr(predicate::str::contains("not found"));
 }
 
+#[test]
+fn info_lists_related_skills() {
+    skillet()
+        .args(["info", "joshrotenberg/rust-dev", "--repo"])
+        .arg(test_repo())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("related"));
+}
+
+// -- Similar --
+
+#[test]
+fn similar_lists_related_skills() {
+    skillet()
+        .args([
+            "similar",
+            "joshrotenberg/rust-dev",
+            "--limit",
+            "2",
+            "--repo",
+        ])
+        .arg(test_repo())
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Skills similar to joshrotenberg/rust-dev")
+                .and(predicate::str::contains("shared:"))
+                .and(predicate::str::contains("joshrotenberg/rust-dev (").not()),
+        );
+}
+
+#[test]
+fn similar_not_found() {
+    skillet()
+        .args(["similar", "nonexistent/skill", "--repo"])
+        .arg(test_repo())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not found"));
+}
+
+// -- Search evaluation --
+
+#[test]
+fn search_eval_reports_metrics() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let golden = tmp.path().join("golden.toml");
+    std::fs::write(
+        &golden,
+        "[[case]]\nquery = \"rust\"\nexpected = [\"joshrotenberg/rust-dev\"]\n\n\
+         [[case]]\nquery = \"nonexistent_xyzzy_skill\"\nexpected = [\"acme/python-dev\"]\n",
+    )
+    .expect("write golden file");
+
+    skillet()
+        .arg("search-eval")
+        .arg(&golden)
+        .arg("--repo")
+        .arg(test_repo())
+        .assert()
+        .success()
+        .stdout(
+            predicate::str::contains("Evaluated 2 queries")
+                .and(predicate::str::contains("missing: acme/python-dev"))
+                .and(predicate::str::contains("mean")),
+        );
+}
+
+#[test]
+fn search_eval_json_output() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let golden = tmp.path().join("golden.jsonl");
+    std::fs::write(
+        &golden,
+        "{\"query\": \"rust\", \"expected\": [\"joshrotenberg/rust-dev\"]}\n",
+    )
+    .expect("write golden file");
+
+    let output = skillet()
+        .arg("search-eval")
+        .arg(&golden)
+        .args(["--json", "-k", "5", "--repo"])
+        .arg(test_repo())
+        .output()
+        .expect("run search-eval");
+    assert!(output.status.success());
+
+    let report: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("valid JSON report");
+    assert_eq!(report["k"], 5);
+    assert_eq!(report["queries"][0]["query"], "rust");
+    assert!(report["mrr"].as_f64().unwrap() > 0.0);
+}
+
+// -- MCP server requirements --
+
+/// A repo with one skill requiring `github` and `sentry`, and a project
+/// whose `.mcp.json` configures only `github`. Returns (repo, project, home).
+fn mcp_fixture(tmp: &tempfile::TempDir) -> (PathBuf, PathBuf, PathBuf) {
+    let repo = tmp.path().join("repo");
+    let skill = repo.join("acme/gh-triage");
+    std::fs::create_dir_all(&skill).expect("create skill dir");
+    std::fs::write(
+        skill.join("skill.toml"),
+        "[skill]\nname = \"gh-triage\"\nowner = \"acme\"\nversion = \"1.0.0\"\n\
+         description = \"Triage GitHub issues and link Sentry errors\"\n\n\
+         [skill.compatibility]\nrequired_mcp_servers = [\"github\", \"sentry\"]\n",
+    )
+    .expect("write skill.toml");
+    std::fs::write(
+        skill.join("SKILL.md"),
+        "# GitHub triage\n\nTriage issues.\n",
+    )
+    .expect("write SKILL.md");
+
+    let project = tmp.path().join("project");
+    std::fs::create_dir_all(&project).expect("create project dir");
+    std::fs::write(
+        project.join(".mcp.json"),
+        r#"{"mcpServers": {"github": {"command": "github-mcp"}}}"#,
+    )
+    .expect("write .mcp.json");
+
+    let home = tmp.path().join("home");
+    std::fs::create_dir_all(&home).expect("create home");
+    (repo, project, home)
+}
+
+#[test]
+fn doctor_reports_missing_mcp_servers() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let (repo, project, home) = mcp_fixture(&tmp);
+
+    skillet()
+        .args(["doctor", "--no-cache", "--project"])
+        .arg(&project)
+        .arg("--repo")
+        .arg(&repo)
+        .env("HOME", &home)
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            ".mcp.json (Claude Code (project))",
+        ))
+        .stdout(predicate::str::contains(
+            "[missing] acme/gh-triage: github (configured), sentry (not configured)",
+        ));
+}
+
+#[test]
+fn search_mcp_ready_filters_unmet_requirements() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let (repo, project, home) = mcp_fixture(&tmp);
+
+    skillet()
+        .args(["search", "triage", "--no-cache", "--repo"])
+        .arg(&repo)
+        .current_dir(&project)
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("acme/gh-triage"));
+
+    skillet()
+        .args(["search", "triage", "--mcp-ready", "--no-cache", "--repo"])
+        .arg(&repo)
+        .current_dir(&project)
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No skills found"));
+}
+
+#[test]
+fn info_shows_required_mcp_servers() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let (repo, project, home) = mcp_fixture(&tmp);
+
+    skillet()
+        .args(["info", "acme/gh-triage", "--no-cache", "--repo"])
+        .arg(&repo)
+        .current_dir(&project)
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "mcp servers ........... github (configured), sentry (not configured)",
+        ));
+}
+
+#[test]
+fn mcp_checks_use_project_root_from_subdir() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let (repo, project, home) = mcp_fixture(&tmp);
+    std::fs::write(project.join("skillet.toml"), "[project]\nname = \"app\"\n")
+        .expect("write skillet.toml");
+    let subdir = project.join("src");
+    std::fs::create_dir_all(&subdir).expect("create subdir");
+
+    skillet()
+        .args(["info", "acme/gh-triage", "--no-cache", "--repo"])
+        .arg(&repo)
+        .current_dir(&subdir)
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "mcp servers ........... github (configured), sentry (not configured)",
+        ));
+
+    skillet()
+        .args(["doctor", "--no-cache", "--repo"])
+        .arg(&repo)
+        .current_dir(&subdir)
+        .env("HOME", &home)
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            ".mcp.json (Claude Code (project))",
+        ));
+}
+
 // -- Repo management --
 
 #[test]
@@ -250,6 +680,72 @@ fn repo_list_empty() {
         .stdout(predicate::str::contains("No repos configured"));
 }
 
+#[test]
+fn config_shows_effective_search_settings() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let home = tmp.path().join("home");
+    let config_dir = home.join(".config/skillet");
+    std::fs::create_dir_all(&config_dir).expect("create config dir");
+    std::fs::write(
+        config_dir.join("config.toml"),
+        "[search]\nk1 = 1.6\nextra_fields = [\"license\"]\n\n[search.field_weights]\ntrigger = 4.0\n",
+    )
+    .expect("write config");
+
+    skillet()
+        .arg("config")
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("k1 .................... 1.6"))
+        .stdout(predicate::str::contains("b ..................... 0.75"))
+        .stdout(predicate::str::contains(
+            "trigger ............... 4 (configured)",
+        ))
+        .stdout(predicate::str::contains("license ............... 1"));
+}
+
+#[test]
+fn config_rejects_invalid_search_settings() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let home = tmp.path().join("home");
+    let config_dir = home.join(".config/skillet");
+    std::fs::create_dir_all(&config_dir).expect("create config dir");
+    std::fs::write(config_dir.join("config.toml"), "[search]\nb = 2.0\n").expect("write config");
+
+    skillet()
+        .arg("config")
+        .env("HOME", &home)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("b must be between 0.0 and 1.0"));
+}
+
+#[test]
+fn config_shows_and_validates_file_limits() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let home = home_with_config(&tmp, "[files]\nmax_file_size = \"64KB\"\n");
+    skillet()
+        .arg("config")
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "max file size ......... 65536 bytes",
+        ))
+        .stdout(predicate::str::contains(
+            "max skill size ........ 10485760 bytes",
+        ));
+
+    let home = home_with_config(&tmp, "[files]\nmax_skill_size = \"lots\"\n");
+    skillet()
+        .arg("config")
+        .env("HOME", &home)
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("[files] max_skill_size"));
+}
+
 // -- npm-style repo tests --
 
 #[test]
@@ -410,6 +906,498 @@ fn invalid_subcommand() {
         .stderr(predicate::str::contains("unrecognized").or(predicate::str::contains("invalid")));
 }
 
+// -- Load diagnostics --
+
+/// A repo with one good skill and one whose skill.toml is missing fields.
+fn repo_with_broken_skill(tmp: &tempfile::TempDir) -> PathBuf {
+    let repo = tmp.path().join("repo");
+    let good = repo.join("acme/good-skill");
+    std::fs::create_dir_all(&good).expect("create good skill");
+    std::fs::write(
+        good.join("SKILL.md"),
+        "---\nname: good-skill\ndescription: Works fine\n---\n\n# Good\n",
+    )
+    .expect("write SKILL.md");
+
+    let broken = repo.join("acme/broken-skill");
+    std::fs::create_dir_all(&broken).expect("create broken skill");
+    std::fs::write(
+        broken.join("skill.toml"),
+        "[skill]\nname = \"broken-skill\"\n",
+    )
+    .expect("write skill.toml");
+    std::fs::write(broken.join("SKILL.md"), "# Broken\n").expect("write SKILL.md");
+    repo
+}
+
+#[test]
+fn search_footer_lists_load_problems() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let repo = repo_with_broken_skill(&tmp);
+
+    skillet()
+        .args(["search", "*", "--no-cache", "--repo"])
+        .arg(&repo)
+        .env("HOME", tmp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("acme/good-skill"))
+        .stdout(predicate::str::contains("Load problems (1):"))
+        .stdout(predicate::str::contains("broken-skill: error:"))
+        .stdout(predicate::str::contains("[invalid_skill]"));
+}
+
+#[test]
+fn info_explains_skill_that_failed_to_load() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let repo = repo_with_broken_skill(&tmp);
+
+    skillet()
+        .args(["info", "acme/broken-skill", "--no-cache", "--repo"])
+        .arg(&repo)
+        .env("HOME", tmp.path())
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("not found in any repo"))
+        .stderr(predicate::str::contains("[invalid_skill]"));
+}
+
+// -- Conflicts --
+
+/// Two local repos that both provide acme/shared, at different versions.
+fn conflicting_repos(tmp: &tempfile::TempDir) -> (PathBuf, PathBuf) {
+    let make = |repo: &str, version: &str| {
+        let dir = tmp.path().join(repo).join("acme/shared");
+        std::fs::create_dir_all(&dir).expect("create skill dir");
+        std::fs::write(
+            dir.join("SKILL.md"),
+            format!(
+                "---\nname: shared\ndescription: Shared skill from {repo}\nversion: {version}\n---\n\n# Shared\n"
+            ),
+        )
+        .expect("write SKILL.md");
+        tmp.path().join(repo)
+    };
+    (make("repo-a", "1.0.0"), make("repo-b", "2.0.0"))
+}
+
+#[test]
+fn conflicts_lists_shadowed_skills() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let (repo_a, repo_b) = conflicting_repos(&tmp);
+
+    skillet()
+        .args(["conflicts", "--no-cache", "--repo"])
+        .arg(&repo_a)
+        .arg("--repo")
+        .arg(&repo_b)
+        .env("HOME", tmp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Conflict policy: first-wins"))
+        .stdout(predicate::str::contains("acme/shared"))
+        .stdout(predicate::str::contains("kept ....... v1.0.0"))
+        .stdout(predicate::str::contains("shadowed ... v2.0.0"))
+        .stdout(predicate::str::contains("1 shadowed skill"));
+}
+
+#[test]
+fn conflict_policy_highest_version() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let (repo_a, repo_b) = conflicting_repos(&tmp);
+    let home = home_with_config(&tmp, "[repos]\nconflict = \"highest-version\"\n");
+
+    skillet()
+        .args(["info", "acme/shared", "--no-cache", "--repo"])
+        .arg(&repo_a)
+        .arg("--repo")
+        .arg(&repo_b)
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Shared skill from repo-b"))
+        .stdout(predicate::str::contains("shadows ............... v1.0.0"));
+
+    let output = skillet()
+        .args(["conflicts", "--json", "--no-cache", "--repo"])
+        .arg(&repo_a)
+        .arg("--repo")
+        .arg(&repo_b)
+        .env("HOME", &home)
+        .output()
+        .expect("run conflicts");
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("valid JSON");
+    assert_eq!(json[0]["policy"], "highest-version");
+    assert_eq!(json[0]["kept"]["version"], "2.0.0");
+}
+
+#[test]
+fn search_cache_tracks_conflict_policy() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let cache = tmp.path().join("cache");
+    let make = |repo: &str, version: &str, word: &str| {
+        let dir = tmp.path().join(repo).join("acme/shared");
+        std::fs::create_dir_all(&dir).expect("create skill dir");
+        std::fs::write(
+            dir.join("SKILL.md"),
+            format!("---\nname: shared\ndescription: Shared {word} skill\nversion: {version}\n---\n\n# Shared\n"),
+        )
+        .expect("write SKILL.md");
+        tmp.path().join(repo)
+    };
+    let (repo_a, repo_b) = (
+        make("repo-a", "1.0.0", "alpha"),
+        make("repo-b", "2.0.0", "beta"),
+    );
+    let search = |home: &Path, query: &str| {
+        skillet()
+            .args(["search", query, "--repo"])
+            .arg(&repo_a)
+            .arg("--repo")
+            .arg(&repo_b)
+            .env("HOME", home)
+            .env("SKILLET_CACHE_DIR", &cache)
+            .assert()
+            .success()
+    };
+
+    let home = home_with_config(&tmp, "");
+    search(&home, "alpha").stdout(predicate::str::contains("acme/shared"));
+
+    // Switching policy indexes the new winner, not the cached loser
+    let home = home_with_config(&tmp, "[repos]\nconflict = \"highest-version\"\n");
+    search(&home, "alpha").stdout(predicate::str::contains("acme/shared").not());
+    search(&home, "beta").stdout(predicate::str::contains("acme/shared"));
+
+    // Likewise for a changed repo priority
+    let home = home_with_config(
+        &tmp,
+        &format!(
+            "[repos]\nconflict = \"priority\"\npriority = [\"{}\"]\n",
+            repo_a.display()
+        ),
+    );
+    search(&home, "alpha").stdout(predicate::str::contains("acme/shared"));
+}
+
+#[test]
+fn repo_alias_qualifies_skills() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let (repo_a, repo_b) = conflicting_repos(&tmp);
+    let home = home_with_config(
+        &tmp,
+        &format!("[repos.aliases]\nfork = \"{}\"\n", repo_b.display()),
+    );
+    let with_repos = |cmd: &mut Command| {
+        cmd.arg("--no-cache")
+            .arg("--repo")
+            .arg(&repo_a)
+            .arg("--repo")
+            .arg(&repo_b)
+            .env("HOME", &home);
+    };
+
+    let mut cmd = skillet();
+    cmd.args(["conflicts"]);
+    with_repos(&mut cmd);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("No conflicts"));
+
+    let mut cmd = skillet();
+    cmd.args(["info", "fork:acme/shared"]);
+    with_repos(&mut cmd);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("fork:acme/shared"))
+        .stdout(predicate::str::contains("Shared skill from repo-b"));
+
+    // The unaliased copy still answers to the bare name
+    let mut cmd = skillet();
+    cmd.args(["info", "acme/shared"]);
+    with_repos(&mut cmd);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("Shared skill from repo-a"));
+}
+
+#[test]
+fn search_cache_tracks_repo_aliases() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let cache = tmp.path().join("cache");
+    let (repo_a, repo_b) = conflicting_repos(&tmp);
+    let search = |home: &Path| {
+        skillet()
+            .args(["search", "shared", "--repo"])
+            .arg(&repo_a)
+            .arg("--repo")
+            .arg(&repo_b)
+            .env("HOME", home)
+            .env("SKILLET_CACHE_DIR", &cache)
+            .assert()
+            .success()
+    };
+
+    search(&home_with_config(&tmp, "")).stdout(predicate::str::contains("fork:").not());
+
+    // Adding an alias must not reuse the index built before it
+    let home = home_with_config(
+        &tmp,
+        &format!("[repos.aliases]\nfork = \"{}\"\n", repo_b.display()),
+    );
+    search(&home).stdout(predicate::str::contains("fork:acme/shared"));
+}
+
+#[test]
+fn import_agent_instructions_as_skills() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let repo = tmp.path().join("app");
+    let rules = repo.join(".cursor/rules");
+    std::fs::create_dir_all(&rules).expect("create rules dir");
+    std::fs::write(
+        rules.join("react.mdc"),
+        "---\ndescription: React component conventions\nglobs: *.tsx\n---\n\nPrefer function components.\n",
+    )
+    .expect("write rule");
+    std::fs::write(
+        repo.join("AGENTS.md"),
+        "# Agents\n\nRun the linter first.\n",
+    )
+    .expect("write AGENTS.md");
+    let home = home_with_config(
+        &tmp,
+        &format!(
+            "[repos.import]\n\"{}\" = [\"cursor\", \"agents-md\"]\n",
+            repo.display()
+        ),
+    );
+
+    skillet()
+        .args(["search", "react component", "--no-cache", "--repo"])
+        .arg(&repo)
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("app/react"));
+
+    skillet()
+        .args(["info", "app/react", "--no-cache", "--repo"])
+        .arg(&repo)
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "activation ............ auto-attached (*.tsx)",
+        ))
+        .stdout(predicate::str::contains(
+            "source ................ imported (cursor)",
+        ));
+
+    // Without the config entry the repo has no skills
+    skillet()
+        .args(["info", "app/agents", "--no-cache", "--repo"])
+        .arg(&repo)
+        .env("HOME", tmp.path())
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn search_cache_tracks_imports() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let cache = tmp.path().join("cache");
+    let (repo, _) = conflicting_repos(&tmp);
+    let rules = repo.join(".cursor/rules");
+    std::fs::create_dir_all(&rules).expect("create rules dir");
+    let write_rule = |description: &str| {
+        std::fs::write(
+            rules.join("react.mdc"),
+            format!("---\ndescription: {description}\n---\n\nPrefer function components.\n"),
+        )
+        .expect("write rule");
+    };
+    let search = |home: &Path, query: &str| {
+        skillet()
+            .args(["search", query, "--repo"])
+            .arg(&repo)
+            .env("HOME", home)
+            .env("SKILLET_CACHE_DIR", &cache)
+            .assert()
+            .success()
+    };
+    write_rule("React component conventions");
+
+    search(&home_with_config(&tmp, ""), "react").stdout(predicate::str::contains("react").not());
+
+    // Newly configured imports show up despite the index cached above
+    let home = home_with_config(
+        &tmp,
+        &format!("[repos.import]\n\"{}\" = [\"cursor\"]\n", repo.display()),
+    );
+    search(&home, "react").stdout(predicate::str::contains("/react"));
+
+    // So do edits to an imported file
+    write_rule("Svelte component conventions");
+    search(&home, "svelte").stdout(predicate::str::contains("/react"));
+}
+
+#[test]
+fn repo_alias_bare_ref_ambiguous() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let (repo_a, repo_b) = conflicting_repos(&tmp);
+    let home = home_with_config(
+        &tmp,
+        &format!(
+            "[repos.aliases]\nmain = \"{}\"\nfork = \"{}\"\n",
+            repo_a.display(),
+            repo_b.display()
+        ),
+    );
+
+    skillet()
+        .args(["info", "acme/shared", "--no-cache", "--repo"])
+        .arg(&repo_a)
+        .arg("--repo")
+        .arg(&repo_b)
+        .env("HOME", &home)
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains(
+            "'acme/shared' is ambiguous; use one of: fork:acme/shared, main:acme/shared",
+        ));
+}
+
+// -- Export --
+
+#[test]
+fn export_writes_layout_and_checks_drift() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let out = tmp.path().join("project");
+
+    skillet()
+        .args([
+            "export",
+            "joshrotenberg/rust-dev",
+            "--target",
+            "claude",
+            "--out",
+        ])
+        .arg(&out)
+        .arg("--repo")
+        .arg(test_repo())
+        .env("HOME", tmp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Exported 1 skill"))
+        .stdout(predicate::str::contains("joshrotenberg/rust-dev"));
+    let skill_md = out.join(".claude/skills/rust-dev/SKILL.md");
+    assert!(skill_md.is_file());
+    assert!(out.join("skillet-export.toml").is_file());
+
+    let check = || {
+        let mut cmd = skillet();
+        cmd.args(["export", "--check", "--out"])
+            .arg(&out)
+            .arg("--repo")
+            .arg(test_repo())
+            .env("HOME", tmp.path());
+        cmd
+    };
+    check()
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No drift"));
+
+    std::fs::write(&skill_md, "edited by hand").expect("edit export");
+    check()
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            "joshrotenberg/rust-dev [claude]: .claude/skills/rust-dev/SKILL.md was modified after export",
+        ));
+}
+
+#[test]
+fn export_rejects_unknown_target() {
+    skillet()
+        .args([
+            "export",
+            "joshrotenberg/rust-dev",
+            "--target",
+            "windsurf",
+            "--repo",
+        ])
+        .arg(test_repo())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("unknown target 'windsurf'"));
+}
+
+// -- Lint --
+
+#[test]
+fn lint_reports_errors_with_locations() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let skill = tmp.path().join("acme/broken-links");
+    std::fs::create_dir_all(&skill).expect("create skill dir");
+    std::fs::write(
+        skill.join("SKILL.md"),
+        "---\nname: broken-links\ndescription: Has a bad link\ntrigger: Never\n---\n\nSee [guide](references/guide.md).\n",
+    )
+    .expect("write SKILL.md");
+
+    skillet()
+        .args(["lint"])
+        .arg(tmp.path())
+        .env("HOME", tmp.path())
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains(
+            "SKILL.md:7: error: link target 'references/guide.md' doesn't exist [broken-link]",
+        ))
+        .stdout(predicate::str::contains(
+            "Checked 1 skill: 1 error, 0 warnings",
+        ));
+}
+
+#[test]
+fn lint_json_output() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let skill = tmp.path().join("acme/no-trigger");
+    std::fs::create_dir_all(&skill).expect("create skill dir");
+    std::fs::write(
+        skill.join("SKILL.md"),
+        "---\nname: no-trigger\ndescription: Missing a trigger\n---\n\n# Body\n",
+    )
+    .expect("write SKILL.md");
+
+    let output = skillet()
+        .args(["lint", "--json"])
+        .arg(tmp.path())
+        .env("HOME", tmp.path())
+        .output()
+        .expect("run lint");
+    assert!(output.status.success(), "warnings alone don't fail");
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("valid JSON");
+    assert_eq!(json["skills"], 1);
+    assert_eq!(json["errors"], 0);
+    assert_eq!(json["warnings"], 1);
+    assert_eq!(json["diagnostics"][0]["rule"], "missing-trigger");
+    assert_eq!(json["diagnostics"][0]["severity"], "warning");
+}
+
+#[test]
+fn lint_official_repo_is_clean() {
+    skillet()
+        .args(["lint"])
+        .arg(official_repo())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("0 errors, 0 warnings"));
+}
+
 // -- Official repo (in-repo) --
 
 #[test]
@@ -440,3 +1428,57 @@ fn info_official_skill() {
                 .and(predicate::str::contains("consumer")),
         );
 }
+
+#[test]
+fn frontmatter_fields_shown_and_filterable() {
+    let tmp = tempfile::tempdir().expect("create temp dir");
+    let repo = tmp.path().join("repo");
+    for (name, audience) in [("api-style", "backend"), ("css-style", "frontend")] {
+        let dir = repo.join("acme").join(name);
+        std::fs::create_dir_all(&dir).expect("create skill dir");
+        std::fs::write(
+            dir.join("SKILL.md"),
+            format!(
+                "---\nname: {name}\ndescription: Style rules\nmodel: sonnet\n\
+                 allowed-tools: Read Grep\nmetadata:\n  audience: {audience}\n---\n\n# Style\n"
+            ),
+        )
+        .expect("write SKILL.md");
+    }
+
+    skillet()
+        .args(["info", "acme/api-style", "--no-cache", "--repo"])
+        .arg(&repo)
+        .env("HOME", tmp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("model ................. sonnet"))
+        .stdout(predicate::str::contains(
+            "allowed tools ......... Read, Grep",
+        ))
+        .stdout(predicate::str::contains("audience .............. backend"));
+
+    skillet()
+        .args([
+            "search",
+            "*",
+            "--meta",
+            "audience=Frontend",
+            "--no-cache",
+            "--repo",
+        ])
+        .arg(&repo)
+        .env("HOME", tmp.path())
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("acme/css-style"))
+        .stdout(predicate::str::contains("acme/api-style").not());
+
+    skillet()
+        .args(["search", "*", "--meta", "audience", "--repo"])
+        .arg(&repo)
+        .env("HOME", tmp.path())
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("expected KEY=VALUE"));
+}
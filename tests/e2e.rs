@@ -160,6 +160,32 @@ fn suggest_graph_follows_links() {
         .stdout(predicate::str::contains("a-skill").and(predicate::str::contains("b-skill")));
 }
 
+/// A suggestion that can't be cloned is reported instead of silently dropped.
+#[test]
+fn suggest_graph_reports_failed_clones() {
+    let tmp = tempfile::tempdir().unwrap();
+    let home = tmp.path().join("home");
+    write_test_config(&home);
+
+    let missing = tmp.path().join("no-such-repo");
+    let repo_a = make_git_repo(tmp.path(), "repo-a");
+    add_skill(&repo_a, "alice", "a-skill", "Skill from repo A");
+    write_skillet_toml(&repo_a, "repo-a", &[(file_url(&missing), None)]);
+    commit_all(&repo_a, "add skill and suggest");
+
+    skillet()
+        .args(["search", "*", "--remote", &file_url(&repo_a)])
+        .env("HOME", &home)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("a-skill"))
+        .stdout(predicate::str::contains("Load problems (1):"))
+        .stdout(predicate::str::contains(
+            "no-such-repo: error: couldn't clone suggested repo",
+        ))
+        .stdout(predicate::str::contains("[clone_failed]"));
+}
+
 /// Trust tiers are assigned based on suggest graph depth.
 #[test]
 fn suggest_graph_trust_tiers() {
@@ -503,6 +529,284 @@ fn mcp_prompts_from_suggest_graph() {
     let _ = child.wait();
 }
 
+/// Include directives are expanded in prompts; `raw` returns the original.
+#[test]
+fn mcp_prompts_expand_includes() {
+    let tmp = tempfile::tempdir().unwrap();
+    let repo = make_git_repo(tmp.path(), "repo");
+    add_skill(&repo, "acme", "house-style", "House style");
+    std::fs::write(
+        repo.join("acme/house-style/SKILL.md"),
+        "# House style\n\n## Safety\n\nNever force-push.\n\n## Naming\n\nUse snake_case.\n",
+    )
+    .unwrap();
+    add_skill(&repo, "acme", "deploy", "Deploying");
+    std::fs::create_dir_all(repo.join("acme/deploy/references")).unwrap();
+    std::fs::write(
+        repo.join("acme/deploy/references/common.md"),
+        "Check the dashboard first.\n",
+    )
+    .unwrap();
+    std::fs::write(
+        repo.join("acme/deploy/SKILL.md"),
+        "# Deploy\n\n<!-- skillet:include references/common.md -->\n\n{{> acme/house-style#Safety}}\n",
+    )
+    .unwrap();
+    commit_all(&repo, "add skills");
+
+    let port = {
+        let l = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        l.local_addr().unwrap().port()
+    };
+    let mut child = process::Command::new(assert_cmd::cargo::cargo_bin!("skillet"))
+        .args([
+            "serve",
+            "--repo",
+            repo.to_str().unwrap(),
+            "--http",
+            &format!("127.0.0.1:{port}"),
+            "--log-level",
+            "error",
+        ])
+        .env("HOME", tmp.path().join("home"))
+        .stdout(process::Stdio::null())
+        .stderr(process::Stdio::null())
+        .spawn()
+        .expect("spawn");
+
+    let client = reqwest::blocking::Client::new();
+    let base = format!("http://127.0.0.1:{port}");
+    let mut ready = false;
+    for _ in 0..50 {
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        if client.get(format!("{base}/health")).send().is_ok() {
+            ready = true;
+            break;
+        }
+    }
+    assert!(ready, "server should start");
+
+    let session_id = client
+        .post(&base)
+        .json(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-03-26",
+                "capabilities": {},
+                "clientInfo": {"name": "test", "version": "0.1"}
+            },
+            "id": 1
+        }))
+        .send()
+        .unwrap()
+        .headers()
+        .get("mcp-session-id")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let get_prompt = |arguments: serde_json::Value| -> String {
+        let resp: serde_json::Value = client
+            .post(&base)
+            .header("mcp-session-id", &session_id)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "prompts/get",
+                "params": { "name": "acme_deploy", "arguments": arguments },
+                "id": 2
+            }))
+            .send()
+            .unwrap()
+            .json()
+            .unwrap();
+        resp["result"]["messages"][0]["content"]["text"]
+            .as_str()
+            .expect("prompt text")
+            .to_string()
+    };
+
+    let expanded = get_prompt(serde_json::json!({}));
+    assert!(
+        expanded.contains("Check the dashboard first."),
+        "{expanded}"
+    );
+    assert!(expanded.contains("Never force-push."), "{expanded}");
+    assert!(!expanded.contains("snake_case"), "{expanded}");
+    assert!(!expanded.contains("skillet:include"), "{expanded}");
+
+    let raw = get_prompt(serde_json::json!({ "raw": "true" }));
+    assert!(
+        raw.contains("<!-- skillet:include references/common.md -->"),
+        "{raw}"
+    );
+    assert!(raw.contains("{{> acme/house-style#Safety}}"), "{raw}");
+
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Suggested repos keep their URL-keyed alias and trust tier when a
+/// refresh reloads them.
+#[test]
+fn mcp_refresh_keeps_suggested_repo_settings() {
+    let tmp = tempfile::tempdir().unwrap();
+
+    let repo_b = make_git_repo(tmp.path(), "repo-b");
+    add_skill(&repo_b, "bob", "b-skill", "Skill from B");
+    commit_all(&repo_b, "add skill");
+
+    let repo_a = make_git_repo(tmp.path(), "repo-a");
+    add_skill(&repo_a, "alice", "a-skill", "Skill from A");
+    write_skillet_toml(&repo_a, "repo-a", &[(file_url(&repo_b), None)]);
+    commit_all(&repo_a, "add skill and suggest");
+
+    let home = tmp.path().join("home");
+    let config_dir = home.join(".config").join("skillet");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    std::fs::write(
+        config_dir.join("config.toml"),
+        format!(
+            "[cache]\nenabled = false\n\n[repos.aliases]\nfork = \"{}\"\n",
+            file_url(&repo_b)
+        ),
+    )
+    .unwrap();
+
+    let port = {
+        let l = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        l.local_addr().unwrap().port()
+    };
+    let mut child = process::Command::new(assert_cmd::cargo::cargo_bin!("skillet"))
+        .args([
+            "serve",
+            "--remote",
+            &file_url(&repo_a),
+            "--refresh-interval",
+            "1s",
+            "--http",
+            &format!("127.0.0.1:{port}"),
+            "--log-level",
+            "error",
+        ])
+        .env("HOME", &home)
+        .stdout(process::Stdio::null())
+        .stderr(process::Stdio::null())
+        .spawn()
+        .expect("spawn");
+
+    let client = reqwest::blocking::Client::new();
+    let base = format!("http://127.0.0.1:{port}");
+    let mut ready = false;
+    for _ in 0..50 {
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        if client.get(format!("{base}/health")).send().is_ok() {
+            ready = true;
+            break;
+        }
+    }
+    assert!(ready, "server should start");
+
+    let session_id = client
+        .post(&base)
+        .json(&serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-03-26",
+                "capabilities": {},
+                "clientInfo": {"name": "test", "version": "0.1"}
+            },
+            "id": 1
+        }))
+        .send()
+        .unwrap()
+        .headers()
+        .get("mcp-session-id")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let prompt_names = || -> Vec<String> {
+        let resp: serde_json::Value = client
+            .post(&base)
+            .header("mcp-session-id", &session_id)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "prompts/list",
+                "params": {},
+                "id": 2
+            }))
+            .send()
+            .unwrap()
+            .json()
+            .unwrap();
+        resp["result"]["prompts"]
+            .as_array()
+            .expect("prompts array")
+            .iter()
+            .filter_map(|p| p["name"].as_str().map(String::from))
+            .collect()
+    };
+
+    let trust = || -> String {
+        let resp: serde_json::Value = client
+            .post(&base)
+            .header("mcp-session-id", &session_id)
+            .json(&serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "tools/call",
+                "params": {
+                    "name": "info_skill",
+                    "arguments": { "owner": "fork:bob", "name": "b-skill" }
+                },
+                "id": 3
+            }))
+            .send()
+            .unwrap()
+            .json()
+            .unwrap();
+        let text = resp["result"]["content"][0]["text"]
+            .as_str()
+            .expect("info text");
+        text.lines()
+            .find(|line| line.starts_with("**Trust:**"))
+            .unwrap_or_else(|| panic!("no trust tier: {text}"))
+            .to_string()
+    };
+
+    let names = prompt_names();
+    assert!(
+        names.contains(&"fork__bob_b-skill".to_string()),
+        "{names:?}"
+    );
+    let tier = trust();
+
+    // A new commit on the seed repo triggers a refresh
+    add_skill(&repo_a, "alice", "new-skill", "Added later");
+    commit_all(&repo_a, "add another skill");
+    let mut names = Vec::new();
+    for _ in 0..50 {
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        names = prompt_names();
+        if names.contains(&"alice_new-skill".to_string()) {
+            break;
+        }
+    }
+    assert!(names.contains(&"alice_new-skill".to_string()), "{names:?}");
+    assert!(
+        names.contains(&"fork__bob_b-skill".to_string()),
+        "{names:?}"
+    );
+    assert!(!names.contains(&"bob_b-skill".to_string()), "{names:?}");
+    assert_eq!(trust(), tier);
+
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
 // ── Auto-detect skills/ without skillet.toml ────────────────────
 
 /// A local repo with skills/ directory and no skillet.toml or git remote
@@ -592,6 +896,75 @@ fn suggest_graph_max_depth_enforced() {
     );
 }
 
+/// Suggestions are cloned in parallel but merged in manifest order, and a
+/// failed clone doesn't use up the per-repo fan-out.
+#[test]
+fn suggest_graph_parallel_merge_is_ordered() {
+    let tmp = tempfile::tempdir().unwrap();
+    let home = tmp.path().join("home");
+    let config_dir = home.join(".config").join("skillet");
+    std::fs::create_dir_all(&config_dir).unwrap();
+    std::fs::write(
+        config_dir.join("config.toml"),
+        "[repos]\nlocal = []\nremote = []\nconcurrency = 4\n\n[cache]\nenabled = false\n\n[suggest]\nmax_per_repo = 3\n",
+    )
+    .unwrap();
+
+    // repo-b and repo-d both publish bob/shared; repo-b comes first
+    let repo_b = make_git_repo(tmp.path(), "repo-b");
+    add_skill(&repo_b, "bob", "shared", "Shared from B");
+    commit_all(&repo_b, "add skill");
+
+    let repo_d = make_git_repo(tmp.path(), "repo-d");
+    add_skill(&repo_d, "bob", "shared", "Shared from D");
+    add_skill(&repo_d, "dave", "d-skill", "Skill D");
+    commit_all(&repo_d, "add skills");
+
+    let repo_e = make_git_repo(tmp.path(), "repo-e");
+    add_skill(&repo_e, "erin", "e-skill", "Skill E");
+    commit_all(&repo_e, "add skill");
+
+    let repo_f = make_git_repo(tmp.path(), "repo-f");
+    add_skill(&repo_f, "fred", "f-skill", "Skill F");
+    commit_all(&repo_f, "add skill");
+
+    let missing = tmp.path().join("repo-missing");
+    let repo_a = make_git_repo(tmp.path(), "repo-a");
+    add_skill(&repo_a, "alice", "a-skill", "Skill A");
+    write_skillet_toml(
+        &repo_a,
+        "repo-a",
+        &[
+            (file_url(&repo_b), None),
+            (file_url(&missing), None),
+            (file_url(&repo_d), None),
+            (file_url(&repo_e), None),
+            (file_url(&repo_f), None),
+        ],
+    );
+    commit_all(&repo_a, "add skill and suggests");
+
+    let output = skillet()
+        .args(["search", "*", "--remote", &file_url(&repo_a)])
+        .env("HOME", &home)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "search should succeed: {stdout}");
+    assert!(
+        stdout.contains("Shared from B") && !stdout.contains("Shared from D"),
+        "earlier suggestion should win: {stdout}"
+    );
+    // The failed clone leaves room for repo-e within max_per_repo = 3
+    assert!(stdout.contains("d-skill"), "should find d-skill: {stdout}");
+    assert!(stdout.contains("e-skill"), "should find e-skill: {stdout}");
+    assert!(
+        !stdout.contains("f-skill"),
+        "fan-out limit should stop before repo-f: {stdout}"
+    );
+}
+
 /// Suggest graph handles circular references without looping.
 #[test]
 fn suggest_graph_circular_reference() {
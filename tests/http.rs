@@ -18,20 +18,44 @@ static TEST_REPO: std::sync::LazyLock<skillet_mcp::testutil::TestRepo> =
 
 /// Spawn the skillet HTTP server on the given port, returning the child process.
 fn spawn_server(port: u16) -> Child {
+    spawn_server_with(port, |_| {})
+}
+
+/// Spawn the server with a skillet config, using a temporary HOME.
+fn spawn_server_with_config(port: u16, home: &std::path::Path, config: &str) -> Child {
+    let config_dir = home.join(".config/skillet");
+    std::fs::create_dir_all(&config_dir).expect("create config dir");
+    std::fs::write(config_dir.join("config.toml"), config).expect("write config");
+    spawn_server_with(port, |cmd| {
+        cmd.env("HOME", home);
+    })
+}
+
+fn spawn_server_with(port: u16, configure: impl FnOnce(&mut Command)) -> Child {
+    spawn_server_for(port, TEST_REPO.path(), configure)
+}
+
+/// Spawn the server over `repo` instead of the standard test repo.
+/// `configure` runs after the standard arguments, so it can add flags.
+fn spawn_server_for(
+    port: u16,
+    repo: &std::path::Path,
+    configure: impl FnOnce(&mut Command),
+) -> Child {
     let bin = assert_cmd::cargo::cargo_bin!("skillet");
-    let repo = TEST_REPO.path();
-
-    Command::new(bin)
-        .args([
-            "serve",
-            "--http",
-            &format!("127.0.0.1:{port}"),
-            "--repo",
-            repo.to_str().unwrap(),
-            "--log-level",
-            "error",
-        ])
-        .stdout(std::process::Stdio::null())
+
+    let mut cmd = Command::new(bin);
+    cmd.args([
+        "serve",
+        "--http",
+        &format!("127.0.0.1:{port}"),
+        "--repo",
+        repo.to_str().unwrap(),
+        "--log-level",
+        "error",
+    ]);
+    configure(&mut cmd);
+    cmd.stdout(std::process::Stdio::null())
         .stderr(std::process::Stdio::null())
         .spawn()
         .expect("failed to spawn skillet server")
@@ -62,12 +86,17 @@ fn jsonrpc_request(method: &str, params: serde_json::Value, id: u64) -> serde_js
 
 /// Initialize an MCP session and return the session ID.
 async fn initialize(client: &reqwest::Client, base: &str) -> String {
+    initialize_as(client, base, "test-client").await
+}
+
+/// Initialize an MCP session reporting `name` as the client.
+async fn initialize_as(client: &reqwest::Client, base: &str, name: &str) -> String {
     let body = jsonrpc_request(
         "initialize",
         serde_json::json!({
             "protocolVersion": "2025-03-26",
             "capabilities": {},
-            "clientInfo": { "name": "test-client", "version": "0.1.0" }
+            "clientInfo": { "name": name, "version": "0.1.0" }
         }),
         1,
     );
@@ -208,6 +237,19 @@ async fn http_list_tools() {
         "should have search_skills"
     );
     assert!(names.contains(&"info_skill"), "should have info_skill");
+    assert!(
+        names.contains(&"similar_skills"),
+        "should have similar_skills"
+    );
+    assert!(names.contains(&"list_tags"), "should have list_tags");
+    assert!(
+        names.contains(&"load_diagnostics"),
+        "should have load_diagnostics"
+    );
+    assert!(
+        names.contains(&"browse_skills"),
+        "should have browse_skills"
+    );
     assert!(
         !names.contains(&"install_skill"),
         "install_skill should be removed"
@@ -256,6 +298,10 @@ async fn http_search_skills() {
         text.contains("rust-dev"),
         "search for 'rust' should find rust-dev: {text}"
     );
+    assert!(
+        text.contains("**Facets:**") && text.contains("- owners: "),
+        "search results should include facet counts: {text}"
+    );
 }
 
 #[tokio::test]
@@ -343,6 +389,333 @@ async fn http_prompts_list() {
     );
 }
 
+#[tokio::test]
+async fn http_project_context_prompt_and_resource() {
+    let project = tempfile::tempdir().unwrap();
+    std::fs::write(
+        project.path().join("skillet.toml"),
+        "[project]\nname = \"acme\"\nagents_md = \"AGENTS.md\"\n",
+    )
+    .unwrap();
+    std::fs::write(
+        project.path().join("AGENTS.md"),
+        "# Acme conventions\n\n## Style\n\nAlways use tabs.\n\n## Testing\n\nRun make test.\n",
+    )
+    .unwrap();
+
+    let port = free_port();
+    let _guard = ServerGuard(spawn_server_with(port, |cmd| {
+        cmd.current_dir(project.path());
+    }));
+    wait_for_server(port).await;
+
+    let client = reqwest::Client::new();
+    let base = format!("http://127.0.0.1:{port}");
+
+    // Instructions point at the project context
+    let body = jsonrpc_request(
+        "initialize",
+        serde_json::json!({
+            "protocolVersion": "2025-03-26",
+            "capabilities": {},
+            "clientInfo": { "name": "test-client", "version": "0.1.0" }
+        }),
+        1,
+    );
+    let resp = client.post(&base).json(&body).send().await.unwrap();
+    let session_id = resp.headers()["mcp-session-id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let json: serde_json::Value = resp.json().await.unwrap();
+    let instructions = json["result"]["instructions"].as_str().unwrap();
+    assert!(
+        instructions.contains("acme project") && instructions.contains("project-context"),
+        "instructions should mention the project context: {instructions}"
+    );
+
+    // Served as a prompt, with section filtering
+    let body = jsonrpc_request(
+        "prompts/get",
+        serde_json::json!({
+            "name": "project-context",
+            "arguments": { "section": "Style" }
+        }),
+        2,
+    );
+    let json: serde_json::Value = client
+        .post(&base)
+        .header("mcp-session-id", &session_id)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let text = json["result"]["messages"][0]["content"]["text"]
+        .as_str()
+        .expect("prompt text");
+    assert!(text.contains("Always use tabs."), "got: {text}");
+    assert!(!text.contains("make test"), "got: {text}");
+
+    // And as a resource
+    let body = jsonrpc_request(
+        "resources/read",
+        serde_json::json!({ "uri": "skillet://project/AGENTS.md" }),
+        3,
+    );
+    let json: serde_json::Value = client
+        .post(&base)
+        .header("mcp-session-id", &session_id)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let text = json["result"]["contents"][0]["text"]
+        .as_str()
+        .expect("resource text");
+    assert!(text.starts_with("# Acme conventions"), "got: {text}");
+}
+
+#[tokio::test]
+async fn http_project_context_appears_under_watch() {
+    let tmp = tempfile::tempdir().unwrap();
+    let repo = tmp.path().join("repo");
+    let skill = repo.join("acme/tool");
+    std::fs::create_dir_all(&skill).unwrap();
+    std::fs::write(
+        skill.join("skill.toml"),
+        "[skill]\nname = \"tool\"\nowner = \"acme\"\nversion = \"1.0.0\"\n\
+         description = \"A tool\"\n",
+    )
+    .unwrap();
+    std::fs::write(skill.join("SKILL.md"), "# Tool\n").unwrap();
+
+    // Named without a .md extension, so only a path match picks it up
+    let project = tmp.path().join("project");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(
+        project.join("skillet.toml"),
+        "[project]\nname = \"acme\"\nagents_md = \"CONVENTIONS\"\n",
+    )
+    .unwrap();
+
+    let port = free_port();
+    let _guard = ServerGuard(spawn_server_for(port, &repo, |cmd| {
+        cmd.arg("--watch").current_dir(&project);
+    }));
+    wait_for_server(port).await;
+
+    let client = reqwest::Client::new();
+    let base = format!("http://127.0.0.1:{port}");
+
+    // Instructions can't be updated later, so they say where to look
+    let body = jsonrpc_request(
+        "initialize",
+        serde_json::json!({
+            "protocolVersion": "2025-03-26",
+            "capabilities": {},
+            "clientInfo": { "name": "test-client", "version": "0.1.0" }
+        }),
+        1,
+    );
+    let resp = client.post(&base).json(&body).send().await.unwrap();
+    let session_id = resp.headers()["mcp-session-id"]
+        .to_str()
+        .unwrap()
+        .to_string();
+    let json: serde_json::Value = resp.json().await.unwrap();
+    let instructions = json["result"]["instructions"].as_str().unwrap();
+    assert!(
+        instructions.contains("if prompts/list includes `project-context`"),
+        "got: {instructions}"
+    );
+
+    std::fs::write(
+        project.join("CONVENTIONS"),
+        "# Acme conventions\n\nAlways use tabs.\n",
+    )
+    .unwrap();
+
+    let body = jsonrpc_request(
+        "prompts/get",
+        serde_json::json!({ "name": "project-context" }),
+        2,
+    );
+    for _ in 0..100 {
+        let json: serde_json::Value = client
+            .post(&base)
+            .header("mcp-session-id", &session_id)
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        if let Some(text) = json["result"]["messages"][0]["content"]["text"].as_str() {
+            assert!(text.contains("Always use tabs."), "got: {text}");
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!("project context was not picked up after AGENTS.md was created");
+}
+
+#[tokio::test]
+async fn http_mcp_requirements_use_project_root() {
+    let tmp = tempfile::tempdir().unwrap();
+    let repo = tmp.path().join("repo");
+    let skill = repo.join("acme/gh-triage");
+    std::fs::create_dir_all(&skill).unwrap();
+    std::fs::write(
+        skill.join("skill.toml"),
+        "[skill]\nname = \"gh-triage\"\nowner = \"acme\"\nversion = \"1.0.0\"\n\
+         description = \"Triage GitHub issues\"\n\n\
+         [skill.compatibility]\nrequired_mcp_servers = [\"github\"]\n",
+    )
+    .unwrap();
+    std::fs::write(
+        skill.join("SKILL.md"),
+        "# GitHub triage\n\nTriage issues.\n",
+    )
+    .unwrap();
+
+    // Started from a subdirectory, the server still reads the project's
+    // .mcp.json next to skillet.toml
+    let project = tmp.path().join("project");
+    std::fs::create_dir_all(project.join("src")).unwrap();
+    std::fs::write(project.join("skillet.toml"), "[project]\nname = \"acme\"\n").unwrap();
+    std::fs::write(
+        project.join(".mcp.json"),
+        r#"{"mcpServers": {"github": {"command": "github-mcp"}}}"#,
+    )
+    .unwrap();
+    let home = tmp.path().join("home");
+    std::fs::create_dir_all(&home).unwrap();
+
+    let port = free_port();
+    let _guard = ServerGuard(spawn_server_for(port, &repo, |cmd| {
+        cmd.current_dir(project.join("src")).env("HOME", &home);
+    }));
+    wait_for_server(port).await;
+
+    let client = reqwest::Client::new();
+    let base = format!("http://127.0.0.1:{port}");
+    let session_id = initialize(&client, &base).await;
+    let call = |id: u64, name: &str, arguments: serde_json::Value| {
+        let body = jsonrpc_request(
+            "tools/call",
+            serde_json::json!({ "name": name, "arguments": arguments }),
+            id,
+        );
+        let request = client
+            .post(&base)
+            .header("mcp-session-id", &session_id)
+            .json(&body);
+        async move {
+            let json: serde_json::Value = request.send().await.unwrap().json().await.unwrap();
+            json["result"]["content"][0]["text"]
+                .as_str()
+                .expect("tool text")
+                .to_string()
+        }
+    };
+
+    let text = call(
+        2,
+        "info_skill",
+        serde_json::json!({ "owner": "acme", "name": "gh-triage" }),
+    )
+    .await;
+    assert!(text.contains("github (configured)"), "got: {text}");
+
+    let text = call(
+        3,
+        "search_skills",
+        serde_json::json!({ "query": "triage", "mcp_ready": true }),
+    )
+    .await;
+    assert!(text.contains("gh-triage"), "got: {text}");
+}
+
+#[tokio::test]
+async fn http_client_profile_hides_incompatible_skills() {
+    let port = free_port();
+    let tmp = tempfile::tempdir().unwrap();
+    let _guard = ServerGuard(spawn_server_with_config(
+        port,
+        tmp.path(),
+        "[agent.clients.limited-client]\ntool_use = false\n",
+    ));
+    wait_for_server(port).await;
+
+    let client = reqwest::Client::new();
+    let base = format!("http://127.0.0.1:{port}");
+
+    // The override applies only to the client that reports its name
+    let limited = initialize_as(&client, &base, "limited-client").await;
+    let other = initialize(&client, &base).await;
+
+    let prompt_names = |session: String, id: u64| {
+        let client = client.clone();
+        let base = base.clone();
+        async move {
+            let json: serde_json::Value = client
+                .post(&base)
+                .header("mcp-session-id", &session)
+                .json(&jsonrpc_request("prompts/list", serde_json::json!({}), id))
+                .send()
+                .await
+                .unwrap()
+                .json()
+                .await
+                .unwrap();
+            json["result"]["prompts"]
+                .as_array()
+                .expect("prompts array")
+                .iter()
+                .filter_map(|p| p["name"].as_str().map(str::to_string))
+                .collect::<Vec<_>>()
+        }
+    };
+    let limited_prompts = prompt_names(limited.clone(), 40).await;
+    let other_prompts = prompt_names(other, 41).await;
+    assert!(!limited_prompts.contains(&"joshrotenberg_rust-dev".to_string()));
+    assert!(other_prompts.contains(&"joshrotenberg_rust-dev".to_string()));
+
+    let body = jsonrpc_request(
+        "tools/call",
+        serde_json::json!({
+            "name": "search_skills",
+            "arguments": { "query": "rust" }
+        }),
+        42,
+    );
+    let json: serde_json::Value = client
+        .post(&base)
+        .header("mcp-session-id", &limited)
+        .json(&body)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let text = json["result"]["content"][0]["text"]
+        .as_str()
+        .unwrap_or_default();
+    assert!(
+        text.contains("Hidden as incompatible")
+            && text.contains("joshrotenberg/rust-dev: requires tool use"),
+        "should explain hidden skills: {text}"
+    );
+}
+
 #[tokio::test]
 async fn http_prompts_get() {
     let port = free_port();
@@ -588,6 +961,137 @@ async fn http_list_skills_by_owner_not_found() {
     );
 }
 
+#[tokio::test]
+async fn http_list_tags() {
+    let port = free_port();
+    let _guard = ServerGuard(spawn_server(port));
+    wait_for_server(port).await;
+
+    let client = reqwest::Client::new();
+    let base = format!("http://127.0.0.1:{port}");
+    let session_id = initialize(&client, &base).await;
+
+    let body = jsonrpc_request(
+        "tools/call",
+        serde_json::json!({
+            "name": "list_tags",
+            "arguments": {}
+        }),
+        21,
+    );
+    let resp = client
+        .post(&base)
+        .header("mcp-session-id", &session_id)
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), 200);
+    let json: serde_json::Value = resp.json().await.unwrap();
+    let content = json["result"]["content"].as_array().expect("content array");
+    let text = content
+        .iter()
+        .filter_map(|c| c["text"].as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(
+        text.contains("Skill Tags"),
+        "should have tags header: {text}"
+    );
+    assert!(text.contains("rust"), "should list rust tag: {text}");
+}
+
+#[tokio::test]
+async fn http_browse_skills() {
+    let port = free_port();
+    let _guard = ServerGuard(spawn_server(port));
+    wait_for_server(port).await;
+
+    let client = reqwest::Client::new();
+    let base = format!("http://127.0.0.1:{port}");
+    let session_id = initialize(&client, &base).await;
+
+    let body = jsonrpc_request(
+        "tools/call",
+        serde_json::json!({
+            "name": "browse_skills",
+            "arguments": { "path": "lang" }
+        }),
+        22,
+    );
+    let resp = client
+        .post(&base)
+        .header("mcp-session-id", &session_id)
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), 200);
+    let json: serde_json::Value = resp.json().await.unwrap();
+    let content = json["result"]["content"].as_array().expect("content array");
+    let text = content
+        .iter()
+        .filter_map(|c| c["text"].as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(text.contains("Browse: lang"), "should have header: {text}");
+    assert!(
+        text.contains("`lang/java`"),
+        "should list the java group: {text}"
+    );
+}
+
+// ── similar_skills ──────────────────────────────────────────────
+
+#[tokio::test]
+async fn http_similar_skills() {
+    let port = free_port();
+    let _guard = ServerGuard(spawn_server(port));
+    wait_for_server(port).await;
+
+    let client = reqwest::Client::new();
+    let base = format!("http://127.0.0.1:{port}");
+    let session_id = initialize(&client, &base).await;
+
+    let body = jsonrpc_request(
+        "tools/call",
+        serde_json::json!({
+            "name": "similar_skills",
+            "arguments": { "owner": "joshrotenberg", "name": "rust-dev", "limit": 3 }
+        }),
+        25,
+    );
+    let resp = client
+        .post(&base)
+        .header("mcp-session-id", &session_id)
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), 200);
+    let json: serde_json::Value = resp.json().await.unwrap();
+    let content = json["result"]["content"].as_array().expect("content array");
+    let text = content
+        .iter()
+        .filter_map(|c| c["text"].as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    assert!(
+        text.contains("similar to joshrotenberg/rust-dev"),
+        "should list similar skills: {text}"
+    );
+    assert!(
+        text.contains("**Shared terms:**"),
+        "should show shared terms: {text}"
+    );
+}
+
 // ── annotate_skill ──────────────────────────────────────────────
 
 #[tokio::test]
@@ -635,6 +1139,77 @@ async fn http_annotate_skill() {
     );
 }
 
+#[tokio::test]
+async fn http_annotate_skill_resolves_aliased_refs() {
+    let home = tempfile::tempdir().unwrap();
+    let port = free_port();
+    let _guard = ServerGuard(spawn_server_with_config(
+        port,
+        home.path(),
+        &format!(
+            "[repos.aliases]\nmain = \"{}\"\n",
+            TEST_REPO.path().display()
+        ),
+    ));
+    wait_for_server(port).await;
+
+    let client = reqwest::Client::new();
+    let base = format!("http://127.0.0.1:{port}");
+    let session_id = initialize(&client, &base).await;
+    let call = |id: u64, name: &str, arguments: serde_json::Value| {
+        let body = jsonrpc_request(
+            "tools/call",
+            serde_json::json!({ "name": name, "arguments": arguments }),
+            id,
+        );
+        let request = client
+            .post(&base)
+            .header("mcp-session-id", &session_id)
+            .json(&body);
+        async move {
+            let json: serde_json::Value = request.send().await.unwrap().json().await.unwrap();
+            let text = json["result"]["content"][0]["text"]
+                .as_str()
+                .expect("tool text")
+                .to_string();
+            (json["result"]["isError"] == true, text)
+        }
+    };
+
+    // A bare owner resolves to the aliased skill and the note is stored
+    // under its qualified reference
+    let (is_error, text) = call(
+        2,
+        "annotate_skill",
+        serde_json::json!({
+            "owner": "joshrotenberg",
+            "name": "rust-dev",
+            "note": "Pin the toolchain first"
+        }),
+    )
+    .await;
+    assert!(!is_error, "got: {text}");
+    assert!(text.contains("main:joshrotenberg/rust-dev"), "got: {text}");
+
+    let (_, text) = call(
+        3,
+        "info_skill",
+        serde_json::json!({ "owner": "joshrotenberg", "name": "rust-dev" }),
+    )
+    .await;
+    assert!(text.contains("Pin the toolchain first"), "got: {text}");
+
+    // Notes for unknown skills are rejected
+    let (is_error, text) = call(
+        4,
+        "annotate_skill",
+        serde_json::json!({ "owner": "nobody", "name": "missing", "note": "x" }),
+    )
+    .await;
+    assert!(is_error, "got: {text}");
+    assert!(text.contains("not found"), "got: {text}");
+}
+
 // ── Multiple sessions ───────────────────────────────────────────
 
 #[tokio::test]
@@ -246,15 +246,22 @@ fn scenario_malformed_skill_skipped() {
         .output()
         .expect("search");
     let stdout = String::from_utf8_lossy(&output.stdout);
+    let (results, problems) = stdout
+        .split_once("Load problems")
+        .expect("load problems footer");
 
     assert!(
-        stdout.contains("valid-skill"),
+        results.contains("valid-skill"),
         "valid skill should be found: {stdout}"
     );
     assert!(
-        !stdout.contains("broken-skill"),
+        !results.contains("broken-skill"),
         "broken skill should be skipped: {stdout}"
     );
+    assert!(
+        problems.contains("bad/broken-skill: error:"),
+        "broken skill should be reported: {stdout}"
+    );
 }
 
 /// Empty repo returns no results without error